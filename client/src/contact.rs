@@ -0,0 +1,161 @@
+//! Sealing and opening of encrypted contact info.
+//!
+//! A profile's contact info is encrypted once under a random [`ContactKey`]
+//! (XChaCha20-Poly1305) and stored on `User.sealed_contact_info`. The content
+//! key itself is never stored in the clear: it is wrapped in an X25519 sealed
+//! box ("key envelope") for the profile owner (`User.contact_key_envelope`)
+//! and for every accepted collaborator (`ContactEnvelope` accounts).
+//!
+//! ```
+//! use devcol_client::contact::*;
+//!
+//! let owner = EncryptionKeypair::generate();
+//! let collaborator = EncryptionKeypair::generate();
+//!
+//! let key = ContactKey::generate();
+//! let sealed = seal_contact_info(&key, "discord: alice#0001").unwrap();
+//! let envelope = seal_contact_key(&key, &collaborator.public_key_bytes()).unwrap();
+//!
+//! let opened_key = open_contact_key(&envelope, &collaborator).unwrap();
+//! assert_eq!(open_contact_info(&opened_key, &sealed).unwrap(), "discord: alice#0001");
+//! assert!(open_contact_key(&envelope, &owner).is_err());
+//! ```
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    XChaCha20Poly1305, XNonce,
+};
+use crypto_box::{PublicKey, SecretKey};
+use devcol_solana::{CONTACT_ENVELOPE_MAX_LEN, CONTACT_INFO_MAX_LEN, SEALED_CONTACT_MAX_LEN};
+use std::fmt;
+
+const NONCE_LEN: usize = 24;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContactError {
+    /// Plaintext contact info is longer than the program accepts
+    ContactInfoTooLong,
+    /// Sealing contact info or a contact key failed
+    Encryption,
+    /// Ciphertext is malformed, truncated or was not sealed for this key
+    Decryption,
+    /// Decrypted contact info is not valid UTF-8
+    InvalidUtf8,
+}
+
+impl fmt::Display for ContactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ContactInfoTooLong => write!(
+                f,
+                "contact info must be {} bytes or less",
+                CONTACT_INFO_MAX_LEN
+            ),
+            Self::Encryption => write!(f, "failed to encrypt contact data"),
+            Self::Decryption => write!(f, "failed to decrypt contact data"),
+            Self::InvalidUtf8 => write!(f, "decrypted contact info is not valid UTF-8"),
+        }
+    }
+}
+
+impl std::error::Error for ContactError {}
+
+/// X25519 keypair a user registers (public half) on their profile
+pub struct EncryptionKeypair {
+    secret: SecretKey,
+}
+
+impl EncryptionKeypair {
+    pub fn generate() -> Self {
+        Self { secret: SecretKey::generate(&mut OsRng) }
+    }
+
+    /// Restore a keypair from its 32-byte secret (e.g. derived from a wallet signature)
+    pub fn from_secret_bytes(bytes: [u8; 32]) -> Self {
+        Self { secret: SecretKey::from_bytes(bytes) }
+    }
+
+    pub fn secret_bytes(&self) -> [u8; 32] {
+        self.secret.to_bytes()
+    }
+
+    /// Value to pass as `encryption_pubkey` to `set_sealed_contact_info`
+    pub fn public_key_bytes(&self) -> [u8; 32] {
+        self.secret.public_key().to_bytes()
+    }
+}
+
+/// Symmetric key the contact info is encrypted under
+#[derive(Clone, PartialEq, Eq)]
+pub struct ContactKey([u8; 32]);
+
+impl ContactKey {
+    pub fn generate() -> Self {
+        Self(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// Encrypt contact info for `User.sealed_contact_info` (nonce || ciphertext)
+pub fn seal_contact_info(key: &ContactKey, contact_info: &str) -> Result<Vec<u8>, ContactError> {
+    if contact_info.len() > CONTACT_INFO_MAX_LEN {
+        return Err(ContactError::ContactInfoTooLong);
+    }
+    let cipher = XChaCha20Poly1305::new(&key.0.into());
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, contact_info.as_bytes())
+        .map_err(|_| ContactError::Encryption)?;
+
+    let mut sealed = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    debug_assert!(sealed.len() <= SEALED_CONTACT_MAX_LEN);
+    Ok(sealed)
+}
+
+/// Decrypt `User.sealed_contact_info` with a key recovered from an envelope
+pub fn open_contact_info(key: &ContactKey, sealed: &[u8]) -> Result<String, ContactError> {
+    if sealed.len() < NONCE_LEN {
+        return Err(ContactError::Decryption);
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let nonce: [u8; NONCE_LEN] = nonce.try_into().map_err(|_| ContactError::Decryption)?;
+    let cipher = XChaCha20Poly1305::new(&key.0.into());
+    let plaintext = cipher
+        .decrypt(&XNonce::from(nonce), ciphertext)
+        .map_err(|_| ContactError::Decryption)?;
+    String::from_utf8(plaintext).map_err(|_| ContactError::InvalidUtf8)
+}
+
+/// Wrap the contact key for a recipient's registered X25519 public key
+pub fn seal_contact_key(
+    key: &ContactKey,
+    recipient_pubkey: &[u8; 32],
+) -> Result<Vec<u8>, ContactError> {
+    let envelope = PublicKey::from_bytes(*recipient_pubkey)
+        .seal(&mut OsRng, &key.0)
+        .map_err(|_| ContactError::Encryption)?;
+    debug_assert!(envelope.len() <= CONTACT_ENVELOPE_MAX_LEN);
+    Ok(envelope)
+}
+
+/// Unwrap a key envelope with the recipient's keypair
+pub fn open_contact_key(
+    envelope: &[u8],
+    recipient: &EncryptionKeypair,
+) -> Result<ContactKey, ContactError> {
+    let bytes = recipient
+        .secret
+        .unseal(envelope)
+        .map_err(|_| ContactError::Decryption)?;
+    let key: [u8; 32] = bytes.try_into().map_err(|_| ContactError::Decryption)?;
+    Ok(ContactKey(key))
+}
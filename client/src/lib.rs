@@ -0,0 +1,8 @@
+//! Off-chain helpers for the devcol program: PDA derivation and the
+//! client-side crypto the program relies on but cannot perform itself.
+
+pub mod contact;
+pub mod pda;
+pub mod visibility;
+
+pub use devcol_solana::ID as PROGRAM_ID;
@@ -0,0 +1,185 @@
+//! PDA derivation matching the seeds used by the program.
+
+use anchor_lang::prelude::Pubkey;
+use devcol_solana::ID;
+
+pub fn user_address(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"user", wallet.as_ref()], &ID)
+}
+
+/// Rate limit counters for `wallet`; outlives the User profile
+pub fn rate_state_address(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"rate_state", wallet.as_ref()], &ID)
+}
+
+/// Creator record for a program-owned `authority` (multisig vault, DAO treasury)
+pub fn program_creator_address(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"program_creator", authority.as_ref()], &ID)
+}
+
+/// `creator` is the original creator (`Project.seed_creator`), not the current owner
+pub fn project_address(creator: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"project", creator.as_ref(), name.as_bytes()], &ID)
+}
+
+pub fn collab_request_address(sender: &Pubkey, project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"collab_request", sender.as_ref(), project.as_ref()], &ID)
+}
+
+/// `thread` is the request's `timestamp`; a re-sent request at the same address starts over
+pub fn request_message_address(collab_request: &Pubkey, thread: i64, seq: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"request_message",
+            collab_request.as_ref(),
+            &thread.to_le_bytes(),
+            &seq.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+pub fn contact_envelope_address(owner: &Pubkey, recipient: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"contact_envelope", owner.as_ref(), recipient.as_ref()],
+        &ID,
+    )
+}
+
+pub fn connection_address(a: &Pubkey, b: &Pubkey) -> (Pubkey, u8) {
+    let (user_a, user_b) = devcol_solana::Connection::ordered(*a, *b);
+    Pubkey::find_program_address(&[b"connection", user_a.as_ref(), user_b.as_ref()], &ID)
+}
+
+/// `followed_since` is the followed profile's `member_since`
+pub fn follow_address(follower: &Pubkey, followed: &Pubkey, followed_since: i64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"follow",
+            follower.as_ref(),
+            followed.as_ref(),
+            &followed_since.to_le_bytes(),
+        ],
+        &ID,
+    )
+}
+
+/// `project_since` is the project's creation `timestamp`
+pub fn star_address(user: &Pubkey, project: &Pubkey, project_since: i64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"star", user.as_ref(), project.as_ref(), &project_since.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn block_address(blocker: &Pubkey, blocked: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"block", blocker.as_ref(), blocked.as_ref()], &ID)
+}
+
+pub fn application_bond_address(applicant: &Pubkey, project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"application_bond", applicant.as_ref(), project.as_ref()],
+        &ID,
+    )
+}
+
+pub fn sla_bond_address(project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"sla_bond", project.as_ref()], &ID)
+}
+
+pub fn config_address() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &ID)
+}
+
+pub fn project_role_address(project: &Pubkey, delegate: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"project_role", project.as_ref(), delegate.as_ref()],
+        &ID,
+    )
+}
+
+pub fn approver_set_address(project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"approver_set", project.as_ref()], &ID)
+}
+
+pub fn proposal_address(project: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"proposal", project.as_ref(), &index.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn organization_address(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"organization", name.as_bytes()], &ID)
+}
+
+pub fn org_project_address(organization: &Pubkey, index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"org_project", organization.as_ref(), &index.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn bounty_address(project: &Pubkey, index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"bounty", project.as_ref(), &index.to_le_bytes()],
+        &ID,
+    )
+}
+
+/// Owner of a project's token vaults (the associated token accounts of this address)
+pub fn vault_authority_address(project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault_authority", project.as_ref()], &ID)
+}
+
+pub fn stream_address(collab_request: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"stream", collab_request.as_ref()], &ID)
+}
+
+/// Dispute over a bounty or stream escrow
+pub fn dispute_address(escrow: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"dispute", escrow.as_ref()], &ID)
+}
+
+pub fn donation_vault_address(project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"donation_vault", project.as_ref()], &ID)
+}
+
+pub fn donor_record_address(project: &Pubkey, donor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"donor", project.as_ref(), donor.as_ref()], &ID)
+}
+
+pub fn grant_round_address(operator: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"grant_round", operator.as_ref(), name.as_bytes()], &ID)
+}
+
+pub fn grant_contribution_address(round: &Pubkey, project: &Pubkey, contributor: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"grant_contribution", round.as_ref(), project.as_ref(), contributor.as_ref()],
+        &ID,
+    )
+}
+
+pub fn event_address(organizer: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"event", organizer.as_ref(), name.as_bytes()], &ID)
+}
+
+pub fn event_entry_address(event: &Pubkey, project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"event_entry", event.as_ref(), project.as_ref()], &ID)
+}
+
+pub fn milestone_address(project: &Pubkey, index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"milestone", project.as_ref(), &index.to_le_bytes()],
+        &ID,
+    )
+}
+
+pub fn task_address(project: &Pubkey, index: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"task", project.as_ref(), &index.to_le_bytes()], &ID)
+}
+
+/// Task credit of `member` on `project`
+pub fn member_stats_address(project: &Pubkey, member: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"member_stats", project.as_ref(), member.as_ref()], &ID)
+}
@@ -0,0 +1,27 @@
+//! Connection checks and the profile visibility rules built on them.
+//!
+//! The program stores the connection graph and enforces these rules when
+//! contact envelopes are granted; services that serve extended profile data
+//! (IPFS metadata) apply them before returning anything to a viewer.
+
+use anchor_lang::{prelude::Pubkey, AccountDeserialize};
+use devcol_solana::{Connection, User};
+
+/// Check `is_connected(a, b)` against the raw data of the account at
+/// [`crate::pda::connection_address`] (`None` if the account does not exist).
+pub fn is_connected(connection_data: Option<&[u8]>, a: &Pubkey, b: &Pubkey) -> bool {
+    let Some(mut data) = connection_data else {
+        return false;
+    };
+    match Connection::try_deserialize(&mut data) {
+        Ok(connection) => connection.is_connected(a, b),
+        Err(_) => false,
+    }
+}
+
+/// Whether `viewer` may see the extended profile data and contact info of `owner`
+///
+/// Same rule the program applies before granting a contact envelope.
+pub fn can_view_profile(owner: &User, viewer: &Pubkey, connected: bool) -> bool {
+    owner.visible_to(viewer, connected)
+}
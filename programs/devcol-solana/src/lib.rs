@@ -1,11 +1,176 @@
+// Anchor 0.31's generated IDL handlers still call the deprecated `AccountInfo::realloc`
+#![allow(deprecated)]
+// Profile and project instructions take many optional fields, mirrored by the generated CPI helpers
+#![allow(clippy::too_many_arguments)]
+
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use solana_curve25519::edwards::{validate_edwards, PodEdwardsPoint};
 
 declare_id!("CGyvTakWk8D9vj9cqhTJ4Er15fzb2phPVQLyLuyWGCNT");
 
+/// Max plaintext contact info length (bytes)
+pub const CONTACT_INFO_MAX_LEN: usize = 200;
+/// Max sealed contact info length: 24-byte nonce + plaintext + 16-byte tag
+pub const SEALED_CONTACT_MAX_LEN: usize = 240;
+/// Max key envelope length: X25519 sealed box of a 32-byte key (32 + 16 + 32)
+pub const CONTACT_ENVELOPE_MAX_LEN: usize = 80;
+/// Window after sending in which a bonded request can be withdrawn (24 hours)
+pub const BOND_WITHDRAW_GRACE_PERIOD: i64 = 24 * 60 * 60;
+/// Time a project has to answer a bonded request before the applicant can pull it (14 days)
+pub const BOND_REVIEW_PERIOD: i64 = 14 * 24 * 60 * 60;
+/// Time after a missed response deadline in which the applicant can claim the SLA penalty (7 days)
+pub const SLA_CLAIM_PERIOD: i64 = 7 * 24 * 60 * 60;
+/// Max approvers per project and max proposal lifetime (30 days)
+pub const MAX_APPROVERS: usize = 10;
+pub const MAX_PROPOSAL_TTL: i64 = 30 * 24 * 60 * 60;
+/// Max members per organization
+pub const MAX_ORG_MEMBERS: usize = 20;
+/// Max length of a name used as a PDA seed (bytes; Solana's per-seed limit)
+pub const SEED_NAME_MAX_LEN: usize = 32;
+/// Max SPL mints allowlisted for payments
+pub const MAX_ALLOWED_MINTS: usize = 16;
+/// Max arbiters in the config set, their max fee (10%) and the default ruling window (14 days)
+pub const MAX_ARBITERS: usize = 8;
+pub const MAX_ARBITER_FEE_BPS: u16 = 1_000;
+pub const DEFAULT_DISPUTE_TIMEOUT: i64 = 14 * 24 * 60 * 60;
+/// Max note a donor can attach to a donation (bytes)
+pub const DONATION_MEMO_MAX_LEN: usize = 140;
+/// Max projects per grant round
+pub const MAX_ROUND_PROJECTS: usize = 16;
+/// Sybil weighting of quadratic-funding votes (bps of a contributor's square-root term):
+/// verified users count fully; others start at a quarter and gain per reputation point
+pub const UNVERIFIED_WEIGHT_BPS: u64 = 2_500;
+pub const REPUTATION_WEIGHT_BPS: u64 = 250;
+/// Most reputation one project can credit a member; approvals are owner-controlled, so a
+/// single project can't carry anyone to full sybil weight
+pub const MAX_PROJECT_REPUTATION: u32 = 5;
+/// Max judges, tracks and prize places per event
+pub const MAX_EVENT_JUDGES: usize = 8;
+pub const MAX_EVENT_TRACKS: usize = 8;
+pub const MAX_EVENT_PRIZES: usize = 8;
+/// Max projects per event; rankings are submitted in one transaction
+pub const MAX_EVENT_PROJECTS: u32 = 24;
+/// Highest score a judge can give
+pub const MAX_JUDGE_SCORE: u8 = 100;
+/// Max members assigned to one milestone
+pub const MAX_MILESTONE_ASSIGNEES: usize = 4;
+/// Max labels (project tech tags) per task
+pub const MAX_TASK_LABELS: usize = 4;
+/// Rate limit windows (seconds)
+pub const HOUR: i64 = 60 * 60;
+pub const DAY: i64 = 24 * HOUR;
+pub const WEEK: i64 = 7 * DAY;
+
 #[program]
 pub mod devcol_solana {
     use super::*;
 
+    // ==================== CONFIG INSTRUCTIONS ====================
+
+    /// Create the global config (only by the program's upgrade authority)
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        limits: RateLimits,
+        verified_limits: RateLimits,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.limits = limits;
+        config.verified_limits = verified_limits;
+        config.allowed_mints = Vec::new();
+        config.arbiters = Vec::new();
+        config.arbiter_fee_bps = 0;
+        config.dispute_timeout = DEFAULT_DISPUTE_TIMEOUT;
+        config.disputes_opened = 0;
+        config.bump = ctx.bumps.config;
+        msg!("Config initialized with admin {}", config.admin);
+        Ok(())
+    }
+
+    /// Update global config (admin only)
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_admin: Option<Pubkey>,
+        limits: Option<RateLimits>,
+        verified_limits: Option<RateLimits>,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        if let Some(admin) = new_admin {
+            config.admin = admin;
+        }
+        if let Some(new_limits) = limits {
+            config.limits = new_limits;
+        }
+        if let Some(new_limits) = verified_limits {
+            config.verified_limits = new_limits;
+        }
+        msg!("Config updated");
+        Ok(())
+    }
+
+    /// Allow or disallow an SPL Token / Token-2022 mint for project payments (admin only)
+    pub fn set_mint_allowed(ctx: Context<UpdateConfig>, mint: Pubkey, allowed: bool) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let listed = config.allowed_mints.iter().position(|m| m == &mint);
+        match (listed, allowed) {
+            (None, true) => {
+                require!(config.allowed_mints.len() < MAX_ALLOWED_MINTS, ErrorCode::TooManyAllowedMints);
+                config.allowed_mints.push(mint);
+            }
+            (Some(i), false) => {
+                config.allowed_mints.swap_remove(i);
+            }
+            _ => {}
+        }
+        msg!("Mint {} allowed: {}", mint, allowed);
+        Ok(())
+    }
+
+    /// Add or remove a wallet from the arbiter set disputes are assigned from (admin only)
+    pub fn set_arbiter(ctx: Context<UpdateConfig>, arbiter: Pubkey, allowed: bool) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let listed = config.arbiters.iter().position(|a| a == &arbiter);
+        match (listed, allowed) {
+            (None, true) => {
+                require!(config.arbiters.len() < MAX_ARBITERS, ErrorCode::TooManyArbiters);
+                config.arbiters.push(arbiter);
+            }
+            (Some(i), false) => {
+                config.arbiters.swap_remove(i);
+            }
+            _ => {}
+        }
+        msg!("Arbiter {} allowed: {}", arbiter, allowed);
+        Ok(())
+    }
+
+    /// Set the arbiter fee and how long an arbiter has to rule (admin only; applies to new disputes)
+    pub fn set_dispute_terms(
+        ctx: Context<UpdateConfig>,
+        arbiter_fee_bps: u16,
+        dispute_timeout: i64,
+    ) -> Result<()> {
+        require!(
+            arbiter_fee_bps <= MAX_ARBITER_FEE_BPS && dispute_timeout > 0,
+            ErrorCode::InvalidDisputeTerms
+        );
+        let config = &mut ctx.accounts.config;
+        config.arbiter_fee_bps = arbiter_fee_bps;
+        config.dispute_timeout = dispute_timeout;
+        msg!("Dispute terms: {} bps fee, {}s to rule", arbiter_fee_bps, dispute_timeout);
+        Ok(())
+    }
+
+    /// Set or clear a user's verified badge (admin only)
+    pub fn set_user_verified(ctx: Context<SetUserVerified>, verified: bool) -> Result<()> {
+        let user = &mut ctx.accounts.user;
+        user.is_verified = verified;
+        msg!("User {} verified: {}", user.username, verified);
+        Ok(())
+    }
+
     // ==================== USER INSTRUCTIONS ====================
     
     /// Initialize a new user profile with enhanced fields
@@ -27,8 +192,8 @@ pub mod devcol_solana {
         require!(bio.len() <= 200, ErrorCode::BioTooLong);
         require!(github_link.len() <= 100, ErrorCode::GithubLinkTooLong);
         require!(ipfs_metadata_hash.len() <= 64, ErrorCode::IpfsHashTooLong);
-        require!(contact_info.len() <= 200, ErrorCode::ContactInfoTooLong);
-        // Contact info is optional - can be empty string and added later via update
+        // Contact info is only ever stored sealed, through `set_sealed_contact_info`
+        require!(contact_info.is_empty(), ErrorCode::ContactInfoMustBeSealed);
 
         let user = &mut ctx.accounts.user;
         let clock = Clock::get()?;
@@ -51,6 +216,16 @@ pub mod devcol_solana {
         user.open_to_collab = true;
         user.profile_visibility = ProfileVisibility::Public;
         user.bump = ctx.bumps.user;
+        user.encryption_pubkey = [0u8; 32];
+        user.sealed_contact_info = Vec::new();
+        user.contact_key_envelope = Vec::new();
+        user.followers_count = 0;
+        user.following_count = 0;
+
+        // Rate windows carry over from any earlier profile of this wallet
+        let rate_state = &mut ctx.accounts.rate_state;
+        rate_state.wallet = ctx.accounts.signer.key();
+        rate_state.bump = ctx.bumps.rate_state;
 
         msg!("Enhanced user profile created: {}", user.username);
         Ok(())
@@ -58,9 +233,28 @@ pub mod devcol_solana {
 
 #[derive(Accounts)]
 pub struct UpdateProjectRoles<'info> {
-    #[account(mut, has_one = creator)]
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
     pub project: Account<'info, Project>,
-    pub creator: Signer<'info>,
+
+    // Creator, or a delegate holding MANAGE_MEMBERS
+    #[account(
+        constraint = project.is_authorized(&authority.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_MEMBERS)
+            @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), authority.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
 }
 
 #[derive(Accounts)]
@@ -74,6 +268,14 @@ pub struct DeleteProject<'info> {
 
     #[account(mut)]
     pub creator: Signer<'info>,
+
+    /// CHECK: Creator's User PDA; may already be closed
+    #[account(mut, seeds = [b"user", creator.key().as_ref()], bump)]
+    pub creator_user: UncheckedAccount<'info>,
+
+    /// CHECK: Creator's ProgramCreator PDA; empty unless the creator is program-owned
+    #[account(mut, seeds = [b"program_creator", creator.key().as_ref()], bump)]
+    pub program_creator: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
@@ -93,6 +295,22 @@ pub struct UpdateCollabMessage<'info> {
     /// The sender who created the request
     #[account(mut)]
     pub sender: Signer<'info>,
+
+    #[account(
+        seeds = [b"user", sender.key().as_ref()],
+        bump = sender_user.bump
+    )]
+    pub sender_user: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [b"rate_state", sender.key().as_ref()],
+        bump = rate_state.bump
+    )]
+    pub rate_state: Account<'info, RateState>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
 }
 
 #[derive(Accounts)]
@@ -132,7 +350,7 @@ pub struct DeleteCollabRequest<'info> {
     )]
     pub collab_request: Account<'info, CollaborationRequest>,
 
-    #[account(mut)]
+    #[account(mut, address = to.key() @ ErrorCode::Unauthorized)]
     pub project_owner: Signer<'info>,
 
     /// CHECK: Must match the 'to' address on the request
@@ -175,7 +393,12 @@ pub struct DeleteSenderRejectedRequest<'info> {
         profile_visibility: Option<ProfileVisibility>,
     ) -> Result<()> {
         let user = &mut ctx.accounts.user;
-        let clock = Clock::get()?;
+        let now = Clock::get()?.unix_timestamp;
+        let limits = ctx.accounts.config.limits_for(user);
+        require!(
+            ctx.accounts.rate_state.update_window.try_hit(now, HOUR, limits.updates_per_hour),
+            ErrorCode::UpdateRateLimited
+        );
 
         if let Some(new_display_name) = display_name {
             require!(new_display_name.len() <= 64, ErrorCode::DisplayNameTooLong);
@@ -202,8 +425,8 @@ pub struct DeleteSenderRejectedRequest<'info> {
             user.ipfs_metadata_hash = new_hash;
         }
         if let Some(new_contact) = contact_info {
-            require!(new_contact.len() <= 200, ErrorCode::ContactInfoTooLong);
-            // Contact info can be empty - it's optional
+            // Plaintext can only be cleared; set contact info sealed via `set_sealed_contact_info`
+            require!(new_contact.is_empty(), ErrorCode::ContactInfoMustBeSealed);
             user.contact_info = new_contact;
         }
         if let Some(collab) = open_to_collab {
@@ -214,60 +437,67 @@ pub struct DeleteSenderRejectedRequest<'info> {
         }
 
         // Update last active timestamp
-        let clock = Clock::get()?;
-        user.last_active = clock.unix_timestamp;
+        user.last_active = now;
         
         msg!("User profile updated: {}", user.username);
         Ok(())
     }
 
-    /// Migrate old user account to new schema with contact_info field
-    pub fn migrate_user_account(
-        ctx: Context<MigrateUser>,
-        contact_info: String,
-    ) -> Result<()> {
-        require!(contact_info.len() <= 200, ErrorCode::ContactInfoTooLong);
-        require!(!contact_info.trim().is_empty(), ErrorCode::ContactInfoRequired);
-
-        let user_account = &ctx.accounts.user.to_account_info();
-        let current_size = user_account.data_len();
-        let new_size = 8 + User::INIT_SPACE; // 8 bytes discriminator + User size
-
-        // Only migrate if account is smaller than expected (old schema)
-        if current_size < new_size {
-            // Realloc to new size
-            user_account.realloc(new_size, false)?;
-            
-            // Refund or charge rent difference
-            let rent = Rent::get()?;
-            let new_minimum_balance = rent.minimum_balance(new_size);
-            let current_balance = user_account.lamports();
-            
-            if current_balance < new_minimum_balance {
-                let diff = new_minimum_balance - current_balance;
-                anchor_lang::system_program::transfer(
-                    CpiContext::new(
-                        ctx.accounts.system_program.to_account_info(),
-                        anchor_lang::system_program::Transfer {
-                            from: ctx.accounts.signer.to_account_info(),
-                            to: user_account.clone(),
-                        },
-                    ),
-                    diff,
-                )?;
-            }
+    /// Migrate old user account to the current schema (owner only)
+    ///
+    /// Plaintext contact info is cleared; it can only be stored again sealed, through
+    /// `set_sealed_contact_info`.
+    pub fn migrate_user_account(ctx: Context<MigrateUser>) -> Result<()> {
+        let user_account = ctx.accounts.user.to_account_info();
+        grow_account(
+            &user_account,
+            &ctx.accounts.signer,
+            &ctx.accounts.system_program,
+            8 + User::INIT_SPACE,
+        )?;
 
-            msg!("Account migrated from {} to {} bytes", current_size, new_size);
-        }
+        // Old layouts are a prefix of the current one; appended fields read back as zeroes
+        let mut data = user_account.try_borrow_mut_data()?;
+        let mut user = User::try_deserialize(&mut &data[..])?;
+        user.contact_info = String::new();
+        user.try_serialize(&mut &mut data[..])?;
+
+        let rate_state = &mut ctx.accounts.rate_state;
+        rate_state.wallet = ctx.accounts.signer.key();
+        rate_state.bump = ctx.bumps.rate_state;
 
-        // Set the contact_info field
-        let user = &mut ctx.accounts.user;
-        user.contact_info = contact_info;
-        
         msg!("User account migrated successfully: {}", user.username);
         Ok(())
     }
 
+    /// Register an x25519 key and store contact info sealed under it (clears plaintext contact info)
+    pub fn set_sealed_contact_info(
+        ctx: Context<UpdateUser>,
+        encryption_pubkey: [u8; 32],
+        sealed_contact_info: Vec<u8>,
+        contact_key_envelope: Vec<u8>,
+    ) -> Result<()> {
+        require!(encryption_pubkey != [0u8; 32], ErrorCode::EncryptionKeyNotRegistered);
+        require!(
+            sealed_contact_info.len() <= SEALED_CONTACT_MAX_LEN,
+            ErrorCode::SealedContactTooLong
+        );
+        require!(
+            contact_key_envelope.len() <= CONTACT_ENVELOPE_MAX_LEN,
+            ErrorCode::ContactEnvelopeTooLong
+        );
+
+        let user = &mut ctx.accounts.user;
+        user.encryption_pubkey = encryption_pubkey;
+        user.sealed_contact_info = sealed_contact_info;
+        user.contact_key_envelope = contact_key_envelope;
+        user.contact_info = String::new();
+        user.last_active = Clock::get()?.unix_timestamp;
+
+        msg!("Sealed contact info set for: {}", user.username);
+        Ok(())
+    }
+
     /// Delete user profile (closes account and returns SOL)
     pub fn delete_user(_ctx: Context<DeleteUser>) -> Result<()> {
         // Account will be closed automatically via the 'close' constraint
@@ -292,67 +522,44 @@ pub struct DeleteSenderRejectedRequest<'info> {
         project_status: ProjectStatus,
         required_roles: Vec<RoleRequirement>,
     ) -> Result<()> {
-        require!(name.len() <= 50, ErrorCode::NameTooLong);
-        require!(description.len() <= 1000, ErrorCode::DescriptionTooLong);
-        require!(github_link.len() <= 100, ErrorCode::GithubLinkTooLong);
-        require!(tech_stack.len() <= 12, ErrorCode::TechTagCountExceeded);
-        require!(contribution_needs.len() <= 10, ErrorCode::NeedTagCountExceeded);
-        require!(collab_intent.len() <= 300, ErrorCode::CollabIntentTooLong);
-        require!(required_roles.len() <= 8, ErrorCode::TooManyRoles);
-        for t in tech_stack.iter() { require!(t.len() <= 24, ErrorCode::TechTagTooLong); }
-        for n in contribution_needs.iter() { require!(n.len() <= 24, ErrorCode::NeedTagTooLong); }
-        for r in required_roles.iter() {
-            // Validate needed range
-            require!(r.needed > 0, ErrorCode::InvalidRoleCounts);
-            require!(r.needed <= 10, ErrorCode::RoleNeededTooLarge);
-            // If role is Others then a label is encouraged (optionally enforce non-empty)
-            if let Role::Others = r.role {
-                if let Some(lbl) = &r.label {
-                    require!(lbl.len() <= 24, ErrorCode::RoleLabelTooLong);
-                }
-            }
-        }
+        let clock = Clock::get()?;
+        let user = &mut ctx.accounts.user;
+        let limits = ctx.accounts.config.limits_for(user);
+        require!(
+            ctx.accounts
+                .rate_state
+                .project_window
+                .try_hit(clock.unix_timestamp, WEEK, limits.projects_per_week),
+            ErrorCode::ProjectRateLimited
+        );
+
+        user.projects_count = user.projects_count.saturating_add(1);
 
         let project = &mut ctx.accounts.project;
-        let clock = Clock::get()?;
-        
-        project.creator = ctx.accounts.creator.key();
-        project.name = name;
-        project.description = description;
-        project.github_link = github_link;
-        project.logo_ipfs_hash = logo_ipfs_hash;
-        project.tech_stack = tech_stack.into_iter().map(|v| ShortText{ value: v }).collect();
-        project.contribution_needs = contribution_needs.into_iter().map(|v| ShortText{ value: v }).collect();
-        project.collab_intent = collab_intent;
-        project.collaboration_level = collaboration_level;
-        project.project_status = project_status;
-        project.accepting_collaborations = CollaborationAcceptance::Open; // Default: open for collaboration
-        project.timestamp = clock.unix_timestamp;
-        project.last_updated = clock.unix_timestamp;
-        project.contributors_count = 1; // Creator is first contributor
-        project.is_active = true;
-        project.bump = ctx.bumps.project;
-        // Normalize labels: keep labels only for Others, clear for fixed roles
-        project.required_roles = required_roles
-            .into_iter()
-            .map(|mut rr| {
-                match rr.role {
-                    Role::Others => {
-                        // Keep label (already validated if present)
-                    },
-                    _ => {
-                        rr.label = None;
-                    }
-                }
-                rr
-            })
-            .collect();
+        init_project(
+            project,
+            ctx.accounts.creator.key(),
+            ctx.bumps.project,
+            CreateProjectArgs {
+                name,
+                description,
+                github_link,
+                logo_ipfs_hash,
+                tech_stack,
+                contribution_needs,
+                collab_intent,
+                collaboration_level,
+                project_status,
+                required_roles,
+            },
+            clock.unix_timestamp,
+        )?;
 
         msg!("Project created: {} by {}", project.name, project.creator);
         Ok(())
     }
 
-    /// Update project details (only by creator)
+    /// Update project details (creator or EDIT_PROJECT delegate)
     pub fn update_project(
         ctx: Context<UpdateProject>,
         name: Option<String>,
@@ -364,7 +571,24 @@ pub struct DeleteSenderRejectedRequest<'info> {
         collaboration_level: Option<CollaborationLevel>,
         project_status: Option<ProjectStatus>,
         is_active: Option<bool>,
+        application_bond: Option<u64>,
+        applicant_requirements: Option<ApplicantRequirements>,
     ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let config = &ctx.accounts.config;
+        let (window, limits) = if let Some(user) = ctx.accounts.authority_user.as_deref() {
+            let rate_state = ctx.accounts.rate_state.as_deref_mut().ok_or(ErrorCode::RateStateRequired)?;
+            (&mut rate_state.update_window, config.limits_for(user))
+        } else if let Some(creator) = ctx.accounts.program_creator.as_deref_mut() {
+            (&mut creator.update_window, config.limits)
+        } else {
+            return err!(ErrorCode::ProfileRequired);
+        };
+        require!(
+            window.try_hit(now, HOUR, limits.updates_per_hour),
+            ErrorCode::UpdateRateLimited
+        );
+
         let project = &mut ctx.accounts.project;
 
         if let Some(new_name) = name {
@@ -402,14 +626,94 @@ pub struct DeleteSenderRejectedRequest<'info> {
         if let Some(active) = is_active {
             project.is_active = active;
         }
+        if let Some(bond) = application_bond {
+            // 0 disables the bond; only affects requests sent afterwards
+            project.application_bond = bond;
+        }
+        if let Some(requirements) = applicant_requirements {
+            require!(requirements.min_account_age >= 0, ErrorCode::InvalidApplicantRequirements);
+            project.applicant_requirements = requirements;
+        }
 
         // Update last_updated timestamp
-        project.last_updated = Clock::get()?.unix_timestamp;
+        project.last_updated = now;
 
         msg!("Project updated: {}", project.name);
         Ok(())
     }
 
+    /// Migrate old project account to the current schema (owner only)
+    pub fn migrate_project_account(ctx: Context<MigrateProject>) -> Result<()> {
+        let project_account = ctx.accounts.project.to_account_info();
+        grow_account(
+            &project_account,
+            &ctx.accounts.signer,
+            &ctx.accounts.system_program,
+            8 + Project::INIT_SPACE,
+        )?;
+
+        let mut data = project_account.try_borrow_mut_data()?;
+        let mut project = Project::try_deserialize(&mut &data[..])?;
+        require_keys_eq!(project.creator, ctx.accounts.signer.key(), ErrorCode::Unauthorized);
+        // Projects from before ownership transfers were seeded by their (only) creator
+        if project.seed_creator == Pubkey::default() {
+            project.seed_creator = project.creator;
+        }
+        project.try_serialize(&mut &mut data[..])?;
+
+        msg!("Project account migrated successfully: {}", project.name);
+        Ok(())
+    }
+
+    // ==================== PROGRAM CREATOR INSTRUCTIONS ====================
+
+    /// Register a program-owned authority (multisig vault, DAO treasury PDA) as a project creator
+    ///
+    /// The authority signs through `invoke_signed`; rent is paid by the separate `payer`.
+    pub fn register_program_creator(
+        ctx: Context<RegisterProgramCreator>,
+        display_name: String,
+    ) -> Result<()> {
+        require!(display_name.len() <= 64, ErrorCode::DisplayNameTooLong);
+
+        let creator = &mut ctx.accounts.program_creator;
+        creator.authority = ctx.accounts.authority.key();
+        creator.display_name = display_name;
+        creator.projects_count = 0;
+        creator.project_window = RateWindow::default();
+        creator.update_window = RateWindow::default();
+        creator.created_at = Clock::get()?.unix_timestamp;
+        creator.bump = ctx.bumps.program_creator;
+
+        msg!("Program creator registered: {}", creator.authority);
+        Ok(())
+    }
+
+    /// Create a project owned by a registered program creator
+    ///
+    /// CPI entry point for authorities that cannot hold a `User` profile. Callers use
+    /// `devcol_solana::cpi::create_program_project` with `CpiContext::new_with_signer`,
+    /// signing for `creator`. The resulting project is administered through the usual
+    /// `UpdateProject` instructions, passing `program_creator` instead of `authority_user`.
+    pub fn create_program_project(
+        ctx: Context<CreateProgramProject>,
+        args: CreateProjectArgs,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let creator = &mut ctx.accounts.program_creator;
+        require!(
+            creator.project_window.try_hit(now, WEEK, ctx.accounts.config.limits.projects_per_week),
+            ErrorCode::ProjectRateLimited
+        );
+        creator.projects_count = creator.projects_count.saturating_add(1);
+
+        let project = &mut ctx.accounts.project;
+        init_project(project, ctx.accounts.creator.key(), ctx.bumps.project, args, now)?;
+
+        msg!("Project created: {} by program creator {}", project.name, project.creator);
+        Ok(())
+    }
+
     // ==================== COLLABORATION REQUEST INSTRUCTIONS ====================
     
     /// Send a collaboration request
@@ -420,17 +724,17 @@ pub struct DeleteSenderRejectedRequest<'info> {
     ) -> Result<()> {
         require!(message.len() <= 500, ErrorCode::MessageTooLong);
 
+        let now = Clock::get()?.unix_timestamp;
+        let sender_user = &mut ctx.accounts.sender_user;
+        let limits = ctx.accounts.config.limits_for(sender_user);
+        require!(
+            ctx.accounts.rate_state.request_window.try_hit(now, DAY, limits.requests_per_day),
+            ErrorCode::RequestRateLimited
+        );
+
         let project = &ctx.accounts.project;
-        
-        // If roles are defined on the project and a role is specified, validate capacity
-        if let Some(ref role) = desired_role {
-            if !project.required_roles.is_empty() {
-                let role_req = project.required_roles.iter()
-                    .find(|r| &r.role == role)
-                    .ok_or(ErrorCode::RoleNotFound)?;
-                require!(role_req.accepted < role_req.needed, ErrorCode::RoleSlotFull);
-            }
-        }
+        check_application(project, sender_user, desired_role.as_ref(), now)?;
+        let bond_amount = posted_application_bond(project, &ctx.accounts.application_bond)?;
 
         let request = &mut ctx.accounts.collab_request;
         request.from = ctx.accounts.sender.key();
@@ -439,10 +743,20 @@ pub struct DeleteSenderRejectedRequest<'info> {
         request.message = message;
         request.owner_message = String::new(); // Initialize as empty
         request.status = RequestStatus::Pending;
-        request.timestamp = Clock::get()?.unix_timestamp;
+        request.timestamp = now;
         request.reply_timestamp = 0; // Initialize as 0 (no reply yet)
         request.bump = ctx.bumps.collab_request;
         request.desired_role = desired_role;
+        request.message_count = 0;
+        request.last_activity = request.timestamp;
+        request.thread_activity = 0;
+        request.left_at = 0;
+        request.bond_amount = bond_amount;
+        request.response_deadline = if project.response_window > 0 {
+            request.timestamp.saturating_add(project.response_window)
+        } else {
+            0
+        };
 
         msg!(
             "Collaboration request sent from {} to {} for project {}",
@@ -453,7 +767,7 @@ pub struct DeleteSenderRejectedRequest<'info> {
         Ok(())
     }
 
-    /// Mark request as under review (only by project owner)
+    /// Mark request as under review (project owner or REVIEW_REQUESTS delegate)
     pub fn mark_under_review(ctx: Context<UpdateCollabRequest>) -> Result<()> {
         let request = &mut ctx.accounts.collab_request;
         require!(
@@ -461,6 +775,7 @@ pub struct DeleteSenderRejectedRequest<'info> {
             ErrorCode::InvalidRequestStatus
         );
         request.status = RequestStatus::UnderReview;
+        request.last_activity = Clock::get()?.unix_timestamp;
         msg!("Collaboration request marked under review: {:?}", request.key());
         Ok(())
     }
@@ -470,26 +785,27 @@ pub struct DeleteSenderRejectedRequest<'info> {
         ctx: Context<UpdateCollabRequest>,
         owner_message: String,
     ) -> Result<()> {
-        require!(owner_message.len() <= 500, ErrorCode::MessageTooLong);
-        let request = &mut ctx.accounts.collab_request;
         require!(
-            request.status == RequestStatus::Pending || request.status == RequestStatus::UnderReview,
-            ErrorCode::InvalidRequestStatus
+            ctx.accounts.project.approval_threshold == 0,
+            ErrorCode::ApprovalRequired
         );
-
-        // Increment accepted count for the role if specified
-        if let Some(ref role) = request.desired_role {
-            let project = &mut ctx.accounts.project;
-            if let Some(role_req) = project.required_roles.iter_mut().find(|r| &r.role == role) {
-                require!(role_req.accepted < role_req.needed, ErrorCode::RoleSlotFull);
-                role_req.accepted += 1;
-            }
-        }
-
-        request.status = RequestStatus::Accepted;
-        request.owner_message = owner_message;
-        request.reply_timestamp = Clock::get()?.unix_timestamp;
-        msg!("Collaboration request accepted: {:?}", request.key());
+        // Delegates and org admins keep their own blocklists, and the owner may block after the send
+        require!(ctx.accounts.block.data_is_empty(), ErrorCode::SenderBlocked);
+        require!(ctx.accounts.owner_block.data_is_empty(), ErrorCode::SenderBlocked);
+        let stats = ctx
+            .accounts
+            .member_stats
+            .as_deref_mut()
+            .ok_or(ErrorCode::MemberStatsRequired)?;
+        apply_accept(
+            &mut ctx.accounts.project,
+            &mut ctx.accounts.collab_request,
+            stats,
+            ctx.bumps.member_stats.unwrap_or_default(),
+            &ctx.accounts.sender_user,
+            owner_message,
+        )?;
+        msg!("Collaboration request accepted: {:?}", ctx.accounts.collab_request.key());
         Ok(())
     }
 
@@ -508,6 +824,10 @@ pub struct DeleteSenderRejectedRequest<'info> {
         request.status = RequestStatus::Rejected;
         request.owner_message = owner_message;
         request.reply_timestamp = Clock::get()?.unix_timestamp;
+        request.last_activity = request.reply_timestamp;
+        ctx.accounts
+            .project
+            .record_response(request.reply_timestamp - request.timestamp);
         msg!("Collaboration request rejected: {:?}", request.key());
         Ok(())
     }
@@ -518,7 +838,14 @@ pub struct DeleteSenderRejectedRequest<'info> {
         let request = &mut ctx.accounts.collab_request;
         require!(request.status == RequestStatus::Pending, ErrorCode::InvalidRequestStatus);
         // Sender constraint enforced in context
+        let now = Clock::get()?.unix_timestamp;
+        let limits = ctx.accounts.config.limits_for(&ctx.accounts.sender_user);
+        require!(
+            ctx.accounts.rate_state.update_window.try_hit(now, HOUR, limits.updates_per_hour),
+            ErrorCode::UpdateRateLimited
+        );
         request.message = message;
+        request.last_activity = now;
         msg!("Collaboration request message updated: {:?}", request.key());
         Ok(())
     }
@@ -526,7 +853,22 @@ pub struct DeleteSenderRejectedRequest<'info> {
     /// Withdraw a pending collaboration request (closes account; only by sender)
     pub fn withdraw_collab_request(ctx: Context<WithdrawCollabRequest>) -> Result<()> {
         let request = &ctx.accounts.collab_request;
-        require!(request.status == RequestStatus::Pending, ErrorCode::InvalidRequestStatus);
+        let now = Clock::get()?.unix_timestamp;
+        // A bonded request the project left unanswered can be pulled back, even once under review
+        let unanswered = request.bond_amount > 0
+            && now > request.timestamp.saturating_add(BOND_REVIEW_PERIOD);
+        require!(
+            request.status == RequestStatus::Pending
+                || (unanswered && request.status == RequestStatus::UnderReview),
+            ErrorCode::InvalidRequestStatus
+        );
+        // Otherwise bonded requests can only be pulled back within the grace window
+        if request.bond_amount > 0 && !unanswered {
+            require!(
+                now <= request.timestamp.saturating_add(BOND_WITHDRAW_GRACE_PERIOD),
+                ErrorCode::BondGracePeriodElapsed
+            );
+        }
         // Account will be closed to sender via context 'close'
         msg!("Collaboration request withdrawn: {:?}", request.key());
         Ok(())
@@ -541,374 +883,7791 @@ pub struct DeleteSenderRejectedRequest<'info> {
         Ok(())
     }
 
-    /// Delete sender's own rejected collaboration request (allows reapplying)
+    /// Delete sender's own rejected or expired collaboration request (allows reapplying)
     pub fn delete_sender_rejected_request(ctx: Context<DeleteSenderRejectedRequest>) -> Result<()> {
         let request = &ctx.accounts.collab_request;
-        require!(request.status == RequestStatus::Rejected, ErrorCode::InvalidRequestStatus);
+        require!(
+            request.status == RequestStatus::Rejected || request.status == RequestStatus::Expired,
+            ErrorCode::InvalidRequestStatus
+        );
         // Account will be closed to sender via context 'close'
-        msg!("Sender deleted their rejected request: {:?}", request.key());
+        msg!("Sender deleted their closed request: {:?}", request.key());
         Ok(())
     }
 
-    /// Permanently delete a project and refund lamports to the creator
-    pub fn delete_project(ctx: Context<DeleteProject>) -> Result<()> {
-        // The account is closed to the creator by the context attribute
-        let pk = ctx.accounts.project.key();
-        msg!("Project deleted: {:?}", pk);
+    /// Leave a project as an accepted member, freeing the role slot and pausing any stream
+    pub fn leave_project(ctx: Context<LeaveProject>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let request = &mut ctx.accounts.collab_request;
+        require!(request.status == RequestStatus::Accepted, ErrorCode::InvalidRequestStatus);
+        request.status = RequestStatus::Left;
+        request.last_activity = now;
+        request.left_at = now;
+
+        if let Some(ref role) = request.desired_role {
+            let project = &mut ctx.accounts.project;
+            if let Some(role_req) = project.required_roles.iter_mut().find(|r| &r.role == role) {
+                role_req.accepted = role_req.accepted.saturating_sub(1);
+            }
+        }
+        update_if_exists::<Stream>(&ctx.accounts.stream, |stream| stream.pause(now))?;
+
+        msg!("{} left project {}", request.from, request.project);
         Ok(())
     }
 
-    /// Close project for collaboration (stop accepting requests)
-    pub fn close_project(ctx: Context<UpdateProject>) -> Result<()> {
-        let project = &mut ctx.accounts.project;
-        project.accepting_collaborations = CollaborationAcceptance::Closed;
-        project.last_updated = Clock::get()?.unix_timestamp;
-        msg!("Project closed for collaboration: {}", project.name);
+    // ==================== MEMBERSHIP INSTRUCTIONS ====================
+
+    /// Read-only check that `member` belongs to the project, optionally in `role`
+    ///
+    /// Fails with `NotAMember`/`MemberRoleMismatch` unless the relationship holds; on success
+    /// the `Membership` is returned via `set_return_data`. Pass the member's accepted
+    /// collaboration request, or omit it to check the project owner. Other programs call
+    /// this through `devcol_solana::cpi::assert_membership` and read the result with `.get()`.
+    pub fn assert_membership(
+        ctx: Context<AssertMembership>,
+        member: Pubkey,
+        role: Option<Role>,
+    ) -> Result<Membership> {
+        let project = &ctx.accounts.project;
+        let membership = match ctx.accounts.collab_request.as_deref() {
+            Some(request) => {
+                require!(request.status == RequestStatus::Accepted, ErrorCode::NotAMember);
+                Membership {
+                    project: project.key(),
+                    member,
+                    is_owner: false,
+                    role: request.desired_role.clone(),
+                    joined_at: request.reply_timestamp,
+                }
+            }
+            None => {
+                require_keys_eq!(project.creator, member, ErrorCode::NotAMember);
+                Membership {
+                    project: project.key(),
+                    member,
+                    is_owner: true,
+                    role: None,
+                    joined_at: project.timestamp,
+                }
+            }
+        };
+        if let Some(role) = role {
+            require!(membership.role.as_ref() == Some(&role), ErrorCode::MemberRoleMismatch);
+        }
+
+        msg!("Membership confirmed: {} in {}", member, membership.project);
+        Ok(membership)
+    }
+
+    // ==================== VIEW INSTRUCTIONS ====================
+
+    /// Remaining slots per role; returned via `set_return_data` for use with simulateTransaction
+    pub fn get_open_slots(ctx: Context<ProjectView>) -> Result<OpenSlots> {
+        let project = &ctx.accounts.project;
+        Ok(OpenSlots {
+            accepting: project.is_active
+                && project.accepting_collaborations == CollaborationAcceptance::Open,
+            slots: project
+                .required_roles
+                .iter()
+                .map(|r| OpenSlot {
+                    role: r.role.clone(),
+                    label: r.label.clone(),
+                    open: r.needed.saturating_sub(r.accepted),
+                })
+                .collect(),
+        })
+    }
+
+    /// Whether `applicant` could send a request for `desired_role` right now
+    ///
+    /// Runs the same checks as `send_collab_request` without changing any state; the first
+    /// failing check is reported as its error code instead of failing the instruction.
+    pub fn can_apply(
+        ctx: Context<CanApply>,
+        applicant: Pubkey,
+        desired_role: Option<Role>,
+    ) -> Result<Eligibility> {
+        let now = Clock::get()?.unix_timestamp;
+        let accounts = &ctx.accounts;
+        let result = (|| -> Result<()> {
+            require!(accounts.collab_request.data_is_empty(), ErrorCode::RequestAlreadySent);
+            require!(accounts.block.data_is_empty(), ErrorCode::BlockedByUser);
+            let user = &accounts.applicant_user;
+            let limits = accounts.config.limits_for(user);
+            let mut window = accounts.rate_state.request_window;
+            require!(
+                window.try_hit(now, DAY, limits.requests_per_day),
+                ErrorCode::RequestRateLimited
+            );
+            check_application(&accounts.project, user, desired_role.as_ref(), now)?;
+            posted_application_bond(&accounts.project, &accounts.application_bond)?;
+            Ok(())
+        })();
+
+        let eligibility = Eligibility::from_check(result);
+        msg!("Eligibility of {}: {}", applicant, eligibility.error_code);
+        Ok(eligibility)
+    }
+
+    /// Public profile counters; returned via `set_return_data`
+    pub fn get_profile_summary(ctx: Context<ProfileView>) -> Result<ProfileSummary> {
+        let user = &ctx.accounts.user;
+        Ok(ProfileSummary {
+            wallet: user.wallet,
+            username: user.username.clone(),
+            display_name: user.display_name.clone(),
+            reputation: user.reputation,
+            projects_count: user.projects_count,
+            collabs_count: user.collabs_count,
+            followers_count: user.followers_count,
+            following_count: user.following_count,
+            member_since: user.member_since,
+            is_verified: user.is_verified,
+            open_to_collab: user.open_to_collab,
+            profile_visibility: user.profile_visibility.clone(),
+        })
+    }
+
+    // ==================== APPLICATION BOND INSTRUCTIONS ====================
+
+    /// Deposit the project's application bond ahead of `send_collab_request`
+    pub fn deposit_application_bond(ctx: Context<DepositApplicationBond>) -> Result<()> {
+        let amount = ctx.accounts.project.application_bond;
+        require!(amount > 0, ErrorCode::ApplicationBondNotRequired);
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.applicant.to_account_info(),
+                    to: ctx.accounts.application_bond.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let bond = &mut ctx.accounts.application_bond;
+        bond.applicant = ctx.accounts.applicant.key();
+        bond.project = ctx.accounts.project.key();
+        bond.amount = amount;
+        bond.deposited_at = Clock::get()?.unix_timestamp;
+        bond.bump = ctx.bumps.application_bond;
+
+        msg!("Application bond of {} lamports deposited for project {}", amount, bond.project);
         Ok(())
     }
 
-    /// Reopen project for collaboration (start accepting requests again)
-    pub fn reopen_project(ctx: Context<UpdateProject>) -> Result<()> {
-        let project = &mut ctx.accounts.project;
-        project.accepting_collaborations = CollaborationAcceptance::Open;
-        project.last_updated = Clock::get()?.unix_timestamp;
-        msg!("Project reopened for collaboration: {}", project.name);
+    /// Reclaim a bond once its request was accepted, rejected, withdrawn or never sent
+    pub fn reclaim_application_bond(ctx: Context<ReclaimApplicationBond>) -> Result<()> {
+        let request_info = ctx.accounts.collab_request.to_account_info();
+        if !request_info.data_is_empty() {
+            let request = CollaborationRequest::try_deserialize(&mut &request_info.data.borrow()[..])?;
+            require!(
+                matches!(
+                    request.status,
+                    RequestStatus::Accepted | RequestStatus::Rejected | RequestStatus::Left
+                ),
+                ErrorCode::InvalidRequestStatus
+            );
+        }
+        // Bond and rent are closed to applicant via context 'close'
+        msg!("Application bond reclaimed: {} lamports", ctx.accounts.application_bond.amount);
         Ok(())
     }
 
-    /// Update project's role requirements (creator only)
-    pub fn update_project_roles(
-        ctx: Context<UpdateProjectRoles>,
-        role_requirements: Vec<RoleRequirement>,
+    /// Flag an open request as spam: the bond is forfeited to the project account or burned
+    pub fn flag_request_spam(ctx: Context<FlagRequestSpam>, burn: bool) -> Result<()> {
+        let request = &mut ctx.accounts.collab_request;
+        require!(
+            request.status == RequestStatus::Pending || request.status == RequestStatus::UnderReview,
+            ErrorCode::InvalidRequestStatus
+        );
+        let expected_destination = if burn {
+            anchor_lang::solana_program::incinerator::ID
+        } else {
+            request.project
+        };
+        require_keys_eq!(
+            ctx.accounts.forfeit_destination.key(),
+            expected_destination,
+            ErrorCode::InvalidForfeitDestination
+        );
+
+        let forfeited = ctx.accounts.application_bond.amount;
+        ctx.accounts.application_bond.sub_lamports(forfeited)?;
+        ctx.accounts.forfeit_destination.add_lamports(forfeited)?;
+
+        request.status = RequestStatus::Spam;
+        request.bond_amount = 0;
+        request.reply_timestamp = Clock::get()?.unix_timestamp;
+        request.last_activity = request.reply_timestamp;
+        // Remaining rent on the bond account is closed to applicant via context 'close'
+        msg!(
+            "Request flagged as spam: {:?} ({} lamports {})",
+            request.key(),
+            forfeited,
+            if burn { "burned" } else { "forfeited" }
+        );
+        Ok(())
+    }
+
+    // ==================== RESPONSE SLA INSTRUCTIONS ====================
+
+    /// Commit to answering requests within `response_window` seconds, staking a bond
+    pub fn commit_response_sla(
+        ctx: Context<CommitResponseSla>,
+        response_window: i64,
+        penalty: u64,
+        stake: u64,
     ) -> Result<()> {
-        let project = &mut ctx.accounts.project;
-        // Memory guard: limit number of roles to keep account size safe
-        require!(role_requirements.len() <= 12, ErrorCode::TooManyRoles);
+        require!(response_window > 0, ErrorCode::InvalidResponseWindow);
+        require!(penalty > 0 && stake >= penalty, ErrorCode::InvalidSlaStake);
 
-        // Validate each role requirement
-        for r in role_requirements.iter() {
-            // Cap needed to a sane upper bound (e.g., 10)
-            require!(r.needed <= 10, ErrorCode::RoleNeededTooLarge);
-            // accepted must be <= needed
-            require!(r.accepted <= r.needed, ErrorCode::InvalidRoleCounts);
-        }
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.sla_bond.to_account_info(),
+                },
+            ),
+            stake,
+        )?;
 
-        // Apply update
-        project.required_roles = role_requirements;
+        let sla_bond = &mut ctx.accounts.sla_bond;
+        sla_bond.project = ctx.accounts.project.key();
+        sla_bond.owner = ctx.accounts.creator.key();
+        sla_bond.amount = stake;
+        sla_bond.penalty = penalty;
+        sla_bond.response_window = response_window;
+        sla_bond.retire_at = 0;
+        sla_bond.bump = ctx.bumps.sla_bond;
+
+        let project = &mut ctx.accounts.project;
+        project.response_window = response_window;
+        project.live_escrows = project.live_escrows.saturating_add(1);
+        project.last_updated = Clock::get()?.unix_timestamp;
+
+        msg!(
+            "Response SLA committed for {}: {}s window, {} lamports staked",
+            project.name,
+            response_window,
+            stake
+        );
         Ok(())
     }
-}
 
-// ==================== ACCOUNT CONTEXTS ====================
+    /// Add lamports to an active SLA bond
+    pub fn top_up_sla_bond(ctx: Context<ManageSlaBond>, amount: u64) -> Result<()> {
+        require!(ctx.accounts.sla_bond.retire_at == 0, ErrorCode::SlaRetiring);
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.sla_bond.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        let sla_bond = &mut ctx.accounts.sla_bond;
+        sla_bond.amount = sla_bond.amount.saturating_add(amount);
+        msg!("SLA bond topped up to {} lamports", sla_bond.amount);
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct CreateUser<'info> {
-    #[account(
-        init,
-        payer = signer,
-        space = 8 + User::INIT_SPACE,
-        seeds = [b"user", signer.key().as_ref()],
-        bump
-    )]
-    pub user: Account<'info, User>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
+    /// Stop offering the SLA; the bond stays claimable until outstanding deadlines have passed
+    pub fn retire_response_sla(ctx: Context<ManageSlaBond>) -> Result<()> {
+        let sla_bond = &mut ctx.accounts.sla_bond;
+        require!(sla_bond.retire_at == 0, ErrorCode::SlaRetiring);
+        let now = Clock::get()?.unix_timestamp;
+        // Requests sent before now are due within one window; the bond covers their claim period too
+        sla_bond.retire_at = now
+            .saturating_add(sla_bond.response_window)
+            .saturating_add(SLA_CLAIM_PERIOD);
+
+        let project = &mut ctx.accounts.project;
+        project.response_window = 0;
+        project.last_updated = now;
+        msg!("Response SLA retiring for {}; bond withdrawable at {}", project.name, sla_bond.retire_at);
+        Ok(())
+    }
+
+    /// Withdraw the remaining SLA bond after retirement (closes the bond account)
+    pub fn withdraw_sla_bond(ctx: Context<WithdrawSlaBond>) -> Result<()> {
+        let sla_bond = &ctx.accounts.sla_bond;
+        require!(
+            sla_bond.retire_at != 0 && Clock::get()?.unix_timestamp >= sla_bond.retire_at,
+            ErrorCode::SlaStillActive
+        );
+        release_escrow(&mut ctx.accounts.project);
+        // Account will be closed to creator via context 'close'
+        msg!("SLA bond withdrawn: {} lamports", sla_bond.amount);
+        Ok(())
+    }
+
+    /// Claim compensation for a request left unanswered past its response deadline
+    pub fn claim_sla_breach(ctx: Context<ClaimSlaBreach>) -> Result<()> {
+        let request = &mut ctx.accounts.collab_request;
+        require!(
+            request.status == RequestStatus::Pending || request.status == RequestStatus::UnderReview,
+            ErrorCode::InvalidRequestStatus
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            request.response_deadline != 0 && now > request.response_deadline,
+            ErrorCode::SlaNotBreached
+        );
+        require!(
+            now <= request.response_deadline.saturating_add(SLA_CLAIM_PERIOD),
+            ErrorCode::SlaClaimPeriodElapsed
+        );
+
+        // Penalty comes out of the staked bond, capped by what is left in it
+        let sla_bond = &mut ctx.accounts.sla_bond;
+        let penalty = sla_bond.penalty.min(sla_bond.amount);
+        sla_bond.amount -= penalty;
+        sla_bond.sub_lamports(penalty)?;
+        ctx.accounts.applicant.add_lamports(penalty)?;
+
+        // Refund the application deposit (bond + rent), if one was made
+        let refunded = close_if_exists(
+            &ctx.accounts.application_bond.to_account_info(),
+            &ctx.accounts.applicant.to_account_info(),
+        )?;
+
+        request.status = RequestStatus::Expired;
+        request.bond_amount = 0;
+        request.last_activity = now;
+
+        let project = &mut ctx.accounts.project;
+        project.sla_breach_count = project.sla_breach_count.saturating_add(1);
+
+        msg!(
+            "SLA breach claimed on {:?}: {} lamports penalty, {} lamports deposit refunded",
+            request.key(),
+            penalty,
+            refunded
+        );
+        Ok(())
+    }
+
+    // ==================== REQUEST THREAD INSTRUCTIONS ====================
+
+    /// Append a message to an open or accepted request thread (sender or project owner only)
+    pub fn post_request_message(
+        ctx: Context<PostRequestMessage>,
+        text: String,
+        ipfs_hash: String,
+    ) -> Result<()> {
+        require!(text.len() <= 500, ErrorCode::MessageTooLong);
+        require!(ipfs_hash.len() <= 64, ErrorCode::IpfsHashTooLong);
+        require!(
+            !text.trim().is_empty() || !ipfs_hash.trim().is_empty(),
+            ErrorCode::EmptyMessage
+        );
+
+        let request = &mut ctx.accounts.collab_request;
+        // Threads stay open while the request is undecided or the sender is a member
+        require!(
+            request.is_open() || request.status == RequestStatus::Accepted,
+            ErrorCode::InvalidRequestStatus
+        );
+        let author = ctx.accounts.author.key();
+        // Party constraint enforced in context
+        let clock = Clock::get()?;
+
+        let thread_message = &mut ctx.accounts.request_message;
+        thread_message.request = request.key();
+        thread_message.thread = request.timestamp;
+        thread_message.author = author;
+        thread_message.seq = request.message_count;
+        thread_message.timestamp = clock.unix_timestamp;
+        thread_message.text = text;
+        thread_message.ipfs_hash = ipfs_hash;
+        thread_message.bump = ctx.bumps.request_message;
+
+        request.message_count = request
+            .message_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MessageCountOverflow)?;
+        request.thread_activity = clock.unix_timestamp;
+
+        msg!(
+            "Message #{} posted by {} on request {:?}",
+            thread_message.seq,
+            author,
+            request.key()
+        );
+        Ok(())
+    }
+
+    /// Close a thread message once its request has been closed or re-sent (refunds rent to the author)
+    pub fn close_request_message(ctx: Context<CloseRequestMessage>) -> Result<()> {
+        // Messages stay append-only while their request is alive; a re-sent request at the
+        // same address starts a new thread
+        let info = &ctx.accounts.collab_request;
+        if !info.data_is_empty() {
+            let request = CollaborationRequest::try_deserialize(&mut &info.data.borrow()[..])?;
+            require!(
+                request.timestamp != ctx.accounts.request_message.thread,
+                ErrorCode::RequestStillOpen
+            );
+        }
+        msg!("Request message closed: {:?}", ctx.accounts.request_message.key());
+        Ok(())
+    }
+
+    // ==================== CONTACT DISCLOSURE INSTRUCTIONS ====================
+
+    /// Share the contact key with the counterpart of an accepted request
+    pub fn grant_contact_access(
+        ctx: Context<GrantContactAccess>,
+        sealed_key: Vec<u8>,
+    ) -> Result<()> {
+        require!(!sealed_key.is_empty(), ErrorCode::ContactEnvelopeRequired);
+        require!(
+            sealed_key.len() <= CONTACT_ENVELOPE_MAX_LEN,
+            ErrorCode::ContactEnvelopeTooLong
+        );
+        // Accepted-request and party constraints enforced in context
+        let owner = ctx.accounts.owner.key();
+        let recipient = ctx.accounts.recipient_user.wallet;
+        let connected = ctx
+            .accounts
+            .connection
+            .as_ref()
+            .is_some_and(|c| c.is_connected(&owner, &recipient));
+        require!(
+            ctx.accounts.owner_user.visible_to(&recipient, connected),
+            ErrorCode::ContactNotVisible
+        );
+
+        let envelope = &mut ctx.accounts.contact_envelope;
+        envelope.owner = ctx.accounts.owner.key();
+        envelope.recipient = ctx.accounts.recipient_user.wallet;
+        envelope.request = ctx.accounts.collab_request.key();
+        envelope.recipient_encryption_pubkey = ctx.accounts.recipient_user.encryption_pubkey;
+        envelope.sealed_key = sealed_key;
+        envelope.granted_at = Clock::get()?.unix_timestamp;
+        envelope.bump = ctx.bumps.contact_envelope;
+
+        msg!(
+            "Contact access granted by {} to {}",
+            envelope.owner,
+            envelope.recipient
+        );
+        Ok(())
+    }
+
+    /// Revoke a previously granted contact envelope (refunds rent to the owner)
+    pub fn revoke_contact_access(ctx: Context<RevokeContactAccess>) -> Result<()> {
+        // Account will be closed to owner via context 'close'
+        msg!(
+            "Contact access revoked for {}",
+            ctx.accounts.contact_envelope.recipient
+        );
+        Ok(())
+    }
+
+    // ==================== CONNECTION INSTRUCTIONS ====================
+
+    /// Ask another user to connect (creates a pending connection)
+    pub fn request_connection(ctx: Context<RequestConnection>) -> Result<()> {
+        let requester = ctx.accounts.requester.key();
+        let addressee = ctx.accounts.addressee_user.wallet;
+        require!(requester != addressee, ErrorCode::CannotConnectToSelf);
+
+        let (user_a, user_b) = Connection::ordered(requester, addressee);
+        let connection = &mut ctx.accounts.connection;
+        connection.user_a = user_a;
+        connection.user_b = user_b;
+        connection.requester = requester;
+        connection.status = ConnectionStatus::Pending;
+        connection.created_at = Clock::get()?.unix_timestamp;
+        connection.connected_at = 0;
+        connection.bump = ctx.bumps.connection;
+
+        msg!("Connection requested by {} to {}", requester, addressee);
+        Ok(())
+    }
+
+    /// Accept a pending connection (only by the user who was asked)
+    pub fn accept_connection(ctx: Context<AcceptConnection>) -> Result<()> {
+        let connection = &mut ctx.accounts.connection;
+        require!(
+            connection.status == ConnectionStatus::Pending,
+            ErrorCode::InvalidConnectionStatus
+        );
+        // Addressee constraint enforced in context
+        connection.status = ConnectionStatus::Connected;
+        connection.connected_at = Clock::get()?.unix_timestamp;
+        msg!("Connection accepted: {} <-> {}", connection.user_a, connection.user_b);
+        Ok(())
+    }
+
+    /// Connect the two parties of an accepted collaboration request without a handshake
+    pub fn connect_via_collaboration(ctx: Context<ConnectViaCollaboration>) -> Result<()> {
+        let request = &ctx.accounts.collab_request;
+        require!(request.from != request.to, ErrorCode::CannotConnectToSelf);
+        // Accepted status and party constraints enforced in context
+
+        let (user_a, user_b) = Connection::ordered(request.from, request.to);
+        let now = Clock::get()?.unix_timestamp;
+        let connection = &mut ctx.accounts.connection;
+        connection.user_a = user_a;
+        connection.user_b = user_b;
+        connection.requester = ctx.accounts.signer.key();
+        connection.status = ConnectionStatus::Connected;
+        connection.created_at = now;
+        connection.connected_at = now;
+        connection.bump = ctx.bumps.connection;
+
+        msg!("Connection created from collaboration: {} <-> {}", user_a, user_b);
+        Ok(())
+    }
+
+    /// Decline, cancel or remove a connection (either party; rent returns to the requester)
+    pub fn remove_connection(ctx: Context<RemoveConnection>) -> Result<()> {
+        // Account will be closed to requester via context 'close'
+        let connection = &ctx.accounts.connection;
+        msg!("Connection removed: {} <-> {}", connection.user_a, connection.user_b);
+        Ok(())
+    }
+
+    // ==================== SOCIAL INSTRUCTIONS ====================
+
+    /// Follow another user
+    pub fn follow_user(ctx: Context<FollowUser>) -> Result<()> {
+        let follower = ctx.accounts.follower.key();
+        let followed = ctx.accounts.followed_user.wallet;
+        require!(follower != followed, ErrorCode::CannotFollowSelf);
+
+        let follow = &mut ctx.accounts.follow;
+        follow.follower = follower;
+        follow.followed = followed;
+        follow.timestamp = Clock::get()?.unix_timestamp;
+        follow.bump = ctx.bumps.follow;
+        follow.follower_since = ctx.accounts.follower_user.member_since;
+        follow.followed_since = ctx.accounts.followed_user.member_since;
+
+        let follower_user = &mut ctx.accounts.follower_user;
+        follower_user.following_count = follower_user.following_count.saturating_add(1);
+        let followed_user = &mut ctx.accounts.followed_user;
+        followed_user.followers_count = followed_user.followers_count.saturating_add(1);
+
+        msg!("{} followed {}", follower, followed);
+        Ok(())
+    }
+
+    /// Unfollow a user (closes the follow account and refunds rent)
+    pub fn unfollow_user(ctx: Context<UnfollowUser>) -> Result<()> {
+        // Either profile may have been deleted since; only live ones are updated
+        // Profiles recreated since the follow started their counts over; leave those alone
+        let follow = &ctx.accounts.follow;
+        update_if_exists::<User>(&ctx.accounts.follower_user, |u| {
+            if u.member_since == follow.follower_since {
+                u.following_count = u.following_count.saturating_sub(1);
+            }
+        })?;
+        update_if_exists::<User>(&ctx.accounts.followed_user, |u| {
+            if u.member_since == follow.followed_since {
+                u.followers_count = u.followers_count.saturating_sub(1);
+            }
+        })?;
+        // Account will be closed to follower via context 'close'
+        msg!("{} unfollowed {}", ctx.accounts.follower.key(), ctx.accounts.follow.followed);
+        Ok(())
+    }
+
+    /// Star a project
+    pub fn star_project(ctx: Context<StarProject>) -> Result<()> {
+        let star = &mut ctx.accounts.star;
+        star.user = ctx.accounts.user.key();
+        star.project = ctx.accounts.project.key();
+        star.timestamp = Clock::get()?.unix_timestamp;
+        star.bump = ctx.bumps.star;
+        star.project_since = ctx.accounts.project.timestamp;
+
+        let project = &mut ctx.accounts.project;
+        project.stars_count = project.stars_count.saturating_add(1);
+
+        msg!("{} starred project {}", star.user, project.name);
+        Ok(())
+    }
+
+    /// Remove a star from a project (closes the star account and refunds rent)
+    pub fn unstar_project(ctx: Context<UnstarProject>) -> Result<()> {
+        // The project may have been deleted (or recreated) since; only the starred one is updated
+        let project_since = ctx.accounts.star.project_since;
+        update_if_exists::<Project>(&ctx.accounts.project, |p| {
+            if p.timestamp == project_since {
+                p.stars_count = p.stars_count.saturating_sub(1);
+            }
+        })?;
+        // Account will be closed to user via context 'close'
+        msg!("{} unstarred project {}", ctx.accounts.user.key(), ctx.accounts.star.project);
+        Ok(())
+    }
+
+    /// Block a wallet from sending requests, thread messages or connection invites to you
+    pub fn block_user(ctx: Context<BlockUser>, blocked: Pubkey) -> Result<()> {
+        let blocker = ctx.accounts.blocker.key();
+        require!(blocker != blocked, ErrorCode::CannotBlockSelf);
+
+        let block = &mut ctx.accounts.block;
+        block.blocker = blocker;
+        block.blocked = blocked;
+        block.timestamp = Clock::get()?.unix_timestamp;
+        block.bump = ctx.bumps.block;
+
+        msg!("{} blocked {}", blocker, blocked);
+        Ok(())
+    }
+
+    /// Remove a block (closes the block account and refunds rent)
+    pub fn unblock_user(ctx: Context<UnblockUser>) -> Result<()> {
+        // Account will be closed to blocker via context 'close'
+        msg!("{} unblocked {}", ctx.accounts.blocker.key(), ctx.accounts.block.blocked);
+        Ok(())
+    }
+
+    // ==================== ORGANIZATION INSTRUCTIONS ====================
+
+    /// Create an organization; the creator becomes its first admin
+    pub fn create_organization(
+        ctx: Context<CreateOrganization>,
+        name: String,
+        logo_ipfs_hash: String,
+    ) -> Result<()> {
+        require!(!name.trim().is_empty(), ErrorCode::NameRequired);
+        require!(name.len() <= SEED_NAME_MAX_LEN, ErrorCode::SeedNameTooLong);
+        require!(logo_ipfs_hash.len() <= 64, ErrorCode::IpfsHashTooLong);
+
+        let now = Clock::get()?.unix_timestamp;
+        let organization = &mut ctx.accounts.organization;
+        organization.name = name;
+        organization.logo_ipfs_hash = logo_ipfs_hash;
+        organization.created_by = ctx.accounts.creator.key();
+        organization.members = vec![OrgMember {
+            wallet: ctx.accounts.creator.key(),
+            role: OrgRole::Admin,
+        }];
+        organization.is_verified = false;
+        organization.project_index = 0;
+        organization.project_count = 0;
+        organization.created_at = now;
+        organization.bump = ctx.bumps.organization;
+
+        msg!("Organization created: {}", organization.name);
+        Ok(())
+    }
+
+    /// Update organization details (admins only)
+    pub fn update_organization(
+        ctx: Context<ManageOrganization>,
+        logo_ipfs_hash: Option<String>,
+    ) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        if let Some(hash) = logo_ipfs_hash {
+            require!(hash.len() <= 64, ErrorCode::IpfsHashTooLong);
+            organization.logo_ipfs_hash = hash;
+        }
+        msg!("Organization updated: {}", organization.name);
+        Ok(())
+    }
+
+    /// Add a member or change an existing member's role (admins only)
+    pub fn set_org_member(ctx: Context<ManageOrganization>, wallet: Pubkey, role: OrgRole) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        match organization.members.iter_mut().find(|m| m.wallet == wallet) {
+            Some(member) => member.role = role,
+            None => {
+                require!(
+                    organization.members.len() < MAX_ORG_MEMBERS,
+                    ErrorCode::TooManyOrgMembers
+                );
+                organization.members.push(OrgMember { wallet, role });
+            }
+        }
+        require!(organization.admin_count() > 0, ErrorCode::LastOrgAdmin);
+        msg!("Organization {} member set: {}", organization.name, wallet);
+        Ok(())
+    }
+
+    /// Remove a member (admins, or the member leaving)
+    pub fn remove_org_member(ctx: Context<RemoveOrgMember>, wallet: Pubkey) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        let len_before = organization.members.len();
+        organization.members.retain(|m| m.wallet != wallet);
+        require!(organization.members.len() < len_before, ErrorCode::NotAnOrgMember);
+        require!(organization.admin_count() > 0, ErrorCode::LastOrgAdmin);
+        msg!("Organization {} member removed: {}", organization.name, wallet);
+        Ok(())
+    }
+
+    /// Set or clear an organization's verified badge (config admin only)
+    pub fn set_org_verified(ctx: Context<SetOrgVerified>, verified: bool) -> Result<()> {
+        let organization = &mut ctx.accounts.organization;
+        organization.is_verified = verified;
+        msg!("Organization {} verified: {}", organization.name, verified);
+        Ok(())
+    }
+
+    /// Create a project directly under an organization and list it in the org project index
+    /// (org admins only; the admin becomes the project creator)
+    pub fn create_organization_project(
+        ctx: Context<CreateOrganizationProject>,
+        args: CreateProjectArgs,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let user = &mut ctx.accounts.user;
+        let limits = ctx.accounts.config.limits_for(user);
+        require!(
+            ctx.accounts.rate_state.project_window.try_hit(now, WEEK, limits.projects_per_week),
+            ErrorCode::ProjectRateLimited
+        );
+        user.projects_count = user.projects_count.saturating_add(1);
+
+        let project = &mut ctx.accounts.project;
+        init_project(project, ctx.accounts.creator.key(), ctx.bumps.project, args, now)?;
+        let organization = &mut ctx.accounts.organization;
+        project.organization = organization.key();
+
+        let entry = &mut ctx.accounts.org_project;
+        entry.organization = organization.key();
+        entry.project = project.key();
+        entry.index = organization.project_index;
+        entry.added_at = now;
+        entry.bump = ctx.bumps.org_project;
+        organization.project_index = organization.project_index.saturating_add(1);
+        organization.project_count = organization.project_count.saturating_add(1);
+
+        msg!("Project created: {} in organization {}", project.name, organization.name);
+        Ok(())
+    }
+
+    /// Place a project under an organization and list it in the org project index
+    /// (signer must be both the project creator and an org admin)
+    pub fn add_project_to_organization(ctx: Context<AddProjectToOrganization>) -> Result<()> {
+        let project = &mut ctx.accounts.project;
+        require!(
+            project.organization == Pubkey::default(),
+            ErrorCode::ProjectAlreadyInOrganization
+        );
+        let organization = &mut ctx.accounts.organization;
+
+        let entry = &mut ctx.accounts.org_project;
+        entry.organization = organization.key();
+        entry.project = project.key();
+        entry.index = organization.project_index;
+        entry.added_at = Clock::get()?.unix_timestamp;
+        entry.bump = ctx.bumps.org_project;
+
+        organization.project_index = organization.project_index.saturating_add(1);
+        organization.project_count = organization.project_count.saturating_add(1);
+        project.organization = organization.key();
+
+        msg!("Project {} added to organization {}", project.name, organization.name);
+        Ok(())
+    }
+
+    /// Take a project out of its organization and drop its index entry (org admins only)
+    pub fn remove_project_from_organization(ctx: Context<RemoveProjectFromOrganization>) -> Result<()> {
+        let project = &mut ctx.accounts.project;
+        project.organization = Pubkey::default();
+        let organization = &mut ctx.accounts.organization;
+        organization.project_count = organization.project_count.saturating_sub(1);
+        // Index entry will be closed to the signer via context 'close'
+        msg!(
+            "Project {} removed from organization {}",
+            project.name,
+            ctx.accounts.organization.name
+        );
+        Ok(())
+    }
+
+    // ==================== PROJECT ROLE INSTRUCTIONS ====================
+
+    /// Delegate permissions on a project to another wallet (creator or MANAGE_ROLES delegate)
+    pub fn grant_project_role(
+        ctx: Context<GrantProjectRole>,
+        delegate: Pubkey,
+        permissions: u8,
+    ) -> Result<()> {
+        let project = &ctx.accounts.project;
+        require!(delegate != project.creator, ErrorCode::InvalidDelegate);
+        require!(
+            permissions != 0 && permissions & !ProjectRole::ALL == 0,
+            ErrorCode::InvalidPermissions
+        );
+        // Delegates can only hand out permissions they hold themselves
+        let grantable = project.grantable_permissions(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.authority_role.as_deref(),
+            ctx.accounts.organization.as_deref(),
+        );
+        require!(permissions & !grantable == 0, ErrorCode::Unauthorized);
+
+        let role = &mut ctx.accounts.project_role;
+        role.project = project.key();
+        role.delegate = delegate;
+        role.permissions = permissions;
+        role.granted_by = ctx.accounts.authority.key();
+        role.granted_at = Clock::get()?.unix_timestamp;
+        role.bump = ctx.bumps.project_role;
+        role.owner_epoch = project.owner_epoch;
+
+        msg!("Project role granted on {} to {} ({:#04x})", project.name, delegate, permissions);
+        Ok(())
+    }
+
+    /// Change a delegate's permissions, or renew a lapsed delegation (creator or MANAGE_ROLES delegate)
+    pub fn update_project_role(ctx: Context<UpdateProjectRole>, permissions: u8) -> Result<()> {
+        require!(
+            permissions != 0 && permissions & !ProjectRole::ALL == 0,
+            ErrorCode::InvalidPermissions
+        );
+        let grantable = ctx.accounts.project.grantable_permissions(
+            &ctx.accounts.authority.key(),
+            ctx.accounts.authority_role.as_deref(),
+            ctx.accounts.organization.as_deref(),
+        );
+        let role = &mut ctx.accounts.project_role;
+        require!(
+            (permissions | role.permissions) & !grantable == 0,
+            ErrorCode::Unauthorized
+        );
+        role.permissions = permissions;
+        // Updating also re-confirms a delegation that lapsed with an ownership transfer
+        role.owner_epoch = ctx.accounts.project.owner_epoch;
+        msg!("Project role updated for {} ({:#04x})", role.delegate, permissions);
+        Ok(())
+    }
+
+    /// Remove a delegate (creator, MANAGE_ROLES delegate, or the delegate resigning)
+    pub fn revoke_project_role(ctx: Context<RevokeProjectRole>) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let role = &ctx.accounts.project_role;
+        if authority != role.delegate {
+            let grantable = ctx.accounts.project.grantable_permissions(
+                &authority,
+                ctx.accounts.authority_role.as_deref(),
+                ctx.accounts.organization.as_deref(),
+            );
+            require!(role.permissions & !grantable == 0, ErrorCode::Unauthorized);
+        }
+        // Account will be closed to the original payer via context 'close'
+        msg!("Project role revoked for {}", role.delegate);
+        Ok(())
+    }
+
+    // ==================== APPROVAL INSTRUCTIONS ====================
+
+    /// Require `threshold` of `approvers` to sign off on sensitive actions (creator only, once;
+    /// later changes go through an UpdateApprovers proposal)
+    pub fn configure_approvers(
+        ctx: Context<ConfigureApprovers>,
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        validate_approvers(&approvers, threshold)?;
+        require!(threshold > 0, ErrorCode::InvalidApprovalThreshold);
+
+        let approver_set = &mut ctx.accounts.approver_set;
+        approver_set.project = ctx.accounts.project.key();
+        approver_set.approvers = approvers;
+        approver_set.threshold = threshold;
+        approver_set.bump = ctx.bumps.approver_set;
+
+        let project = &mut ctx.accounts.project;
+        project.approval_threshold = threshold;
+        project.last_updated = Clock::get()?.unix_timestamp;
+
+        msg!(
+            "Approvers configured for {}: {} of {}",
+            project.name,
+            threshold,
+            approver_set.approvers.len()
+        );
+        Ok(())
+    }
+
+    /// Propose a sensitive action (approvers only); the proposer's approval is recorded
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        action: ProposalAction,
+        ttl: i64,
+    ) -> Result<()> {
+        require!(ttl > 0 && ttl <= MAX_PROPOSAL_TTL, ErrorCode::InvalidProposalTtl);
+        action.validate()?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let project = &mut ctx.accounts.project;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.project = project.key();
+        proposal.index = project.proposal_count;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.action = action;
+        proposal.approvals = vec![proposal.proposer];
+        proposal.created_at = now;
+        proposal.expires_at = now.saturating_add(ttl);
+        proposal.bump = ctx.bumps.proposal;
+
+        project.proposal_count = project.proposal_count.saturating_add(1);
+
+        msg!("Proposal #{} created on {} by {}", proposal.index, project.name, proposal.proposer);
+        Ok(())
+    }
+
+    /// Approve an active proposal (approvers only)
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        let approver = ctx.accounts.approver.key();
+        let proposal = &mut ctx.accounts.proposal;
+        require!(
+            Clock::get()?.unix_timestamp <= proposal.expires_at,
+            ErrorCode::ProposalExpired
+        );
+        require!(!proposal.approvals.contains(&approver), ErrorCode::AlreadyApproved);
+        proposal.approvals.push(approver);
+        msg!(
+            "Proposal #{} approved by {} ({} approvals)",
+            proposal.index,
+            approver,
+            proposal.approvals.len()
+        );
+        Ok(())
+    }
+
+    /// Cancel a proposal (proposer or creator; anyone once it has expired)
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        let proposal = &ctx.accounts.proposal;
+        let expired = Clock::get()?.unix_timestamp > proposal.expires_at;
+        require!(
+            expired || authority == proposal.proposer || authority == ctx.accounts.project.creator,
+            ErrorCode::Unauthorized
+        );
+        // Account will be closed to proposer via context 'close'
+        msg!("Proposal #{} cancelled", proposal.index);
+        Ok(())
+    }
+
+    /// Execute an approved AcceptRequest proposal
+    pub fn execute_accept_request(ctx: Context<ExecuteAcceptRequest>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        ctx.accounts.approver_set.check_executable(proposal, Clock::get()?.unix_timestamp)?;
+        let ProposalAction::AcceptRequest { request, owner_message } = &proposal.action else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        require_keys_eq!(
+            *request,
+            ctx.accounts.collab_request.key(),
+            ErrorCode::ProposalActionMismatch
+        );
+        apply_accept(
+            &mut ctx.accounts.project,
+            &mut ctx.accounts.collab_request,
+            &mut ctx.accounts.member_stats,
+            ctx.bumps.member_stats,
+            &ctx.accounts.sender_user,
+            owner_message.clone(),
+        )?;
+        msg!("Proposal #{} executed: request {:?} accepted", proposal.index, request);
+        Ok(())
+    }
+
+    /// Execute an approved UpdateProjectRoles proposal
+    pub fn execute_update_roles(ctx: Context<ExecuteProjectProposal>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        ctx.accounts.approver_set.check_executable(proposal, Clock::get()?.unix_timestamp)?;
+        let ProposalAction::UpdateProjectRoles { role_requirements } = &proposal.action else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        ctx.accounts
+            .project
+            .check_team_cap(role_requirements, Clock::get()?.unix_timestamp)?;
+        ctx.accounts.project.required_roles = role_requirements.clone();
+        msg!("Proposal #{} executed: project roles updated", proposal.index);
+        Ok(())
+    }
+
+    /// Execute an approved TransferOwnership proposal (the new owner still calls `accept_transfer`)
+    pub fn execute_transfer(ctx: Context<ExecuteTransfer>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        ctx.accounts.approver_set.check_executable(proposal, Clock::get()?.unix_timestamp)?;
+        let ProposalAction::TransferOwnership { new_owner } = &proposal.action else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        require_keys_eq!(
+            *new_owner,
+            ctx.accounts.new_owner_user.wallet,
+            ErrorCode::ProposalActionMismatch
+        );
+        let project = &mut ctx.accounts.project;
+        project.check_not_in_organization()?;
+        require!(*new_owner != project.creator, ErrorCode::InvalidTransferTarget);
+        project.pending_owner = *new_owner;
+        msg!("Proposal #{} executed: transfer to {} pending", proposal.index, new_owner);
+        Ok(())
+    }
+
+    /// Execute an approved UpdateApprovers proposal (threshold 0 removes the approver set)
+    pub fn execute_update_approvers(ctx: Context<ExecuteUpdateApprovers>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        ctx.accounts.approver_set.check_executable(proposal, Clock::get()?.unix_timestamp)?;
+        let ProposalAction::UpdateApprovers { approvers, threshold } = &proposal.action else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+
+        let project = &mut ctx.accounts.project;
+        project.approval_threshold = *threshold;
+        if *threshold == 0 {
+            close_if_exists(
+                &ctx.accounts.approver_set.to_account_info(),
+                &ctx.accounts.creator.to_account_info(),
+            )?;
+        } else {
+            let approver_set = &mut ctx.accounts.approver_set;
+            approver_set.approvers = approvers.clone();
+            approver_set.threshold = *threshold;
+        }
+        msg!("Proposal #{} executed: approvers updated ({} required)", proposal.index, threshold);
+        Ok(())
+    }
+
+    /// Execute an approved DeleteProject proposal (project and approver set close to the creator)
+    pub fn execute_delete_project(ctx: Context<ExecuteDeleteProject>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        ctx.accounts.approver_set.check_executable(proposal, Clock::get()?.unix_timestamp)?;
+        require!(
+            proposal.action == ProposalAction::DeleteProject,
+            ErrorCode::ProposalActionMismatch
+        );
+        retire_project(
+            &ctx.accounts.project,
+            &ctx.accounts.creator_user,
+            &ctx.accounts.program_creator,
+        )?;
+        msg!("Proposal #{} executed: project {:?} deleted", proposal.index, ctx.accounts.project.key());
+        Ok(())
+    }
+
+    /// Execute an approved WithdrawDonations proposal paying out SOL donations
+    pub fn execute_withdraw_donations(ctx: Context<ExecuteWithdrawDonations>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        ctx.accounts.approver_set.check_executable(proposal, Clock::get()?.unix_timestamp)?;
+        let ProposalAction::WithdrawDonations { mint, amount, recipient } = proposal.action else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        require!(
+            mint == Pubkey::default() && recipient == ctx.accounts.recipient.key(),
+            ErrorCode::ProposalActionMismatch
+        );
+        withdraw_sol_donations(&ctx.accounts.vault, &ctx.accounts.recipient, amount)?;
+        emit!(DonationWithdrawn {
+            project: ctx.accounts.project.key(),
+            mint,
+            amount,
+            recipient,
+            proposal: Some(proposal.index),
+        });
+        msg!("Proposal #{} executed: {} lamports of donations to {}", proposal.index, amount, recipient);
+        Ok(())
+    }
+
+    /// Execute an approved WithdrawDonations proposal paying out SPL token donations
+    pub fn execute_withdraw_token_donations(ctx: Context<ExecuteWithdrawTokenDonations>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        ctx.accounts.approver_set.check_executable(proposal, Clock::get()?.unix_timestamp)?;
+        let ProposalAction::WithdrawDonations { mint, amount, recipient } = proposal.action else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        require!(
+            mint == ctx.accounts.mint.key() && recipient == ctx.accounts.recipient_token.owner,
+            ErrorCode::ProposalActionMismatch
+        );
+        transfer_from_donation_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.vault_token,
+            &ctx.accounts.mint,
+            &ctx.accounts.recipient_token,
+            &ctx.accounts.vault,
+            amount,
+        )?;
+        emit!(DonationWithdrawn {
+            project: ctx.accounts.project.key(),
+            mint,
+            amount,
+            recipient,
+            proposal: Some(proposal.index),
+        });
+        msg!("Proposal #{} executed: {} of mint {} to {}", proposal.index, amount, mint, recipient);
+        Ok(())
+    }
+
+    // ==================== MILESTONE INSTRUCTIONS ====================
+
+    /// Add a milestone to a project (creator or EDIT_PROJECT delegate)
+    ///
+    /// Assignees other than the creator must be accepted members; pass their collaboration
+    /// requests as remaining accounts, in assignee order.
+    pub fn create_milestone<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateMilestone<'info>>,
+        title: String,
+        spec_ipfs_hash: String,
+        due_date: i64,
+        assignees: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(title.len() <= 64, ErrorCode::MilestoneTitleTooLong);
+        require!(spec_ipfs_hash.len() <= 64, ErrorCode::IpfsHashTooLong);
+        let now = Clock::get()?.unix_timestamp;
+        require!(due_date > now, ErrorCode::InvalidMilestoneDueDate);
+        let project = &mut ctx.accounts.project;
+        check_milestone_assignees(project, &assignees, ctx.remaining_accounts)?;
+
+        let milestone = &mut ctx.accounts.milestone;
+        milestone.project = project.key();
+        milestone.index = project.milestone_count;
+        milestone.title = title;
+        milestone.spec_ipfs_hash = spec_ipfs_hash;
+        milestone.due_date = due_date;
+        milestone.assignees = assignees;
+        milestone.status = MilestoneStatus::Open;
+        milestone.created_at = now;
+        milestone.completed_at = 0;
+        milestone.bump = ctx.bumps.milestone;
+
+        project.milestone_count = project
+            .milestone_count
+            .checked_add(1)
+            .ok_or(ErrorCode::MilestoneCountOverflow)?;
+        project.refresh_progress();
+        project.last_updated = now;
+
+        msg!("Milestone #{} added to {}: {}", milestone.index, project.name, milestone.title);
+        Ok(())
+    }
+
+    /// Edit an open milestone (creator or EDIT_PROJECT delegate)
+    ///
+    /// New assignees are checked the same way as in `create_milestone`.
+    pub fn update_milestone<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ManageMilestone<'info>>,
+        title: Option<String>,
+        spec_ipfs_hash: Option<String>,
+        due_date: Option<i64>,
+        assignees: Option<Vec<Pubkey>>,
+    ) -> Result<()> {
+        let milestone = &mut ctx.accounts.milestone;
+        require!(milestone.status == MilestoneStatus::Open, ErrorCode::InvalidMilestoneStatus);
+        if let Some(new_title) = title {
+            require!(new_title.len() <= 64, ErrorCode::MilestoneTitleTooLong);
+            milestone.title = new_title;
+        }
+        if let Some(new_spec) = spec_ipfs_hash {
+            require!(new_spec.len() <= 64, ErrorCode::IpfsHashTooLong);
+            milestone.spec_ipfs_hash = new_spec;
+        }
+        if let Some(new_due) = due_date {
+            require!(new_due > Clock::get()?.unix_timestamp, ErrorCode::InvalidMilestoneDueDate);
+            milestone.due_date = new_due;
+        }
+        if let Some(new_assignees) = assignees {
+            check_milestone_assignees(&ctx.accounts.project, &new_assignees, ctx.remaining_accounts)?;
+            milestone.assignees = new_assignees;
+        }
+        msg!("Milestone #{} updated", milestone.index);
+        Ok(())
+    }
+
+    /// Mark a milestone done (creator or EDIT_PROJECT delegate)
+    pub fn complete_milestone(ctx: Context<ManageMilestone>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let milestone = &mut ctx.accounts.milestone;
+        require!(milestone.status == MilestoneStatus::Open, ErrorCode::InvalidMilestoneStatus);
+        milestone.status = MilestoneStatus::Completed;
+        milestone.completed_at = now;
+
+        let project = &mut ctx.accounts.project;
+        project.milestones_completed = project
+            .milestones_completed
+            .checked_add(1)
+            .ok_or(ErrorCode::MilestoneCountOverflow)?;
+        project.refresh_progress();
+        project.last_updated = now;
+
+        msg!("Milestone #{} completed; {} is {}% done", milestone.index, project.name, project.completion_pct);
+        Ok(())
+    }
+
+    /// Reopen a completed milestone (creator or EDIT_PROJECT delegate)
+    pub fn reopen_milestone(ctx: Context<ManageMilestone>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let milestone = &mut ctx.accounts.milestone;
+        require!(milestone.status == MilestoneStatus::Completed, ErrorCode::InvalidMilestoneStatus);
+        milestone.status = MilestoneStatus::Open;
+        milestone.completed_at = 0;
+
+        let project = &mut ctx.accounts.project;
+        project.milestones_completed = project
+            .milestones_completed
+            .checked_sub(1)
+            .ok_or(ErrorCode::MilestoneCountOverflow)?;
+        project.refresh_progress();
+        project.last_updated = now;
+
+        msg!("Milestone #{} reopened; {} is {}% done", milestone.index, project.name, project.completion_pct);
+        Ok(())
+    }
+
+    /// Let milestone progress drive `project_status` (creator or EDIT_PROJECT delegate)
+    pub fn set_status_from_milestones(ctx: Context<UpdateProject>, enabled: bool) -> Result<()> {
+        let project = &mut ctx.accounts.project;
+        project.status_from_milestones = enabled;
+        project.refresh_progress();
+        msg!("Project {} status from milestones: {}", project.name, enabled);
+        Ok(())
+    }
+
+    // ==================== TASK BOARD INSTRUCTIONS ====================
+
+    /// Post a task on the project's board (creator or EDIT_PROJECT delegate)
+    ///
+    /// Labels must be taken from the project's tech stack; `estimate` is in story points.
+    pub fn create_task(ctx: Context<CreateTask>, title: String, labels: Vec<String>, estimate: u16) -> Result<()> {
+        require!(title.len() <= 64, ErrorCode::TaskTitleTooLong);
+        require!(labels.len() <= MAX_TASK_LABELS, ErrorCode::InvalidTaskLabel);
+        let project = &mut ctx.accounts.project;
+        for (i, label) in labels.iter().enumerate() {
+            require!(
+                !labels[..i].contains(label) && project.tech_stack.iter().any(|t| &t.value == label),
+                ErrorCode::InvalidTaskLabel
+            );
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let task = &mut ctx.accounts.task;
+        task.project = project.key();
+        task.index = project.task_count;
+        task.title = title;
+        task.labels = labels.into_iter().map(|v| ShortText{ value: v }).collect();
+        task.estimate = estimate;
+        task.status = TaskStatus::Open;
+        task.assignee = Pubkey::default();
+        task.submission_ipfs_hash = String::new();
+        task.created_at = now;
+        task.completed_at = 0;
+        task.bump = ctx.bumps.task;
+        project.task_count = project.task_count.checked_add(1).ok_or(ErrorCode::TaskCountOverflow)?;
+
+        msg!("Task #{} posted on {}: {}", task.index, project.name, task.title);
+        Ok(())
+    }
+
+    /// Claim an open task (accepted members only)
+    pub fn claim_task(ctx: Context<ClaimTask>) -> Result<()> {
+        require!(
+            ctx.accounts.member_request.status == RequestStatus::Accepted,
+            ErrorCode::NotAMember
+        );
+        let task = &mut ctx.accounts.task;
+        require!(task.status == TaskStatus::Open, ErrorCode::InvalidTaskStatus);
+        task.status = TaskStatus::Claimed;
+        task.assignee = ctx.accounts.member.key();
+        msg!("Task #{} claimed by {}", task.index, task.assignee);
+        Ok(())
+    }
+
+    /// Hand a claimed task back to the board (assignee only)
+    pub fn unclaim_task(ctx: Context<WorkOnTask>) -> Result<()> {
+        let task = &mut ctx.accounts.task;
+        require!(task.status == TaskStatus::Claimed, ErrorCode::InvalidTaskStatus);
+        task.status = TaskStatus::Open;
+        task.assignee = Pubkey::default();
+        msg!("Task #{} unclaimed", task.index);
+        Ok(())
+    }
+
+    /// Submit work on a claimed task for review (assignee only)
+    pub fn submit_task(ctx: Context<WorkOnTask>, submission_ipfs_hash: String) -> Result<()> {
+        require!(submission_ipfs_hash.len() <= 64, ErrorCode::IpfsHashTooLong);
+        let task = &mut ctx.accounts.task;
+        require!(task.status == TaskStatus::Claimed, ErrorCode::InvalidTaskStatus);
+        task.status = TaskStatus::Submitted;
+        task.submission_ipfs_hash = submission_ipfs_hash;
+        msg!("Task #{} submitted by {}", task.index, task.assignee);
+        Ok(())
+    }
+
+    /// Accept a submitted task and credit its assignee (creator or EDIT_PROJECT delegate)
+    pub fn approve_task(ctx: Context<ApproveTask>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let task = &mut ctx.accounts.task;
+        require!(task.status == TaskStatus::Submitted, ErrorCode::InvalidTaskStatus);
+        task.status = TaskStatus::Done;
+        task.completed_at = now;
+
+        let stats = &mut ctx.accounts.member_stats;
+        stats.open(task.project, task.assignee, ctx.bumps.member_stats);
+        stats.tasks_completed = stats.tasks_completed.saturating_add(1);
+        stats.task_points = stats.task_points.saturating_add(task.estimate as u64);
+        stats.last_completed_at = now;
+
+        // Approved work is what earns reputation: the task's estimate, at least one point, up
+        // to what this project may still award
+        let points = (task.estimate.max(1) as u32)
+            .min(MAX_PROJECT_REPUTATION.saturating_sub(stats.reputation_awarded));
+        stats.reputation_awarded += points;
+        update_if_exists::<User>(&ctx.accounts.assignee_user, |u| {
+            u.reputation = u.reputation.saturating_add(points);
+        })?;
+
+        msg!("Task #{} approved; {} has completed {} tasks", task.index, stats.member, stats.tasks_completed);
+        Ok(())
+    }
+
+    // ==================== BOUNTY INSTRUCTIONS ====================
+
+    /// Post a bounty on a project, escrowing `amount` lamports in the bounty PDA
+    pub fn create_bounty(
+        ctx: Context<CreateBounty>,
+        title: String,
+        spec_ipfs_hash: String,
+        amount: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        open_bounty(
+            &mut ctx.accounts.project,
+            &mut ctx.accounts.bounty,
+            ctx.accounts.manager.key(),
+            Pubkey::default(),
+            title,
+            spec_ipfs_hash,
+            amount,
+            deadline,
+            now,
+        )?;
+        ctx.accounts.bounty.bump = ctx.bumps.bounty;
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.manager.to_account_info(),
+                    to: ctx.accounts.bounty.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let bounty = &ctx.accounts.bounty;
+        msg!("Bounty #{} posted on {}: {} lamports", bounty.index, bounty.project, amount);
+        Ok(())
+    }
+
+    /// Assign (or reassign) an unsubmitted bounty to an accepted project member
+    pub fn assign_bounty(ctx: Context<AssignBounty>, assignee: Pubkey) -> Result<()> {
+        require!(
+            ctx.accounts.member_request.status == RequestStatus::Accepted,
+            ErrorCode::NotAMember
+        );
+        let bounty = &mut ctx.accounts.bounty;
+        require!(
+            matches!(bounty.status, BountyStatus::Open | BountyStatus::Assigned),
+            ErrorCode::InvalidBountyStatus
+        );
+        require!(Clock::get()?.unix_timestamp <= bounty.deadline, ErrorCode::BountyDeadlinePassed);
+        // A new assignee has not had a dispute of their own yet
+        if bounty.assignee != assignee {
+            bounty.lapsed_disputes &= !2;
+        }
+        bounty.assignee = assignee;
+        bounty.status = BountyStatus::Assigned;
+        msg!("Bounty #{} assigned to {}", bounty.index, assignee);
+        Ok(())
+    }
+
+    /// Submit work for an assigned bounty before its deadline (assignee only)
+    pub fn submit_bounty(ctx: Context<SubmitBounty>, submission_ipfs_hash: String) -> Result<()> {
+        require!(submission_ipfs_hash.len() <= 64, ErrorCode::IpfsHashTooLong);
+        let bounty = &mut ctx.accounts.bounty;
+        require!(bounty.status == BountyStatus::Assigned, ErrorCode::InvalidBountyStatus);
+        require!(Clock::get()?.unix_timestamp <= bounty.deadline, ErrorCode::BountyDeadlinePassed);
+        bounty.submission_ipfs_hash = submission_ipfs_hash;
+        bounty.status = BountyStatus::Submitted;
+        msg!("Bounty #{} submitted by {}", bounty.index, bounty.assignee);
+        Ok(())
+    }
+
+    /// Approve a submission and pay the escrow to the assignee
+    pub fn approve_bounty(ctx: Context<ApproveBounty>) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty;
+        require!(bounty.status == BountyStatus::Submitted, ErrorCode::InvalidBountyStatus);
+        bounty.sub_lamports(bounty.amount)?;
+        ctx.accounts.assignee.add_lamports(bounty.amount)?;
+        bounty.status = BountyStatus::Paid;
+        release_escrow(&mut ctx.accounts.project);
+        msg!("Bounty #{} paid: {} lamports to {}", bounty.index, bounty.amount, bounty.assignee);
+        Ok(())
+    }
+
+    /// Refund the escrow to the funder: any time while open, or once an assignee missed the deadline
+    pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.ensure_cancellable(Clock::get()?.unix_timestamp)?;
+        bounty.sub_lamports(bounty.amount)?;
+        ctx.accounts.funder.add_lamports(bounty.amount)?;
+        bounty.status = BountyStatus::Cancelled;
+        release_escrow(&mut ctx.accounts.project);
+        msg!("Bounty #{} cancelled, {} lamports refunded", bounty.index, bounty.amount);
+        Ok(())
+    }
+
+    /// Post a bounty paid in an allowlisted SPL Token / Token-2022 mint
+    ///
+    /// Funds move into the project's vault ATA (owned by its `vault_authority` PDA). With a
+    /// transfer fee the bounty escrows what actually arrived, not the amount sent.
+    pub fn create_token_bounty(
+        ctx: Context<CreateTokenBounty>,
+        title: String,
+        spec_ipfs_hash: String,
+        amount: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        open_bounty(
+            &mut ctx.accounts.project,
+            &mut ctx.accounts.bounty,
+            ctx.accounts.manager.key(),
+            ctx.accounts.mint.key(),
+            title,
+            spec_ipfs_hash,
+            amount,
+            deadline,
+            now,
+        )?;
+        ctx.accounts.bounty.bump = ctx.bumps.bounty;
+
+        let balance_before = ctx.accounts.vault.amount;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.funder_token.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.manager.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+        ctx.accounts.vault.reload()?;
+        let received = ctx.accounts.vault.amount.saturating_sub(balance_before);
+        require!(received > 0, ErrorCode::InvalidBountyAmount);
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.amount = received;
+        msg!(
+            "Bounty #{} posted on {}: {} of mint {}",
+            bounty.index,
+            bounty.project,
+            received,
+            bounty.mint
+        );
+        Ok(())
+    }
+
+    /// Approve a submission and pay the escrowed tokens to the assignee
+    pub fn approve_token_bounty(ctx: Context<ApproveTokenBounty>) -> Result<()> {
+        require!(
+            ctx.accounts.bounty.status == BountyStatus::Submitted,
+            ErrorCode::InvalidBountyStatus
+        );
+        let amount = ctx.accounts.bounty.amount;
+        transfer_from_project_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.assignee_token,
+            &ctx.accounts.vault_authority,
+            &ctx.accounts.project.key(),
+            ctx.bumps.vault_authority,
+            amount,
+        )?;
+        release_escrow(&mut ctx.accounts.project);
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.status = BountyStatus::Paid;
+        msg!("Bounty #{} paid: {} of mint {} to {}", bounty.index, amount, bounty.mint, bounty.assignee);
+        Ok(())
+    }
+
+    /// Refund escrowed tokens to the funder, under the same rules as `cancel_bounty`
+    pub fn cancel_token_bounty(ctx: Context<CancelTokenBounty>) -> Result<()> {
+        ctx.accounts.bounty.ensure_cancellable(Clock::get()?.unix_timestamp)?;
+        let amount = ctx.accounts.bounty.amount;
+        transfer_from_project_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.funder_token,
+            &ctx.accounts.vault_authority,
+            &ctx.accounts.project.key(),
+            ctx.bumps.vault_authority,
+            amount,
+        )?;
+        release_escrow(&mut ctx.accounts.project);
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.status = BountyStatus::Cancelled;
+        msg!("Bounty #{} cancelled, {} of mint {} refunded", bounty.index, amount, bounty.mint);
+        Ok(())
+    }
+
+    /// Close a settled (paid, cancelled or ruled on) bounty, returning its rent to the funder
+    pub fn reclaim_bounty(ctx: Context<ReclaimBounty>) -> Result<()> {
+        let bounty = &ctx.accounts.bounty;
+        require!(
+            matches!(
+                bounty.status,
+                BountyStatus::Paid | BountyStatus::Cancelled | BountyStatus::Resolved
+            ),
+            ErrorCode::InvalidBountyStatus
+        );
+        // The account is closed to the funder by the context attribute
+        msg!("Bounty #{} reclaimed", bounty.index);
+        Ok(())
+    }
+
+    // ==================== STREAM INSTRUCTIONS ====================
+
+    /// Stream SOL to an accepted member: `rate` lamports per second from `start` to `end`
+    ///
+    /// Nothing vests before `cliff`; at the cliff the amount accrued since `start` unlocks.
+    /// The full `rate * (end - start)` is escrowed in the stream PDA up front.
+    pub fn create_stream(
+        ctx: Context<CreateStream>,
+        start: i64,
+        cliff: i64,
+        end: i64,
+        rate: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.collab_request.status == RequestStatus::Accepted,
+            ErrorCode::NotAMember
+        );
+        require!(start <= cliff && cliff <= end && start < end, ErrorCode::InvalidStreamSchedule);
+        require!(rate > 0, ErrorCode::InvalidStreamSchedule);
+        let total = rate
+            .checked_mul((end - start) as u64)
+            .ok_or(ErrorCode::InvalidStreamSchedule)?;
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: ctx.accounts.stream.to_account_info(),
+                },
+            ),
+            total,
+        )?;
+
+        let stream = &mut ctx.accounts.stream;
+        stream.project = ctx.accounts.project.key();
+        stream.collab_request = ctx.accounts.collab_request.key();
+        stream.funder = ctx.accounts.funder.key();
+        stream.recipient = ctx.accounts.collab_request.from;
+        stream.start = start;
+        stream.cliff = cliff;
+        stream.end = end;
+        stream.rate = rate;
+        stream.total = total;
+        stream.withdrawn = 0;
+        stream.paused_at = 0;
+        stream.frozen = false;
+        stream.lapsed_disputes = 0;
+        stream.bump = ctx.bumps.stream;
+
+        msg!("Stream of {} lamports to {} created", total, stream.recipient);
+        Ok(())
+    }
+
+    /// Withdraw everything vested so far (recipient only)
+    pub fn withdraw_stream(ctx: Context<WithdrawStream>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let stream = &mut ctx.accounts.stream;
+        require!(!stream.frozen, ErrorCode::EscrowFrozen);
+        stream.sync_membership(&ctx.accounts.collab_request, now)?;
+
+        let amount = stream.vested(now).saturating_sub(stream.withdrawn);
+        require!(amount > 0, ErrorCode::NothingToWithdraw);
+        stream.withdrawn += amount;
+        stream.sub_lamports(amount)?;
+        ctx.accounts.recipient.add_lamports(amount)?;
+
+        msg!("Stream withdrawal: {} lamports to {}", amount, stream.recipient);
+        Ok(())
+    }
+
+    /// Cancel a stream: vested lamports go to the recipient, the rest back to the funder
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let stream = &mut ctx.accounts.stream;
+        require!(!stream.frozen, ErrorCode::EscrowFrozen);
+        stream.sync_membership(&ctx.accounts.collab_request, now)?;
+
+        let owed = stream.vested(now).saturating_sub(stream.withdrawn);
+        if owed > 0 {
+            stream.withdrawn += owed;
+            stream.sub_lamports(owed)?;
+            ctx.accounts.recipient.add_lamports(owed)?;
+        }
+        // Unvested escrow and rent are closed to the funder via context 'close'
+        msg!(
+            "Stream cancelled: {} lamports to {}, {} returned",
+            owed,
+            stream.recipient,
+            stream.total - stream.withdrawn
+        );
+        Ok(())
+    }
+
+    // ==================== DISPUTE INSTRUCTIONS ====================
+
+    /// Contest an assigned or submitted bounty (funder or assignee), freezing its escrow
+    ///
+    /// An arbiter from the config set rules on a split before the dispute times out; after
+    /// that anyone can expire it and the bounty resumes where it was. A party whose dispute
+    /// timed out can't open another on the same bounty.
+    pub fn open_bounty_dispute(ctx: Context<OpenBountyDispute>, evidence_ipfs_hash: String) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty;
+        require!(
+            matches!(bounty.status, BountyStatus::Assigned | BountyStatus::Submitted),
+            ErrorCode::InvalidBountyStatus
+        );
+        let dispute = &mut ctx.accounts.dispute;
+        open_dispute(
+            dispute,
+            &mut ctx.accounts.config,
+            bounty.key(),
+            DisputeKind::Bounty,
+            bounty.project,
+            bounty.funder,
+            bounty.assignee,
+            ctx.accounts.claimant.key(),
+            evidence_ipfs_hash,
+            bounty.amount,
+            &Clock::get()?,
+        )?;
+        require!(
+            bounty.lapsed_disputes & dispute.claimant_flag() == 0,
+            ErrorCode::DisputeAlreadyLapsed
+        );
+        dispute.bounty_status = bounty.status;
+        dispute.bump = ctx.bumps.dispute;
+        bounty.status = BountyStatus::Disputed;
+
+        msg!("Bounty #{} disputed by {}, arbiter {}", bounty.index, dispute.claimant, dispute.arbiter);
+        Ok(())
+    }
+
+    /// Contest a stream (funder or recipient), freezing what is left of its escrow; once per
+    /// party if their dispute times out
+    pub fn open_stream_dispute(ctx: Context<OpenStreamDispute>, evidence_ipfs_hash: String) -> Result<()> {
+        let stream = &mut ctx.accounts.stream;
+        let dispute = &mut ctx.accounts.dispute;
+        open_dispute(
+            dispute,
+            &mut ctx.accounts.config,
+            stream.key(),
+            DisputeKind::Stream,
+            stream.project,
+            stream.funder,
+            stream.recipient,
+            ctx.accounts.claimant.key(),
+            evidence_ipfs_hash,
+            stream.total - stream.withdrawn,
+            &Clock::get()?,
+        )?;
+        require!(
+            stream.lapsed_disputes & dispute.claimant_flag() == 0,
+            ErrorCode::DisputeAlreadyLapsed
+        );
+        dispute.bump = ctx.bumps.dispute;
+        stream.frozen = true;
+
+        msg!("Stream {} disputed by {}, arbiter {}", stream.key(), dispute.claimant, dispute.arbiter);
+        Ok(())
+    }
+
+    /// Attach the other party's evidence to an open dispute (respondent only)
+    pub fn respond_to_dispute(ctx: Context<RespondToDispute>, evidence_ipfs_hash: String) -> Result<()> {
+        require!(evidence_ipfs_hash.len() <= 64, ErrorCode::IpfsHashTooLong);
+        let dispute = &mut ctx.accounts.dispute;
+        require!(dispute.status == DisputeStatus::Open, ErrorCode::DisputeNotOpen);
+        dispute.respondent_evidence = evidence_ipfs_hash;
+        msg!("Dispute over {} answered by {}", dispute.escrow, dispute.respondent);
+        Ok(())
+    }
+
+    /// Rule on a SOL bounty dispute: after the arbiter fee, `recipient_bps` of the escrow goes
+    /// to the assignee and the rest back to the funder (assigned arbiter only)
+    pub fn resolve_bounty_dispute(ctx: Context<ResolveBountyDispute>, recipient_bps: u16) -> Result<()> {
+        let dispute = &mut ctx.accounts.dispute;
+        let (fee, to_recipient, to_funder) = dispute.rule(recipient_bps, Clock::get()?.unix_timestamp)?;
+
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.sub_lamports(dispute.amount)?;
+        ctx.accounts.arbiter.add_lamports(fee)?;
+        ctx.accounts.assignee.add_lamports(to_recipient)?;
+        ctx.accounts.funder.add_lamports(to_funder)?;
+        bounty.status = BountyStatus::Resolved;
+        release_escrow(&mut ctx.accounts.project);
+
+        msg!(
+            "Bounty #{} ruled: {} to assignee, {} to funder, {} arbiter fee",
+            bounty.index,
+            to_recipient,
+            to_funder,
+            fee
+        );
+        Ok(())
+    }
+
+    /// Rule on a token bounty dispute, splitting the vaulted tokens like `resolve_bounty_dispute`
+    pub fn resolve_token_bounty_dispute(
+        ctx: Context<ResolveTokenBountyDispute>,
+        recipient_bps: u16,
+    ) -> Result<()> {
+        let (fee, to_recipient, to_funder) =
+            ctx.accounts.dispute.rule(recipient_bps, Clock::get()?.unix_timestamp)?;
+
+        let project = ctx.accounts.bounty.project;
+        for (to, amount) in [
+            (&ctx.accounts.arbiter_token, fee),
+            (&ctx.accounts.assignee_token, to_recipient),
+            (&ctx.accounts.funder_token, to_funder),
+        ] {
+            if amount > 0 {
+                transfer_from_project_vault(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.vault,
+                    &ctx.accounts.mint,
+                    to,
+                    &ctx.accounts.vault_authority,
+                    &project,
+                    ctx.bumps.vault_authority,
+                    amount,
+                )?;
+            }
+        }
+        release_escrow(&mut ctx.accounts.project);
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.status = BountyStatus::Resolved;
+
+        msg!(
+            "Bounty #{} ruled: {} to assignee, {} to funder, {} arbiter fee (mint {})",
+            bounty.index,
+            to_recipient,
+            to_funder,
+            fee,
+            bounty.mint
+        );
+        Ok(())
+    }
+
+    /// Rule on a stream dispute, splitting its remaining escrow and ending the stream
+    pub fn resolve_stream_dispute(ctx: Context<ResolveStreamDispute>, recipient_bps: u16) -> Result<()> {
+        let (fee, to_recipient, to_funder) =
+            ctx.accounts.dispute.rule(recipient_bps, Clock::get()?.unix_timestamp)?;
+
+        let stream = &mut ctx.accounts.stream;
+        stream.sub_lamports(fee + to_recipient)?;
+        ctx.accounts.arbiter.add_lamports(fee)?;
+        ctx.accounts.recipient.add_lamports(to_recipient)?;
+        // The funder's share leaves with the rent via context 'close'
+        msg!(
+            "Stream {} ruled: {} to recipient, {} to funder, {} arbiter fee",
+            stream.key(),
+            to_recipient,
+            to_funder,
+            fee
+        );
+        Ok(())
+    }
+
+    /// Unfreeze a bounty whose arbiter missed the ruling window (anyone); it resumes where it was
+    pub fn expire_bounty_dispute(ctx: Context<ExpireBountyDispute>) -> Result<()> {
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.expire(Clock::get()?.unix_timestamp)?;
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.status = dispute.bounty_status;
+        bounty.lapsed_disputes |= dispute.claimant_flag();
+        msg!("Dispute over bounty #{} timed out", bounty.index);
+        Ok(())
+    }
+
+    /// Unfreeze a stream whose arbiter missed the ruling window (anyone)
+    pub fn expire_stream_dispute(ctx: Context<ExpireStreamDispute>) -> Result<()> {
+        let dispute = &mut ctx.accounts.dispute;
+        dispute.expire(Clock::get()?.unix_timestamp)?;
+        let stream = &mut ctx.accounts.stream;
+        stream.frozen = false;
+        stream.lapsed_disputes |= dispute.claimant_flag();
+        msg!("Dispute over stream {} timed out", stream.key());
+        Ok(())
+    }
+
+    // ==================== DONATION INSTRUCTIONS ====================
+
+    /// Open the project's donation vault so supporters can give SOL and tokens (creator only)
+    pub fn open_donation_vault(ctx: Context<OpenDonationVault>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.project = ctx.accounts.project.key();
+        vault.token_raised = Vec::new();
+        vault.bump = ctx.bumps.vault;
+        let project = &mut ctx.accounts.project;
+        project.live_escrows = project.live_escrows.saturating_add(1);
+        msg!("Donation vault opened for {}", project.name);
+        Ok(())
+    }
+
+    /// Close an emptied donation vault, returning its rent (creator only)
+    ///
+    /// Remaining accounts are the vault's token account for each mint it has received, in
+    /// `token_raised` order; every one must be empty, as must the vault itself.
+    pub fn close_donation_vault(ctx: Context<CloseDonationVault>) -> Result<()> {
+        let vault = &ctx.accounts.vault;
+        let info = vault.to_account_info();
+        require!(
+            info.lamports() <= Rent::get()?.minimum_balance(info.data_len()),
+            ErrorCode::DonationsPending
+        );
+        require!(
+            ctx.remaining_accounts.len() == vault.token_raised.len(),
+            ErrorCode::DonationsPending
+        );
+        for (total, token_info) in vault.token_raised.iter().zip(ctx.remaining_accounts) {
+            let expected = anchor_spl::associated_token::get_associated_token_address_with_program_id(
+                &vault.key(),
+                &total.mint,
+                token_info.owner,
+            );
+            require_keys_eq!(token_info.key(), expected, ErrorCode::DonationsPending);
+            let token = TokenAccount::try_deserialize(&mut &token_info.data.borrow()[..])?;
+            require!(token.amount == 0, ErrorCode::DonationsPending);
+        }
+
+        release_escrow(&mut ctx.accounts.project);
+        // Account will be closed to the creator via context 'close'
+        msg!("Donation vault closed for {}", ctx.accounts.project.name);
+        Ok(())
+    }
+
+    /// Donate SOL to a project (anyone); pass `receipt` to keep an on-chain Donation record
+    pub fn donate(ctx: Context<Donate>, amount: u64, memo: String) -> Result<()> {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.donor.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let project = &mut ctx.accounts.project;
+        project.donations_raised = project.donations_raised.saturating_add(amount);
+        record_donation(
+            project,
+            &mut ctx.accounts.donor_record,
+            ctx.accounts.receipt.as_deref_mut(),
+            ctx.accounts.donor.key(),
+            Pubkey::default(),
+            amount,
+            memo,
+            ctx.bumps.donor_record,
+            ctx.bumps.receipt,
+        )?;
+        msg!("{} lamports donated to {}", amount, project.name);
+        Ok(())
+    }
+
+    /// Donate an allowlisted SPL token; the vault records what arrived net of transfer fees
+    pub fn donate_token(ctx: Context<DonateToken>, amount: u64, memo: String) -> Result<()> {
+        let balance_before = ctx.accounts.vault_token.amount;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.donor_token.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.vault_token.to_account_info(),
+                    authority: ctx.accounts.donor.to_account_info(),
+                },
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+        ctx.accounts.vault_token.reload()?;
+        let received = ctx.accounts.vault_token.amount.saturating_sub(balance_before);
+        require!(received > 0, ErrorCode::InvalidDonationAmount);
+
+        let mint = ctx.accounts.mint.key();
+        ctx.accounts.vault.add_token_raised(mint, received)?;
+        record_donation(
+            &mut ctx.accounts.project,
+            &mut ctx.accounts.donor_record,
+            ctx.accounts.receipt.as_deref_mut(),
+            ctx.accounts.donor.key(),
+            mint,
+            received,
+            memo,
+            ctx.bumps.donor_record,
+            ctx.bumps.receipt,
+        )?;
+        msg!("{} of mint {} donated to {}", received, mint, ctx.accounts.project.name);
+        Ok(())
+    }
+
+    /// Withdraw SOL donations (creator only; projects with approvers use a WithdrawDonations proposal)
+    pub fn withdraw_donations(ctx: Context<WithdrawDonations>, amount: u64) -> Result<()> {
+        require!(ctx.accounts.project.approval_threshold == 0, ErrorCode::ApprovalRequired);
+        withdraw_sol_donations(&ctx.accounts.vault, &ctx.accounts.recipient, amount)?;
+        emit!(DonationWithdrawn {
+            project: ctx.accounts.project.key(),
+            mint: Pubkey::default(),
+            amount,
+            recipient: ctx.accounts.recipient.key(),
+            proposal: None,
+        });
+        msg!("{} lamports of donations withdrawn to {}", amount, ctx.accounts.recipient.key());
+        Ok(())
+    }
+
+    /// Withdraw SPL token donations, under the same rules as `withdraw_donations`
+    pub fn withdraw_token_donations(ctx: Context<WithdrawTokenDonations>, amount: u64) -> Result<()> {
+        require!(ctx.accounts.project.approval_threshold == 0, ErrorCode::ApprovalRequired);
+        transfer_from_donation_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.vault_token,
+            &ctx.accounts.mint,
+            &ctx.accounts.recipient_token,
+            &ctx.accounts.vault,
+            amount,
+        )?;
+        emit!(DonationWithdrawn {
+            project: ctx.accounts.project.key(),
+            mint: ctx.accounts.mint.key(),
+            amount,
+            recipient: ctx.accounts.recipient_token.owner,
+            proposal: None,
+        });
+        msg!("{} of mint {} withdrawn from donations", amount, ctx.accounts.mint.key());
+        Ok(())
+    }
+
+    // ==================== GRANT ROUND INSTRUCTIONS ====================
+
+    /// Start a quadratic-funding round, escrowing `matching_pool` lamports in the round PDA
+    ///
+    /// Projects register in [registration_start, registration_end), the operator marks them
+    /// eligible, and profiles contribute in [contribution_start, contribution_end).
+    pub fn create_grant_round(
+        ctx: Context<CreateGrantRound>,
+        name: String,
+        matching_pool: u64,
+        registration_start: i64,
+        registration_end: i64,
+        contribution_start: i64,
+        contribution_end: i64,
+    ) -> Result<()> {
+        require!(name.len() <= SEED_NAME_MAX_LEN, ErrorCode::SeedNameTooLong);
+        require!(
+            registration_start < registration_end
+                && registration_end <= contribution_start
+                && contribution_start < contribution_end,
+            ErrorCode::InvalidGrantSchedule
+        );
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.operator.to_account_info(),
+                    to: ctx.accounts.round.to_account_info(),
+                },
+            ),
+            matching_pool,
+        )?;
+
+        let round = &mut ctx.accounts.round;
+        round.operator = ctx.accounts.operator.key();
+        round.name = name;
+        round.matching_pool = matching_pool;
+        round.registration_start = registration_start;
+        round.registration_end = registration_end;
+        round.contribution_start = contribution_start;
+        round.contribution_end = contribution_end;
+        round.projects = Vec::new();
+        round.total_score = 0;
+        round.status = GrantRoundStatus::Active;
+        round.bump = ctx.bumps.round;
+
+        msg!("Grant round {} created with a {} lamport pool", round.name, matching_pool);
+        Ok(())
+    }
+
+    /// Add lamports to a round's matching pool (anyone, until contributions close)
+    pub fn fund_grant_round(ctx: Context<FundGrantRound>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidGrantAmount);
+        require!(
+            Clock::get()?.unix_timestamp < ctx.accounts.round.contribution_end,
+            ErrorCode::GrantWindowClosed
+        );
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: ctx.accounts.round.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        let round = &mut ctx.accounts.round;
+        round.matching_pool = round.matching_pool.saturating_add(amount);
+        msg!("Grant round {} pool now {} lamports", round.name, round.matching_pool);
+        Ok(())
+    }
+
+    /// Register a project for a round during its registration window (project creator only)
+    pub fn register_grant_project(ctx: Context<RegisterGrantProject>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let project = &mut ctx.accounts.project;
+        let round = &mut ctx.accounts.round;
+        require!(
+            now >= round.registration_start && now < round.registration_end,
+            ErrorCode::GrantWindowClosed
+        );
+        require!(project.is_active, ErrorCode::ProjectNotAccepting);
+        require!(round.entry(&project.key()).is_none(), ErrorCode::GrantProjectAlreadyRegistered);
+        require!(round.projects.len() < MAX_ROUND_PROJECTS, ErrorCode::TooManyGrantProjects);
+        round.projects.push(RoundProject {
+            project: project.key(),
+            eligible: false,
+            contributed: 0,
+            contributors: 0,
+            sum_sqrt: 0,
+            matched: 0,
+            paid: false,
+        });
+        // Held until the payout, which is sent to this project's creator
+        project.live_escrows = project.live_escrows.saturating_add(1);
+        msg!("Project {} registered for grant round {}", project.name, round.name);
+        Ok(())
+    }
+
+    /// Mark a registered project (in)eligible for contributions, before they open (operator only)
+    pub fn set_grant_eligibility(
+        ctx: Context<SetGrantEligibility>,
+        project: Pubkey,
+        eligible: bool,
+    ) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+        require!(
+            Clock::get()?.unix_timestamp < round.contribution_start,
+            ErrorCode::GrantWindowClosed
+        );
+        let entry = round.entry_mut(&project).ok_or(ErrorCode::GrantProjectNotEligible)?;
+        entry.eligible = eligible;
+        msg!("Project {} eligible for grant round: {}", project, eligible);
+        Ok(())
+    }
+
+    /// Contribute lamports to an eligible project during the contribution window (profiles only)
+    ///
+    /// The contributor's square-root term is scaled by their sybil weight (`is_verified`,
+    /// reputation) as of this contribution.
+    pub fn contribute_to_grant(ctx: Context<ContributeToGrant>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidGrantAmount);
+        let now = Clock::get()?.unix_timestamp;
+        let round = &ctx.accounts.round;
+        require!(
+            now >= round.contribution_start && now < round.contribution_end,
+            ErrorCode::GrantWindowClosed
+        );
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.contributor.to_account_info(),
+                    to: ctx.accounts.round.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let project = ctx.accounts.project.key();
+        let contribution = &mut ctx.accounts.contribution;
+        let first = contribution.amount == 0;
+        if first {
+            contribution.round = ctx.accounts.round.key();
+            contribution.project = project;
+            contribution.contributor = ctx.accounts.contributor.key();
+            contribution.bump = ctx.bumps.contribution;
+        }
+        let previous_term = contribution.weighted_sqrt;
+        contribution.amount = contribution.amount.saturating_add(amount);
+        contribution.weighted_sqrt =
+            integer_sqrt(contribution.amount) * sybil_weight_bps(&ctx.accounts.contributor_user) / 10_000;
+
+        let entry = ctx
+            .accounts
+            .round
+            .entry_mut(&project)
+            .filter(|e| e.eligible)
+            .ok_or(ErrorCode::GrantProjectNotEligible)?;
+        entry.contributed = entry.contributed.saturating_add(amount);
+        entry.sum_sqrt = entry.sum_sqrt - previous_term + contribution.weighted_sqrt;
+        if first {
+            entry.contributors = entry.contributors.saturating_add(1);
+        }
+
+        msg!("{} lamports contributed to {} (weighted sqrt {})", amount, project, contribution.weighted_sqrt);
+        Ok(())
+    }
+
+    /// Close contributions and fix each project's share of the matching pool (anyone)
+    ///
+    /// Shares are proportional to (sum of weighted square roots)^2. All per-project tallies
+    /// live in the round, so this is a single bounded pass; payouts are chunked through
+    /// `distribute_grant_round`.
+    pub fn finalize_grant_round(ctx: Context<FinalizeGrantRound>) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+        require!(round.status == GrantRoundStatus::Active, ErrorCode::InvalidGrantRoundStatus);
+        require!(
+            Clock::get()?.unix_timestamp >= round.contribution_end,
+            ErrorCode::GrantWindowOpen
+        );
+
+        let total_score: u128 = round.projects.iter().map(RoundProject::score).sum();
+        let pool = round.matching_pool as u128;
+        for entry in round.projects.iter_mut() {
+            entry.matched = (pool * entry.score()).checked_div(total_score).unwrap_or(0) as u64;
+        }
+        round.total_score = total_score;
+        round.status = GrantRoundStatus::Finalized;
+
+        msg!("Grant round {} finalized across {} projects", round.name, round.projects.len());
+        Ok(())
+    }
+
+    /// Pay finalized projects their contributions plus match (anyone)
+    ///
+    /// Remaining accounts are (writable project, project creator) pairs; already-paid projects
+    /// are skipped, so large rounds can be paid out over several transactions. Every
+    /// registered project goes through here, even with nothing to pay, to release it.
+    pub fn distribute_grant_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeGrantRound<'info>>,
+    ) -> Result<()> {
+        let round = &mut ctx.accounts.round;
+        require!(round.status == GrantRoundStatus::Finalized, ErrorCode::InvalidGrantRoundStatus);
+        require!(ctx.remaining_accounts.len().is_multiple_of(2), ErrorCode::InvalidGrantPayoutAccounts);
+
+        let round_info = round.to_account_info();
+        let mut paid = 0u32;
+        for pair in ctx.remaining_accounts.chunks(2) {
+            let (project_info, recipient) = (&pair[0], &pair[1]);
+            require!(
+                project_info.owner == &crate::ID && project_info.is_writable,
+                ErrorCode::InvalidGrantPayoutAccounts
+            );
+            let mut project = Project::try_deserialize(&mut &project_info.data.borrow()[..])?;
+            require_keys_eq!(project.creator, recipient.key(), ErrorCode::InvalidGrantPayoutAccounts);
+
+            let entry = round
+                .entry_mut(project_info.key)
+                .ok_or(ErrorCode::InvalidGrantPayoutAccounts)?;
+            if entry.paid {
+                continue;
+            }
+            let payout = entry.contributed + entry.matched;
+            entry.paid = true;
+            round_info.sub_lamports(payout)?;
+            recipient.add_lamports(payout)?;
+            release_escrow(&mut project);
+            project.try_serialize(&mut &mut project_info.data.borrow_mut()[..])?;
+            paid += 1;
+        }
+
+        msg!("Grant round {}: {} projects paid", round.name, paid);
+        Ok(())
+    }
+
+    /// Close a round once every project is paid, returning unallocated pool dust and rent
+    /// (operator only)
+    pub fn close_grant_round(ctx: Context<CloseGrantRound>) -> Result<()> {
+        let round = &ctx.accounts.round;
+        require!(round.status == GrantRoundStatus::Finalized, ErrorCode::InvalidGrantRoundStatus);
+        require!(
+            round.projects.iter().all(|e| e.paid),
+            ErrorCode::GrantPayoutsPending
+        );
+        // Account will be closed to the operator via context 'close'
+        msg!("Grant round {} closed", round.name);
+        Ok(())
+    }
+
+    // ==================== HACKATHON EVENT INSTRUCTIONS ====================
+
+    /// Create a hackathon, escrowing the sum of `prizes` (lamports for 1st, 2nd, ...) in the event PDA
+    pub fn create_event(
+        ctx: Context<CreateEvent>,
+        name: String,
+        judges: Vec<Pubkey>,
+        tracks: Vec<String>,
+        start_time: i64,
+        end_time: i64,
+        max_team_size: u8,
+        prizes: Vec<u64>,
+    ) -> Result<()> {
+        require!(name.len() <= SEED_NAME_MAX_LEN, ErrorCode::SeedNameTooLong);
+        require!(start_time < end_time, ErrorCode::InvalidEventSchedule);
+        require!(max_team_size > 0, ErrorCode::InvalidTeamSizeCap);
+        require!(judges.len() <= MAX_EVENT_JUDGES, ErrorCode::TooManyJudges);
+        for (i, j) in judges.iter().enumerate() {
+            require!(!judges[..i].contains(j), ErrorCode::TooManyJudges);
+        }
+        require!(!tracks.is_empty() && tracks.len() <= MAX_EVENT_TRACKS, ErrorCode::InvalidEventTracks);
+        for t in tracks.iter() { require!(t.len() <= 24, ErrorCode::InvalidEventTracks); }
+        require!(prizes.len() <= MAX_EVENT_PRIZES, ErrorCode::InvalidEventPrizes);
+
+        let treasury = prizes
+            .iter()
+            .try_fold(0u64, |sum, p| sum.checked_add(*p))
+            .ok_or(ErrorCode::InvalidEventPrizes)?;
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.organizer.to_account_info(),
+                    to: ctx.accounts.event.to_account_info(),
+                },
+            ),
+            treasury,
+        )?;
+
+        let event = &mut ctx.accounts.event;
+        event.organizer = ctx.accounts.organizer.key();
+        event.name = name;
+        event.judges = judges;
+        event.tracks = tracks.into_iter().map(|v| ShortText{ value: v }).collect();
+        event.start_time = start_time;
+        event.end_time = end_time;
+        event.max_team_size = max_team_size;
+        event.prizes = prizes;
+        event.entries_settled = 0;
+        event.project_count = 0;
+        event.status = EventStatus::Open;
+        event.bump = ctx.bumps.event;
+
+        msg!("Event {} created with a {} lamport treasury", event.name, treasury);
+        Ok(())
+    }
+
+    /// Add or remove a judge until the event ends (organizer only)
+    pub fn set_event_judge(ctx: Context<ManageEvent>, judge: Pubkey, allowed: bool) -> Result<()> {
+        let event = &mut ctx.accounts.event;
+        require!(Clock::get()?.unix_timestamp < event.end_time, ErrorCode::EventEnded);
+        if allowed {
+            if !event.judges.contains(&judge) {
+                require!(event.judges.len() < MAX_EVENT_JUDGES, ErrorCode::TooManyJudges);
+                event.judges.push(judge);
+            }
+        } else {
+            event.judges.retain(|j| j != &judge);
+        }
+        msg!("Event {} judge {}: {}", event.name, judge, allowed);
+        Ok(())
+    }
+
+    /// Enter a project in an event track until it ends (project creator only)
+    ///
+    /// The team (creator plus every role slot) must fit the event's cap, which then stays
+    /// on the project until the event ends so role updates can't grow past it meanwhile.
+    pub fn register_event_project(ctx: Context<RegisterEventProject>, track: u8) -> Result<()> {
+        let event = &mut ctx.accounts.event;
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < event.end_time, ErrorCode::EventEnded);
+        require!((track as usize) < event.tracks.len(), ErrorCode::InvalidEventTracks);
+        require!(event.project_count < MAX_EVENT_PROJECTS, ErrorCode::EventFull);
+
+        let project = &mut ctx.accounts.project;
+        require!(project.is_active, ErrorCode::ProjectNotAccepting);
+        // Overlapping events keep the tightest cap until the last of them ends
+        project.team_size_cap = match project.active_team_cap(now) {
+            Some(cap) => cap.min(event.max_team_size),
+            None => event.max_team_size,
+        };
+        project.team_cap_ends_at = project.team_cap_ends_at.max(event.end_time);
+        project.check_team_cap(&project.required_roles, now)?;
+        event.project_count += 1;
+        // Held until the entry is settled, which pays this project's creator
+        project.live_escrows = project.live_escrows.saturating_add(1);
+
+        let entry = &mut ctx.accounts.entry;
+        entry.event = event.key();
+        entry.project = project.key();
+        entry.track = track;
+        entry.scores = Vec::new();
+        entry.rank = 0;
+        entry.prize_paid = false;
+        entry.bump = ctx.bumps.entry;
+
+        msg!("Project {} entered event {} ({})", project.name, event.name, event.tracks[track as usize].value);
+        Ok(())
+    }
+
+    /// Score an entry after the event ends and before rankings are final (judges only)
+    ///
+    /// Judges can't score their own project; re-scoring replaces their earlier score.
+    pub fn score_event_project(ctx: Context<ScoreEventProject>, score: u8) -> Result<()> {
+        let event = &ctx.accounts.event;
+        let judge = ctx.accounts.judge.key();
+        require!(event.judges.contains(&judge), ErrorCode::NotAJudge);
+        require!(judge != ctx.accounts.project.creator, ErrorCode::NotAJudge);
+        require!(event.status == EventStatus::Open, ErrorCode::EventAlreadyRanked);
+        require!(Clock::get()?.unix_timestamp >= event.end_time, ErrorCode::EventNotEnded);
+        require!(score <= MAX_JUDGE_SCORE, ErrorCode::InvalidJudgeScore);
+
+        let entry = &mut ctx.accounts.entry;
+        match entry.scores.iter_mut().find(|s| s.judge == judge) {
+            Some(existing) => existing.score = score,
+            None => entry.scores.push(JudgeScore { judge, score }),
+        }
+        msg!("Judge {} scored {} for project {}", judge, score, entry.project);
+        Ok(())
+    }
+
+    /// Publish final rankings (organizer only)
+    ///
+    /// Remaining accounts are every entry of the event, writable, best first; the order is
+    /// checked against average judge scores and entries are ranked 1, 2, ... in that order.
+    pub fn rank_event_projects(ctx: Context<ManageEvent>) -> Result<()> {
+        let event = &mut ctx.accounts.event;
+        require!(event.status == EventStatus::Open, ErrorCode::EventAlreadyRanked);
+        require!(Clock::get()?.unix_timestamp >= event.end_time, ErrorCode::EventNotEnded);
+        require!(
+            ctx.remaining_accounts.len() == event.project_count as usize,
+            ErrorCode::InvalidEventRanking
+        );
+
+        let event_key = event.key();
+        let mut previous = u64::MAX;
+        for (i, info) in ctx.remaining_accounts.iter().enumerate() {
+            require!(info.owner == &crate::ID && info.is_writable, ErrorCode::InvalidEventRanking);
+            let mut data = info.try_borrow_mut_data()?;
+            let mut entry = EventEntry::try_deserialize(&mut &data[..])?;
+            require_keys_eq!(entry.event, event_key, ErrorCode::InvalidEventRanking);
+            // Already ranked means the same entry was passed twice
+            require!(entry.rank == 0, ErrorCode::InvalidEventRanking);
+            let average = entry.average_score();
+            require!(average <= previous, ErrorCode::InvalidEventRanking);
+            previous = average;
+            entry.rank = i as u8 + 1;
+            entry.try_serialize(&mut &mut data[..])?;
+        }
+
+        event.status = EventStatus::Ranked;
+        msg!("Event {} ranked {} projects", event.name, event.project_count);
+        Ok(())
+    }
+
+    /// Settle a ranked entry, paying its prize (if it placed) from the event treasury to its
+    /// project's creator (anyone)
+    pub fn claim_event_prize(ctx: Context<ClaimEventPrize>) -> Result<()> {
+        let event = &mut ctx.accounts.event;
+        let entry = &mut ctx.accounts.entry;
+        require!(event.status == EventStatus::Ranked, ErrorCode::EventNotRanked);
+        require!(!entry.prize_paid, ErrorCode::NoEventPrize);
+        let prize = match entry.rank {
+            0 => None,
+            rank => event.prizes.get(rank as usize - 1).copied(),
+        }
+        .unwrap_or(0);
+
+        entry.prize_paid = true;
+        event.entries_settled += 1;
+        event.sub_lamports(prize)?;
+        ctx.accounts.creator.add_lamports(prize)?;
+        release_escrow(&mut ctx.accounts.project);
+
+        msg!("Event {} paid {} lamports to rank {}", event.name, prize, entry.rank);
+        Ok(())
+    }
+
+    /// Close a ranked event once every entry is settled, returning unawarded prizes and rent
+    /// (organizer only)
+    pub fn close_event(ctx: Context<CloseEvent>) -> Result<()> {
+        let event = &ctx.accounts.event;
+        require!(event.status == EventStatus::Ranked, ErrorCode::EventNotRanked);
+        require!(event.entries_settled == event.project_count, ErrorCode::EventPrizesPending);
+        // Account will be closed to the organizer via context 'close'
+        msg!("Event {} closed", event.name);
+        Ok(())
+    }
+
+    // ==================== OWNERSHIP TRANSFER INSTRUCTIONS ====================
+
+    /// Propose handing the project to another user (creator only; replaces any pending proposal)
+    pub fn propose_transfer(ctx: Context<ProposeTransfer>) -> Result<()> {
+        let new_owner = ctx.accounts.new_owner_user.wallet;
+        let project = &mut ctx.accounts.project;
+        require!(project.approval_threshold == 0, ErrorCode::ApprovalRequired);
+        project.check_not_in_organization()?;
+        require!(new_owner != project.creator, ErrorCode::InvalidTransferTarget);
+        project.pending_owner = new_owner;
+        msg!("Ownership transfer of {} proposed to {}", project.name, new_owner);
+        Ok(())
+    }
+
+    /// Cancel a pending ownership transfer (creator only)
+    pub fn cancel_transfer(ctx: Context<CancelTransfer>) -> Result<()> {
+        let project = &mut ctx.accounts.project;
+        require!(project.pending_owner != Pubkey::default(), ErrorCode::NoPendingTransfer);
+        project.pending_owner = Pubkey::default();
+        msg!("Ownership transfer of {} cancelled", project.name);
+        Ok(())
+    }
+
+    /// Accept a pending transfer. Requests passed as remaining accounts are re-pointed to the
+    /// new owner; any others can be fixed later with `sync_request_owner`.
+    pub fn accept_transfer(ctx: Context<AcceptTransfer>) -> Result<()> {
+        let new_owner = ctx.accounts.new_owner.key();
+        let project_key = ctx.accounts.project.key();
+        let previous_owner = ctx.accounts.project.creator;
+        // The project may have joined an organization since the transfer was proposed
+        ctx.accounts.project.check_not_in_organization()?;
+
+        // The previous owner's profile may have been deleted since, or be a program creator
+        update_if_exists::<User>(&ctx.accounts.previous_owner_user, |u| {
+            u.projects_count = u.projects_count.saturating_sub(1);
+        })?;
+        update_if_exists::<ProgramCreator>(&ctx.accounts.previous_program_creator, |c| {
+            c.projects_count = c.projects_count.saturating_sub(1);
+        })?;
+        let new_owner_user = &mut ctx.accounts.new_owner_user;
+        new_owner_user.projects_count = new_owner_user.projects_count.saturating_add(1);
+
+        for info in ctx.remaining_accounts.iter() {
+            require!(
+                info.owner == &crate::ID && info.is_writable,
+                ErrorCode::InvalidRequestAccount
+            );
+            let mut data = info.try_borrow_mut_data()?;
+            let mut request = CollaborationRequest::try_deserialize(&mut &data[..])?;
+            require_keys_eq!(request.project, project_key, ErrorCode::InvalidRequestAccount);
+            // Resolved requests too: the owner is the one who may delete them
+            request.to = new_owner;
+            request.try_serialize(&mut &mut data[..])?;
+        }
+
+        let project = &mut ctx.accounts.project;
+        project.creator = new_owner;
+        project.pending_owner = Pubkey::default();
+        // Delegations granted under the previous owner lapse until the new owner renews them
+        project.owner_epoch = project.owner_epoch.saturating_add(1);
+        project.last_updated = Clock::get()?.unix_timestamp;
+
+        msg!("Project {} transferred from {} to {}", project.name, previous_owner, new_owner);
+        Ok(())
+    }
+
+    /// Re-point a request at the project's current owner (anyone can call)
+    pub fn sync_request_owner(ctx: Context<SyncRequestOwner>) -> Result<()> {
+        let owner = ctx.accounts.project.creator;
+        let request = &mut ctx.accounts.collab_request;
+        request.to = owner;
+        msg!("Collaboration request {:?} now addressed to {}", request.key(), owner);
+        Ok(())
+    }
+
+    /// Permanently delete a project and refund lamports to the creator
+    pub fn delete_project(ctx: Context<DeleteProject>) -> Result<()> {
+        require!(
+            ctx.accounts.project.approval_threshold == 0,
+            ErrorCode::ApprovalRequired
+        );
+        // The account is closed to the creator by the context attribute
+        retire_project(
+            &ctx.accounts.project,
+            &ctx.accounts.creator_user,
+            &ctx.accounts.program_creator,
+        )?;
+        let pk = ctx.accounts.project.key();
+        msg!("Project deleted: {:?}", pk);
+        Ok(())
+    }
+
+    /// Close project for collaboration (stop accepting requests)
+    pub fn close_project(ctx: Context<UpdateProject>) -> Result<()> {
+        let project = &mut ctx.accounts.project;
+        project.accepting_collaborations = CollaborationAcceptance::Closed;
+        project.last_updated = Clock::get()?.unix_timestamp;
+        msg!("Project closed for collaboration: {}", project.name);
+        Ok(())
+    }
+
+    /// Reopen project for collaboration (start accepting requests again)
+    pub fn reopen_project(ctx: Context<UpdateProject>) -> Result<()> {
+        let project = &mut ctx.accounts.project;
+        project.accepting_collaborations = CollaborationAcceptance::Open;
+        project.last_updated = Clock::get()?.unix_timestamp;
+        msg!("Project reopened for collaboration: {}", project.name);
+        Ok(())
+    }
+
+    /// Update project's role requirements (creator or MANAGE_MEMBERS delegate)
+    pub fn update_project_roles(
+        ctx: Context<UpdateProjectRoles>,
+        role_requirements: Vec<RoleRequirement>,
+    ) -> Result<()> {
+        let project = &mut ctx.accounts.project;
+        require!(project.approval_threshold == 0, ErrorCode::ApprovalRequired);
+        validate_role_requirements(&role_requirements)?;
+        project.check_team_cap(&role_requirements, Clock::get()?.unix_timestamp)?;
+
+        // Apply update
+        project.required_roles = role_requirements;
+        Ok(())
+    }
+}
+
+// ==================== ACCOUNT CONTEXTS ====================
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::DevcolSolana>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key())
+            @ ErrorCode::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetUserVerified<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", user.wallet.as_ref()],
+        bump = user.bump
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateUser<'info> {
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + User::INIT_SPACE,
+        seeds = [b"user", signer.key().as_ref()],
+        bump
+    )]
+    pub user: Account<'info, User>,
+
+    // Survives profile deletion, so recreating a profile doesn't reset its limits
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + RateState::INIT_SPACE,
+        seeds = [b"rate_state", signer.key().as_ref()],
+        bump
+    )]
+    pub rate_state: Account<'info, RateState>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateUser<'info> {
+    #[account(
+        mut,
+        seeds = [b"user", signer.key().as_ref()],
+        bump = user.bump,
+        has_one = wallet
+    )]
+    pub user: Account<'info, User>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// CHECK: This is the wallet that must match the user's wallet
+    pub wallet: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"rate_state", signer.key().as_ref()],
+        bump = rate_state.bump
+    )]
+    pub rate_state: Account<'info, RateState>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateUser<'info> {
+    /// CHECK: Signer's User PDA in any older layout; deserialized after it is grown
+    #[account(mut, owner = crate::ID, seeds = [b"user", signer.key().as_ref()], bump)]
+    pub user: UncheckedAccount<'info>,
+
+    // Profiles from before rate state moved out of `User` get theirs here
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + RateState::INIT_SPACE,
+        seeds = [b"rate_state", signer.key().as_ref()],
+        bump
+    )]
+    pub rate_state: Account<'info, RateState>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateProject<'info> {
+    /// CHECK: Project in any older layout; deserialized (and its owner checked) after it is grown
+    #[account(mut, owner = crate::ID)]
+    pub project: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateProject<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Project::INIT_SPACE,
+        seeds = [b"project", creator.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Require that the creator already has a User profile
+    #[account(
+        mut,
+        seeds = [b"user", creator.key().as_ref()],
+        bump = user.bump
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [b"rate_state", creator.key().as_ref()],
+        bump = rate_state.bump
+    )]
+    pub rate_state: Account<'info, RateState>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterProgramCreator<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProgramCreator::INIT_SPACE,
+        seeds = [b"program_creator", authority.key().as_ref()],
+        bump
+    )]
+    pub program_creator: Account<'info, ProgramCreator>,
+
+    /// Program-owned authority, signing via invoke_signed
+    #[account(constraint = is_off_curve(&authority.key()) @ ErrorCode::AuthorityOnCurve)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateProjectArgs)]
+pub struct CreateProgramProject<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Project::INIT_SPACE,
+        seeds = [b"project", creator.key().as_ref(), args.name.as_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"program_creator", creator.key().as_ref()],
+        bump = program_creator.bump
+    )]
+    pub program_creator: Account<'info, ProgramCreator>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+    
+    // Creator, or a delegate holding EDIT_PROJECT
+    #[account(
+        constraint = project.is_authorized(&authority.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::EDIT_PROJECT)
+            @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), authority.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    #[account(
+        seeds = [b"user", authority.key().as_ref()],
+        bump = authority_user.bump
+    )]
+    pub authority_user: Option<Account<'info, User>>,
+
+    // Required alongside `authority_user`
+    #[account(
+        mut,
+        seeds = [b"rate_state", authority.key().as_ref()],
+        bump = rate_state.bump
+    )]
+    pub rate_state: Option<Account<'info, RateState>>,
+
+    // Program-owned authorities pass their ProgramCreator record instead of a User
+    #[account(
+        mut,
+        seeds = [b"program_creator", authority.key().as_ref()],
+        bump = program_creator.bump
+    )]
+    pub program_creator: Option<Account<'info, ProgramCreator>>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct SendCollabRequest<'info> {
+    #[account(
+        init,
+        payer = sender,
+        space = 8 + CollaborationRequest::INIT_SPACE,
+        seeds = [
+            b"collab_request",
+            sender.key().as_ref(),
+            project.key().as_ref()
+        ],
+        bump
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+    
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    // Require that the sender has a User profile
+    #[account(
+        seeds = [b"user", sender.key().as_ref()],
+        bump = sender_user.bump
+    )]
+    pub sender_user: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [b"rate_state", sender.key().as_ref()],
+        bump = rate_state.bump
+    )]
+    pub rate_state: Account<'info, RateState>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub project: Account<'info, Project>,
+
+    /// CHECK: Block PDA (project owner -> sender); must not exist
+    #[account(
+        seeds = [b"block", project.creator.as_ref(), sender.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ ErrorCode::BlockedByUser
+    )]
+    pub block: UncheckedAccount<'info>,
+
+    /// CHECK: ApplicationBond PDA; only read when the project requires a bond
+    #[account(
+        seeds = [b"application_bond", sender.key().as_ref(), project.key().as_ref()],
+        bump
+    )]
+    pub application_bond: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCollabRequest<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"collab_request",
+            collab_request.from.as_ref(),
+            collab_request.project.as_ref()
+        ],
+        bump = collab_request.bump,
+        has_one = project
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+    
+    // Project owner, or a delegate holding REVIEW_REQUESTS
+    #[account(
+        mut,
+        constraint = project.is_authorized(&reviewer.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::REVIEW_REQUESTS)
+            @ ErrorCode::Unauthorized
+    )]
+    pub reviewer: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), reviewer.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    /// CHECK: Block PDA (reviewer -> sender); must not exist to accept
+    #[account(seeds = [b"block", reviewer.key().as_ref(), collab_request.from.as_ref()], bump)]
+    pub block: UncheckedAccount<'info>,
+
+    /// CHECK: Block PDA (project owner -> sender); must not exist to accept, whoever reviews
+    #[account(seeds = [b"block", project.creator.as_ref(), collab_request.from.as_ref()], bump)]
+    pub owner_block: UncheckedAccount<'info>,
+
+    /// CHECK: Sender's User PDA; its collab count is bumped on accept if it still exists
+    #[account(mut, seeds = [b"user", collab_request.from.as_ref()], bump)]
+    pub sender_user: UncheckedAccount<'info>,
+
+    // Sender's credit on this project; required to accept, paid for by the reviewer on first use
+    #[account(
+        init_if_needed,
+        payer = reviewer,
+        space = 8 + MemberStats::INIT_SPACE,
+        seeds = [b"member_stats", project.key().as_ref(), collab_request.from.as_ref()],
+        bump
+    )]
+    pub member_stats: Option<Account<'info, MemberStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct AssertMembership<'info> {
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Member's request to this project; omitted when checking the owner
+    #[account(
+        seeds = [b"collab_request", member.as_ref(), project.key().as_ref()],
+        bump = collab_request.bump
+    )]
+    pub collab_request: Option<Account<'info, CollaborationRequest>>,
+}
+
+#[derive(Accounts)]
+pub struct ProjectView<'info> {
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+}
+
+#[derive(Accounts)]
+pub struct ProfileView<'info> {
+    #[account(seeds = [b"user", user.wallet.as_ref()], bump = user.bump)]
+    pub user: Account<'info, User>,
+}
+
+#[derive(Accounts)]
+#[instruction(applicant: Pubkey)]
+pub struct CanApply<'info> {
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(seeds = [b"user", applicant.as_ref()], bump = applicant_user.bump)]
+    pub applicant_user: Account<'info, User>,
+
+    #[account(seeds = [b"rate_state", applicant.as_ref()], bump = rate_state.bump)]
+    pub rate_state: Account<'info, RateState>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: CollaborationRequest PDA the application would create; must not exist
+    #[account(seeds = [b"collab_request", applicant.as_ref(), project.key().as_ref()], bump)]
+    pub collab_request: UncheckedAccount<'info>,
+
+    /// CHECK: Block PDA (project owner -> applicant); must not exist
+    #[account(seeds = [b"block", project.creator.as_ref(), applicant.as_ref()], bump)]
+    pub block: UncheckedAccount<'info>,
+
+    /// CHECK: ApplicationBond PDA; only read when the project requires a bond
+    #[account(seeds = [b"application_bond", applicant.as_ref(), project.key().as_ref()], bump)]
+    pub application_bond: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DepositApplicationBond<'info> {
+    #[account(
+        init,
+        payer = applicant,
+        space = 8 + ApplicationBond::INIT_SPACE,
+        seeds = [b"application_bond", applicant.key().as_ref(), project.key().as_ref()],
+        bump
+    )]
+    pub application_bond: Account<'info, ApplicationBond>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimApplicationBond<'info> {
+    #[account(
+        mut,
+        close = applicant,
+        seeds = [b"application_bond", applicant.key().as_ref(), application_bond.project.as_ref()],
+        bump = application_bond.bump,
+        has_one = applicant
+    )]
+    pub application_bond: Account<'info, ApplicationBond>,
+
+    /// CHECK: The applicant's request PDA for this project; may already be closed
+    #[account(
+        seeds = [b"collab_request", applicant.key().as_ref(), application_bond.project.as_ref()],
+        bump
+    )]
+    pub collab_request: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FlagRequestSpam<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"collab_request",
+            collab_request.from.as_ref(),
+            collab_request.project.as_ref()
+        ],
+        bump = collab_request.bump,
+        has_one = project
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), reviewer.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    #[account(
+        mut,
+        close = applicant,
+        seeds = [
+            b"application_bond",
+            collab_request.from.as_ref(),
+            collab_request.project.as_ref()
+        ],
+        bump = application_bond.bump
+    )]
+    pub application_bond: Account<'info, ApplicationBond>,
+
+    // Project owner, or a delegate holding REVIEW_REQUESTS
+    #[account(
+        constraint = project.is_authorized(&reviewer.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::REVIEW_REQUESTS)
+            @ ErrorCode::Unauthorized
+    )]
+    pub reviewer: Signer<'info>,
+
+    /// CHECK: Request sender; receives the bond account's rent back
+    #[account(mut, address = collab_request.from)]
+    pub applicant: AccountInfo<'info>,
+
+    /// CHECK: Project account or the incinerator, validated in the handler
+    #[account(mut)]
+    pub forfeit_destination: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CommitResponseSla<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + SlaBond::INIT_SPACE,
+        seeds = [b"sla_bond", project.key().as_ref()],
+        bump
+    )]
+    pub sla_bond: Account<'info, SlaBond>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageSlaBond<'info> {
+    #[account(
+        mut,
+        seeds = [b"sla_bond", project.key().as_ref()],
+        bump = sla_bond.bump,
+        has_one = project
+    )]
+    pub sla_bond: Account<'info, SlaBond>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSlaBond<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"sla_bond", sla_bond.project.as_ref()],
+        bump = sla_bond.bump,
+        has_one = owner,
+        has_one = project
+    )]
+    pub sla_bond: Account<'info, SlaBond>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSlaBreach<'info> {
+    #[account(
+        mut,
+        seeds = [b"collab_request", applicant.key().as_ref(), project.key().as_ref()],
+        bump = collab_request.bump,
+        has_one = project,
+        constraint = collab_request.from == applicant.key() @ ErrorCode::NotRequestParty
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"sla_bond", project.key().as_ref()],
+        bump = sla_bond.bump
+    )]
+    pub sla_bond: Account<'info, SlaBond>,
+
+    /// CHECK: Applicant's ApplicationBond PDA; refunded if it exists
+    #[account(
+        mut,
+        seeds = [b"application_bond", applicant.key().as_ref(), project.key().as_ref()],
+        bump
+    )]
+    pub application_bond: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PostRequestMessage<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"collab_request",
+            collab_request.from.as_ref(),
+            collab_request.project.as_ref()
+        ],
+        bump = collab_request.bump,
+        constraint = collab_request.from == author.key() || collab_request.to == author.key()
+            @ ErrorCode::NotRequestParty
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+
+    #[account(
+        init,
+        payer = author,
+        space = 8 + RequestMessage::INIT_SPACE,
+        seeds = [
+            b"request_message",
+            collab_request.key().as_ref(),
+            &collab_request.timestamp.to_le_bytes(),
+            &collab_request.message_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub request_message: Account<'info, RequestMessage>,
+
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    /// CHECK: Block PDA (counterpart -> author); must not exist
+    #[account(
+        seeds = [
+            b"block",
+            collab_request.counterpart(&author.key()).as_ref(),
+            author.key().as_ref()
+        ],
+        bump,
+        constraint = block.data_is_empty() @ ErrorCode::BlockedByUser
+    )]
+    pub block: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRequestMessage<'info> {
+    #[account(
+        mut,
+        close = author,
+        seeds = [
+            b"request_message",
+            request_message.request.as_ref(),
+            &request_message.thread.to_le_bytes(),
+            &request_message.seq.to_le_bytes()
+        ],
+        bump = request_message.bump,
+        has_one = author
+    )]
+    pub request_message: Account<'info, RequestMessage>,
+
+    #[account(mut)]
+    pub author: Signer<'info>,
+
+    /// CHECK: Must be the request address this message belongs to; empty or re-sent
+    #[account(address = request_message.request)]
+    pub collab_request: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GrantContactAccess<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ContactEnvelope::INIT_SPACE,
+        seeds = [
+            b"contact_envelope",
+            owner.key().as_ref(),
+            recipient_user.wallet.as_ref()
+        ],
+        bump
+    )]
+    pub contact_envelope: Account<'info, ContactEnvelope>,
+
+    #[account(
+        seeds = [b"user", owner.key().as_ref()],
+        bump = owner_user.bump,
+        constraint = owner_user.encryption_pubkey != [0u8; 32] @ ErrorCode::EncryptionKeyNotRegistered
+    )]
+    pub owner_user: Account<'info, User>,
+
+    #[account(
+        seeds = [b"user", recipient_user.wallet.as_ref()],
+        bump = recipient_user.bump,
+        constraint = recipient_user.encryption_pubkey != [0u8; 32] @ ErrorCode::EncryptionKeyNotRegistered
+    )]
+    pub recipient_user: Account<'info, User>,
+
+    #[account(
+        seeds = [
+            b"collab_request",
+            collab_request.from.as_ref(),
+            collab_request.project.as_ref()
+        ],
+        bump = collab_request.bump,
+        constraint = collab_request.status == RequestStatus::Accepted @ ErrorCode::NotAcceptedCollaborator,
+        constraint = (collab_request.from == owner.key() && collab_request.to == recipient_user.wallet)
+            || (collab_request.to == owner.key() && collab_request.from == recipient_user.wallet)
+            @ ErrorCode::NotAcceptedCollaborator
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+
+    // Owner <-> recipient connection; required when the owner's profile is FriendsOnly
+    #[account(
+        seeds = [b"connection", connection.user_a.as_ref(), connection.user_b.as_ref()],
+        bump = connection.bump
+    )]
+    pub connection: Option<Account<'info, Connection>>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeContactAccess<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            b"contact_envelope",
+            owner.key().as_ref(),
+            contact_envelope.recipient.as_ref()
+        ],
+        bump = contact_envelope.bump,
+        has_one = owner
+    )]
+    pub contact_envelope: Account<'info, ContactEnvelope>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RequestConnection<'info> {
+    #[account(
+        init,
+        payer = requester,
+        space = 8 + Connection::INIT_SPACE,
+        seeds = [
+            b"connection",
+            requester.key().min(addressee_user.wallet).as_ref(),
+            requester.key().max(addressee_user.wallet).as_ref()
+        ],
+        bump
+    )]
+    pub connection: Account<'info, Connection>,
+
+    // Both sides must have a User profile
+    #[account(
+        seeds = [b"user", requester.key().as_ref()],
+        bump = requester_user.bump
+    )]
+    pub requester_user: Account<'info, User>,
+
+    #[account(
+        seeds = [b"user", addressee_user.wallet.as_ref()],
+        bump = addressee_user.bump
+    )]
+    pub addressee_user: Account<'info, User>,
+
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    /// CHECK: Block PDA (addressee -> requester); must not exist
+    #[account(
+        seeds = [b"block", addressee_user.wallet.as_ref(), requester.key().as_ref()],
+        bump,
+        constraint = block.data_is_empty() @ ErrorCode::BlockedByUser
+    )]
+    pub block: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptConnection<'info> {
+    #[account(
+        mut,
+        seeds = [b"connection", connection.user_a.as_ref(), connection.user_b.as_ref()],
+        bump = connection.bump,
+        constraint = connection.involves(&addressee.key())
+            && connection.requester != addressee.key() @ ErrorCode::NotConnectionParty
+    )]
+    pub connection: Account<'info, Connection>,
+
+    pub addressee: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConnectViaCollaboration<'info> {
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + Connection::INIT_SPACE,
+        seeds = [
+            b"connection",
+            collab_request.from.min(collab_request.to).as_ref(),
+            collab_request.from.max(collab_request.to).as_ref()
+        ],
+        bump
+    )]
+    pub connection: Account<'info, Connection>,
+
+    #[account(
+        seeds = [
+            b"collab_request",
+            collab_request.from.as_ref(),
+            collab_request.project.as_ref()
+        ],
+        bump = collab_request.bump,
+        constraint = collab_request.status == RequestStatus::Accepted @ ErrorCode::InvalidRequestStatus,
+        constraint = collab_request.from == signer.key() || collab_request.to == signer.key()
+            @ ErrorCode::NotRequestParty
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveConnection<'info> {
+    #[account(
+        mut,
+        close = requester,
+        seeds = [b"connection", connection.user_a.as_ref(), connection.user_b.as_ref()],
+        bump = connection.bump,
+        has_one = requester,
+        constraint = connection.involves(&signer.key()) @ ErrorCode::NotConnectionParty
+    )]
+    pub connection: Account<'info, Connection>,
+
+    pub signer: Signer<'info>,
+
+    /// CHECK: Must match the connection's requester (receives the rent refund)
+    #[account(mut)]
+    pub requester: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FollowUser<'info> {
+    #[account(
+        init,
+        payer = follower,
+        space = 8 + Follow::INIT_SPACE,
+        seeds = [
+            b"follow",
+            follower.key().as_ref(),
+            followed_user.wallet.as_ref(),
+            &followed_user.member_since.to_le_bytes()
+        ],
+        bump
+    )]
+    pub follow: Account<'info, Follow>,
+
+    #[account(
+        mut,
+        seeds = [b"user", follower.key().as_ref()],
+        bump = follower_user.bump
+    )]
+    pub follower_user: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [b"user", followed_user.wallet.as_ref()],
+        bump = followed_user.bump
+    )]
+    pub followed_user: Account<'info, User>,
+
+    #[account(mut)]
+    pub follower: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnfollowUser<'info> {
+    #[account(
+        mut,
+        close = follower,
+        seeds = [
+            b"follow",
+            follower.key().as_ref(),
+            follow.followed.as_ref(),
+            &follow.followed_since.to_le_bytes()
+        ],
+        bump = follow.bump,
+        has_one = follower
+    )]
+    pub follow: Account<'info, Follow>,
+
+    /// CHECK: Follower's User PDA; may already be closed
+    #[account(mut, seeds = [b"user", follower.key().as_ref()], bump)]
+    pub follower_user: UncheckedAccount<'info>,
+
+    /// CHECK: Followed user's User PDA; may already be closed
+    #[account(mut, seeds = [b"user", follow.followed.as_ref()], bump)]
+    pub followed_user: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub follower: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct StarProject<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = 8 + Star::INIT_SPACE,
+        seeds = [b"star", user.key().as_ref(), project.key().as_ref(), &project.timestamp.to_le_bytes()],
+        bump
+    )]
+    pub star: Account<'info, Star>,
+
+    // Require that the user has a User profile
+    #[account(
+        seeds = [b"user", user.key().as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnstarProject<'info> {
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"star", user.key().as_ref(), star.project.as_ref(), &star.project_since.to_le_bytes()],
+        bump = star.bump,
+        has_one = user
+    )]
+    pub star: Account<'info, Star>,
+
+    /// CHECK: Starred project; may already be closed
+    #[account(mut, address = star.project)]
+    pub project: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(blocked: Pubkey)]
+pub struct BlockUser<'info> {
+    #[account(
+        init,
+        payer = blocker,
+        space = 8 + Block::INIT_SPACE,
+        seeds = [b"block", blocker.key().as_ref(), blocked.as_ref()],
+        bump
+    )]
+    pub block: Account<'info, Block>,
+
+    #[account(mut)]
+    pub blocker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnblockUser<'info> {
+    #[account(
+        mut,
+        close = blocker,
+        seeds = [b"block", blocker.key().as_ref(), block.blocked.as_ref()],
+        bump = block.bump,
+        has_one = blocker
+    )]
+    pub block: Account<'info, Block>,
+
+    #[account(mut)]
+    pub blocker: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateOrganization<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Organization::INIT_SPACE,
+        seeds = [b"organization", name_seed(&name)?],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    // Require that the creator already has a User profile
+    #[account(
+        seeds = [b"user", creator.key().as_ref()],
+        bump = user.bump
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageOrganization<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump,
+        constraint = organization.is_admin(&admin.key()) @ ErrorCode::NotAnOrgAdmin
+    )]
+    pub organization: Account<'info, Organization>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RemoveOrgMember<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump,
+        constraint = authority.key() == wallet || organization.is_admin(&authority.key())
+            @ ErrorCode::NotAnOrgAdmin
+    )]
+    pub organization: Account<'info, Organization>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetOrgVerified<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateProjectArgs)]
+pub struct CreateOrganizationProject<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Project::INIT_SPACE,
+        seeds = [b"project", creator.key().as_ref(), args.name.as_bytes()],
+        bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + OrgProject::INIT_SPACE,
+        seeds = [
+            b"org_project",
+            organization.key().as_ref(),
+            &organization.project_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub org_project: Account<'info, OrgProject>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump,
+        constraint = organization.is_admin(&creator.key()) @ ErrorCode::NotAnOrgAdmin
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [b"user", creator.key().as_ref()],
+        bump = user.bump
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        mut,
+        seeds = [b"rate_state", creator.key().as_ref()],
+        bump = rate_state.bump
+    )]
+    pub rate_state: Account<'info, RateState>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddProjectToOrganization<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + OrgProject::INIT_SPACE,
+        seeds = [
+            b"org_project",
+            organization.key().as_ref(),
+            &organization.project_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub org_project: Account<'info, OrgProject>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump,
+        constraint = organization.is_admin(&admin.key()) @ ErrorCode::NotAnOrgAdmin
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        constraint = project.creator == admin.key() @ ErrorCode::Unauthorized
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveProjectFromOrganization<'info> {
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            b"org_project",
+            organization.key().as_ref(),
+            &org_project.index.to_le_bytes()
+        ],
+        bump = org_project.bump,
+        has_one = organization,
+        has_one = project
+    )]
+    pub org_project: Account<'info, OrgProject>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump,
+        constraint = organization.is_admin(&admin.key()) @ ErrorCode::NotAnOrgAdmin
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        constraint = project.organization == organization.key() @ ErrorCode::OrganizationMismatch
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct GrantProjectRole<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ProjectRole::INIT_SPACE,
+        seeds = [b"project_role", project.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub project_role: Account<'info, ProjectRole>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Creator, or a delegate holding MANAGE_ROLES
+    #[account(
+        mut,
+        constraint = project.is_authorized(&authority.key(), authority_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_ROLES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), authority.key().as_ref()],
+        bump = authority_role.bump
+    )]
+    pub authority_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateProjectRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"project_role", project.key().as_ref(), project_role.delegate.as_ref()],
+        bump = project_role.bump,
+        has_one = project
+    )]
+    pub project_role: Account<'info, ProjectRole>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Creator, or a delegate holding MANAGE_ROLES
+    #[account(
+        constraint = project.is_authorized(&authority.key(), authority_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_ROLES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), authority.key().as_ref()],
+        bump = authority_role.bump
+    )]
+    pub authority_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeProjectRole<'info> {
+    #[account(
+        mut,
+        close = granted_by,
+        seeds = [b"project_role", project.key().as_ref(), project_role.delegate.as_ref()],
+        bump = project_role.bump,
+        has_one = project,
+        has_one = granted_by
+    )]
+    pub project_role: Account<'info, ProjectRole>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Creator, a MANAGE_ROLES delegate, or the delegate themselves
+    #[account(
+        constraint = authority.key() == project_role.delegate
+            || project.is_authorized(&authority.key(), authority_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_ROLES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), authority.key().as_ref()],
+        bump = authority_role.bump
+    )]
+    pub authority_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    /// CHECK: Wallet that paid for the role account; receives the rent refund
+    #[account(mut)]
+    pub granted_by: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureApprovers<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + ApproverSet::INIT_SPACE,
+        seeds = [b"approver_set", project.key().as_ref()],
+        bump
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [
+            b"proposal",
+            project.key().as_ref(),
+            &project.proposal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"approver_set", project.key().as_ref()],
+        bump = approver_set.bump,
+        constraint = approver_set.is_approver(&proposer.key()) @ ErrorCode::NotAnApprover
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.project.as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"approver_set", proposal.project.as_ref()],
+        bump = approver_set.bump,
+        constraint = approver_set.is_approver(&approver.key()) @ ErrorCode::NotAnApprover
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    pub approver: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", proposal.project.as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = project,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Proposal creator; receives the rent refund
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProjectProposal<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", project.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = project,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"approver_set", project.key().as_ref()],
+        bump = approver_set.bump
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    /// CHECK: Proposal creator; receives the rent refund
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    // Anyone can crank an approved proposal
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAcceptRequest<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", project.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = project,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"approver_set", project.key().as_ref()],
+        bump = approver_set.bump
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"collab_request",
+            collab_request.from.as_ref(),
+            collab_request.project.as_ref()
+        ],
+        bump = collab_request.bump,
+        has_one = project
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+
+    /// CHECK: Sender's User PDA; its collab count is bumped if it still exists
+    #[account(mut, seeds = [b"user", collab_request.from.as_ref()], bump)]
+    pub sender_user: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + MemberStats::INIT_SPACE,
+        seeds = [b"member_stats", project.key().as_ref(), collab_request.from.as_ref()],
+        bump
+    )]
+    pub member_stats: Account<'info, MemberStats>,
+
+    /// CHECK: Proposal creator; receives the rent refund
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    // Anyone can crank an approved proposal; pays for the member's stats on first credit
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteTransfer<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", project.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = project,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"approver_set", project.key().as_ref()],
+        bump = approver_set.bump
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    // The new owner must already have a User profile
+    #[account(
+        seeds = [b"user", new_owner_user.wallet.as_ref()],
+        bump = new_owner_user.bump
+    )]
+    pub new_owner_user: Account<'info, User>,
+
+    /// CHECK: Proposal creator; receives the rent refund
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    // Anyone can crank an approved proposal
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteUpdateApprovers<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", project.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = project,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        seeds = [b"approver_set", project.key().as_ref()],
+        bump = approver_set.bump
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    /// CHECK: Project creator; receives the approver set's rent if it is removed
+    #[account(mut)]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Proposal creator; receives the rent refund
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    // Anyone can crank an approved proposal
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteDeleteProject<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", project.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = project,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"approver_set", project.key().as_ref()],
+        bump = approver_set.bump
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    /// CHECK: Project creator; receives the project's and approver set's rent
+    #[account(mut)]
+    pub creator: AccountInfo<'info>,
+
+    /// CHECK: Creator's User PDA; may already be closed
+    #[account(mut, seeds = [b"user", creator.key().as_ref()], bump)]
+    pub creator_user: UncheckedAccount<'info>,
+
+    /// CHECK: Creator's ProgramCreator PDA; empty unless the creator is program-owned
+    #[account(mut, seeds = [b"program_creator", creator.key().as_ref()], bump)]
+    pub program_creator: UncheckedAccount<'info>,
+
+    /// CHECK: Proposal creator; receives the rent refund
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    // Anyone can crank an approved proposal
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    pub creator: Signer<'info>,
+
+    // The new owner must already have a User profile
+    #[account(
+        seeds = [b"user", new_owner_user.wallet.as_ref()],
+        bump = new_owner_user.bump
+    )]
+    pub new_owner_user: Account<'info, User>,
+}
+
+#[derive(Accounts)]
+pub struct CancelTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        constraint = project.pending_owner == new_owner.key() @ ErrorCode::NoPendingTransfer
+    )]
+    pub project: Account<'info, Project>,
+
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"user", new_owner.key().as_ref()],
+        bump = new_owner_user.bump
+    )]
+    pub new_owner_user: Account<'info, User>,
+
+    /// CHECK: Previous owner's User PDA; may already be closed
+    #[account(mut, seeds = [b"user", project.creator.as_ref()], bump)]
+    pub previous_owner_user: UncheckedAccount<'info>,
+
+    /// CHECK: Previous owner's ProgramCreator PDA; empty unless the owner is program-owned
+    #[account(mut, seeds = [b"program_creator", project.creator.as_ref()], bump)]
+    pub previous_program_creator: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SyncRequestOwner<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"collab_request",
+            collab_request.from.as_ref(),
+            collab_request.project.as_ref()
+        ],
+        bump = collab_request.bump,
+        has_one = project
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+}
+
+#[derive(Accounts)]
+pub struct DeleteUser<'info> {
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"user", signer.key().as_ref()],
+        bump = user.bump,
+        has_one = wallet
+    )]
+    pub user: Account<'info, User>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// CHECK: This is the wallet that must match the user's wallet
+    pub wallet: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMilestone<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Milestone::INIT_SPACE,
+        seeds = [b"milestone", project.key().as_ref(), &project.milestone_count.to_le_bytes()],
+        bump
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Creator, or a delegate holding EDIT_PROJECT
+    #[account(
+        mut,
+        constraint = project.is_authorized(&authority.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::EDIT_PROJECT)
+            @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), authority.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"milestone", project.key().as_ref(), &milestone.index.to_le_bytes()],
+        bump = milestone.bump,
+        has_one = project
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Creator, or a delegate holding EDIT_PROJECT
+    #[account(
+        constraint = project.is_authorized(&authority.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::EDIT_PROJECT)
+            @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), authority.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct CreateTask<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Task::INIT_SPACE,
+        seeds = [b"task", project.key().as_ref(), &project.task_count.to_le_bytes()],
+        bump
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Creator, or a delegate holding EDIT_PROJECT
+    #[account(
+        mut,
+        constraint = project.is_authorized(&authority.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::EDIT_PROJECT)
+            @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), authority.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimTask<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", project.key().as_ref(), &task.index.to_le_bytes()],
+        bump = task.bump,
+        has_one = project
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // The member's accepted request to this project
+    #[account(
+        seeds = [b"collab_request", member.key().as_ref(), project.key().as_ref()],
+        bump = member_request.bump
+    )]
+    pub member_request: Account<'info, CollaborationRequest>,
+
+    pub member: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WorkOnTask<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task.project.as_ref(), &task.index.to_le_bytes()],
+        bump = task.bump,
+        has_one = assignee
+    )]
+    pub task: Account<'info, Task>,
+
+    pub assignee: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveTask<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", project.key().as_ref(), &task.index.to_le_bytes()],
+        bump = task.bump,
+        has_one = project
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MemberStats::INIT_SPACE,
+        seeds = [b"member_stats", project.key().as_ref(), task.assignee.as_ref()],
+        bump
+    )]
+    pub member_stats: Account<'info, MemberStats>,
+
+    /// CHECK: Assignee's User PDA; earns reputation if it still exists
+    #[account(mut, seeds = [b"user", task.assignee.as_ref()], bump)]
+    pub assignee_user: UncheckedAccount<'info>,
+
+    // Creator, or a delegate holding EDIT_PROJECT; pays for the member's stats on first credit
+    #[account(
+        mut,
+        constraint = project.is_authorized(&authority.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::EDIT_PROJECT)
+            @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), authority.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreateBounty<'info> {
+    #[account(
+        init,
+        payer = manager,
+        space = 8 + Bounty::INIT_SPACE,
+        seeds = [b"bounty", project.key().as_ref(), &project.bounty_count.to_le_bytes()],
+        bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Creator, or a delegate holding MANAGE_BOUNTIES; funds the escrow
+    #[account(
+        mut,
+        constraint = project.is_authorized(&manager.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_BOUNTIES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), manager.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(assignee: Pubkey)]
+pub struct AssignBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty", project.key().as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump,
+        has_one = project
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // The assignee's accepted request to this project
+    #[account(
+        seeds = [b"collab_request", assignee.as_ref(), project.key().as_ref()],
+        bump = member_request.bump
+    )]
+    pub member_request: Account<'info, CollaborationRequest>,
+
+    #[account(
+        constraint = project.is_authorized(&manager.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_BOUNTIES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), manager.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.project.as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump,
+        has_one = assignee
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    pub assignee: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty", project.key().as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump,
+        has_one = project,
+        has_one = assignee,
+        constraint = bounty.is_sol() @ ErrorCode::BountyCurrencyMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub assignee: SystemAccount<'info>,
+
+    #[account(
+        constraint = project.is_authorized(&manager.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_BOUNTIES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), manager.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty", project.key().as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump,
+        has_one = project,
+        has_one = funder,
+        constraint = bounty.is_sol() @ ErrorCode::BountyCurrencyMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub funder: SystemAccount<'info>,
+
+    #[account(
+        constraint = project.is_authorized(&manager.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_BOUNTIES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), manager.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct CreateTokenBounty<'info> {
+    #[account(
+        init,
+        payer = manager,
+        space = 8 + Bounty::INIT_SPACE,
+        seeds = [b"bounty", project.key().as_ref(), &project.bounty_count.to_le_bytes()],
+        bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Creator, or a delegate holding MANAGE_BOUNTIES; funds the escrow
+    #[account(
+        mut,
+        constraint = project.is_authorized(&manager.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_BOUNTIES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), manager.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.allowed_mints.contains(&mint.key()) @ ErrorCode::MintNotAllowed
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = manager,
+        token::token_program = token_program
+    )]
+    pub funder_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Data-less PDA owning the project's token vaults
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = manager,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveTokenBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty", project.key().as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump,
+        has_one = project,
+        has_one = mint @ ErrorCode::BountyCurrencyMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = project.is_authorized(&manager.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_BOUNTIES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), manager.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Any token account of the assignee for this mint
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = assignee_token.owner == bounty.assignee @ ErrorCode::TokenAccountOwnerMismatch,
+        token::token_program = token_program
+    )]
+    pub assignee_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Data-less PDA owning the project's token vaults
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct CancelTokenBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty", project.key().as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump,
+        has_one = project,
+        has_one = mint @ ErrorCode::BountyCurrencyMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        constraint = project.is_authorized(&manager.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_BOUNTIES)
+            @ ErrorCode::Unauthorized
+    )]
+    pub manager: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), manager.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Any token account of the funder for this mint
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = funder_token.owner == bounty.funder @ ErrorCode::TokenAccountOwnerMismatch,
+        token::token_program = token_program
+    )]
+    pub funder_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Data-less PDA owning the project's token vaults
+    #[account(seeds = [b"vault_authority", project.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimBounty<'info> {
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.project.as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump,
+        has_one = funder,
+        close = funder
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct LeaveProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"collab_request", member.key().as_ref(), project.key().as_ref()],
+        bump = collab_request.bump,
+        has_one = project
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    pub member: Signer<'info>,
+
+    /// CHECK: Stream PDA for this membership; paused as of now if one exists
+    #[account(
+        mut,
+        seeds = [b"stream", collab_request.key().as_ref()],
+        bump
+    )]
+    pub stream: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateStream<'info> {
+    #[account(
+        init,
+        payer = funder,
+        space = 8 + Stream::INIT_SPACE,
+        seeds = [b"stream", collab_request.key().as_ref()],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(
+        seeds = [b"collab_request", collab_request.from.as_ref(), project.key().as_ref()],
+        bump = collab_request.bump,
+        has_one = project
+    )]
+    pub collab_request: Account<'info, CollaborationRequest>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    // Creator, or a delegate holding MANAGE_MEMBERS; funds the stream
+    #[account(
+        mut,
+        constraint = project.is_authorized(&funder.key(), project_role.as_deref(), organization.as_deref(), ProjectRole::MANAGE_MEMBERS)
+            @ ErrorCode::Unauthorized
+    )]
+    pub funder: Signer<'info>,
+
+    #[account(
+        seeds = [b"project_role", project.key().as_ref(), funder.key().as_ref()],
+        bump = project_role.bump
+    )]
+    pub project_role: Option<Account<'info, ProjectRole>>,
+
+    #[account(address = project.organization @ ErrorCode::OrganizationMismatch)]
+    pub organization: Option<Account<'info, Organization>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawStream<'info> {
+    #[account(
+        mut,
+        seeds = [b"stream", collab_request.key().as_ref()],
+        bump = stream.bump,
+        has_one = recipient
+    )]
+    pub stream: Account<'info, Stream>,
+
+    /// CHECK: The stream's CollaborationRequest PDA; may have been closed
+    #[account(address = stream.collab_request)]
+    pub collab_request: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    #[account(
+        mut,
+        close = funder,
+        seeds = [b"stream", collab_request.key().as_ref()],
+        bump = stream.bump,
+        has_one = funder,
+        has_one = recipient
+    )]
+    pub stream: Account<'info, Stream>,
+
+    /// CHECK: The stream's CollaborationRequest PDA; may have been closed
+    #[account(address = stream.collab_request)]
+    pub collab_request: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenBountyDispute<'info> {
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [b"dispute", bounty.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.project.as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    // Funder or assignee; pays the dispute's rent, refunded once it is ruled or expires
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenStreamDispute<'info> {
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [b"dispute", stream.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.collab_request.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    // Funder or recipient; pays the dispute's rent, refunded once it is ruled or expires
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RespondToDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.escrow.as_ref()],
+        bump = dispute.bump,
+        has_one = respondent @ ErrorCode::NotADisputeParty
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    pub respondent: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveBountyDispute<'info> {
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [b"dispute", bounty.key().as_ref()],
+        bump = dispute.bump,
+        has_one = arbiter @ ErrorCode::Unauthorized,
+        has_one = claimant
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.project.as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump,
+        has_one = funder,
+        has_one = assignee,
+        has_one = project,
+        constraint = bounty.is_sol() @ ErrorCode::BountyCurrencyMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub funder: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub assignee: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    /// CHECK: Dispute opener; receives the rent refund
+    #[account(mut)]
+    pub claimant: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveTokenBountyDispute<'info> {
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [b"dispute", bounty.key().as_ref()],
+        bump = dispute.bump,
+        has_one = arbiter @ ErrorCode::Unauthorized,
+        has_one = claimant
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.project.as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump,
+        has_one = project,
+        has_one = mint @ ErrorCode::BountyCurrencyMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = funder_token.owner == bounty.funder @ ErrorCode::TokenAccountOwnerMismatch,
+        token::token_program = token_program
+    )]
+    pub funder_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = assignee_token.owner == bounty.assignee @ ErrorCode::TokenAccountOwnerMismatch,
+        token::token_program = token_program
+    )]
+    pub assignee_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = arbiter_token.owner == arbiter.key() @ ErrorCode::TokenAccountOwnerMismatch,
+        token::token_program = token_program
+    )]
+    pub arbiter_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Data-less PDA owning the project's token vaults
+    #[account(seeds = [b"vault_authority", bounty.project.as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub arbiter: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: Dispute opener; receives the rent refund
+    #[account(mut)]
+    pub claimant: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveStreamDispute<'info> {
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [b"dispute", stream.key().as_ref()],
+        bump = dispute.bump,
+        has_one = arbiter @ ErrorCode::Unauthorized,
+        has_one = claimant
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        close = funder,
+        seeds = [b"stream", stream.collab_request.as_ref()],
+        bump = stream.bump,
+        has_one = funder,
+        has_one = recipient
+    )]
+    pub stream: Account<'info, Stream>,
+
+    #[account(mut)]
+    pub funder: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    /// CHECK: Dispute opener; receives the rent refund
+    #[account(mut)]
+    pub claimant: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireBountyDispute<'info> {
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [b"dispute", bounty.key().as_ref()],
+        bump = dispute.bump,
+        has_one = claimant
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.project.as_ref(), &bounty.index.to_le_bytes()],
+        bump = bounty.bump
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Dispute opener; receives the rent refund
+    #[account(mut)]
+    pub claimant: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireStreamDispute<'info> {
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [b"dispute", stream.key().as_ref()],
+        bump = dispute.bump,
+        has_one = claimant
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [b"stream", stream.collab_request.as_ref()],
+        bump = stream.bump
+    )]
+    pub stream: Account<'info, Stream>,
+
+    /// CHECK: Dispute opener; receives the rent refund
+    #[account(mut)]
+    pub claimant: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenDonationVault<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + DonationVault::INIT_SPACE,
+        seeds = [b"donation_vault", project.key().as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, DonationVault>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Donate<'info> {
+    #[account(
+        mut,
+        seeds = [b"donation_vault", project.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, DonationVault>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = 8 + DonorRecord::INIT_SPACE,
+        seeds = [b"donor", project.key().as_ref(), donor.key().as_ref()],
+        bump
+    )]
+    pub donor_record: Account<'info, DonorRecord>,
+
+    // Optional receipt, indexed by the donor's donation count on this project
+    #[account(
+        init,
+        payer = donor,
+        space = 8 + Donation::INIT_SPACE,
+        seeds = [b"donation", donor_record.key().as_ref(), &donor_record.donation_count.to_le_bytes()],
+        bump
+    )]
+    pub receipt: Option<Account<'info, Donation>>,
+
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DonateToken<'info> {
+    #[account(
+        mut,
+        seeds = [b"donation_vault", project.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, DonationVault>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = 8 + DonorRecord::INIT_SPACE,
+        seeds = [b"donor", project.key().as_ref(), donor.key().as_ref()],
+        bump
+    )]
+    pub donor_record: Account<'info, DonorRecord>,
+
+    // Optional receipt, indexed by the donor's donation count on this project
+    #[account(
+        init,
+        payer = donor,
+        space = 8 + Donation::INIT_SPACE,
+        seeds = [b"donation", donor_record.key().as_ref(), &donor_record.donation_count.to_le_bytes()],
+        bump
+    )]
+    pub receipt: Option<Account<'info, Donation>>,
+
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.allowed_mints.contains(&mint.key()) @ ErrorCode::MintNotAllowed
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = donor,
+        token::token_program = token_program
+    )]
+    pub donor_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawDonations<'info> {
+    #[account(
+        mut,
+        seeds = [b"donation_vault", project.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, DonationVault>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseDonationVault<'info> {
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"donation_vault", project.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, DonationVault>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTokenDonations<'info> {
+    #[account(
+        seeds = [b"donation_vault", project.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, DonationVault>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    pub creator: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub recipient_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawDonations<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", project.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = project,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"approver_set", project.key().as_ref()],
+        bump = approver_set.bump
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    /// CHECK: Proposal creator; receives the rent refund
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    // Anyone can crank an approved proposal
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"donation_vault", project.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, DonationVault>,
+
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawTokenDonations<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", project.key().as_ref(), &proposal.index.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = project,
+        has_one = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        seeds = [b"approver_set", project.key().as_ref()],
+        bump = approver_set.bump
+    )]
+    pub approver_set: Account<'info, ApproverSet>,
+
+    /// CHECK: Proposal creator; receives the rent refund
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    // Anyone can crank an approved proposal
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [b"donation_vault", project.key().as_ref()],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, DonationVault>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub recipient_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateGrantRound<'info> {
+    #[account(
+        init,
+        payer = operator,
+        space = 8 + GrantRound::INIT_SPACE,
+        seeds = [b"grant_round", operator.key().as_ref(), name_seed(&name)?],
+        bump
+    )]
+    pub round: Account<'info, GrantRound>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundGrantRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"grant_round", round.operator.as_ref(), round.name.as_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, GrantRound>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterGrantProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"grant_round", round.operator.as_ref(), round.name.as_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, GrantRound>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetGrantEligibility<'info> {
+    #[account(
+        mut,
+        seeds = [b"grant_round", round.operator.as_ref(), round.name.as_bytes()],
+        bump = round.bump,
+        has_one = operator @ ErrorCode::Unauthorized
+    )]
+    pub round: Account<'info, GrantRound>,
+
+    pub operator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ContributeToGrant<'info> {
+    #[account(
+        mut,
+        seeds = [b"grant_round", round.operator.as_ref(), round.name.as_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, GrantRound>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = 8 + GrantContribution::INIT_SPACE,
+        seeds = [
+            b"grant_contribution",
+            round.key().as_ref(),
+            project.key().as_ref(),
+            contributor.key().as_ref()
+        ],
+        bump
+    )]
+    pub contribution: Account<'info, GrantContribution>,
+
+    // Contributor's profile; its verification and reputation set the sybil weight
+    #[account(
+        seeds = [b"user", contributor.key().as_ref()],
+        bump = contributor_user.bump
+    )]
+    pub contributor_user: Account<'info, User>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeGrantRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"grant_round", round.operator.as_ref(), round.name.as_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, GrantRound>,
+}
+
+#[derive(Accounts)]
+pub struct DistributeGrantRound<'info> {
+    #[account(
+        mut,
+        seeds = [b"grant_round", round.operator.as_ref(), round.name.as_bytes()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, GrantRound>,
+}
+
+#[derive(Accounts)]
+pub struct CloseGrantRound<'info> {
+    #[account(
+        mut,
+        close = operator,
+        seeds = [b"grant_round", round.operator.as_ref(), round.name.as_bytes()],
+        bump = round.bump,
+        has_one = operator @ ErrorCode::Unauthorized
+    )]
+    pub round: Account<'info, GrantRound>,
+
+    #[account(mut)]
+    pub operator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateEvent<'info> {
+    #[account(
+        init,
+        payer = organizer,
+        space = 8 + Event::INIT_SPACE,
+        seeds = [b"event", organizer.key().as_ref(), name_seed(&name)?],
+        bump
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
 
 #[derive(Accounts)]
-pub struct UpdateUser<'info> {
+pub struct ManageEvent<'info> {
     #[account(
         mut,
-        seeds = [b"user", signer.key().as_ref()],
-        bump = user.bump,
-        has_one = wallet
+        seeds = [b"event", event.organizer.as_ref(), event.name.as_bytes()],
+        bump = event.bump,
+        has_one = organizer @ ErrorCode::Unauthorized
     )]
-    pub user: Account<'info, User>,
-    
+    pub event: Account<'info, Event>,
+
+    pub organizer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterEventProject<'info> {
+    #[account(
+        mut,
+        seeds = [b"event", event.organizer.as_ref(), event.name.as_bytes()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + EventEntry::INIT_SPACE,
+        seeds = [b"event_entry", event.key().as_ref(), project.key().as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, EventEntry>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
     #[account(mut)]
-    pub signer: Signer<'info>,
-    
-    /// CHECK: This is the wallet that must match the user's wallet
-    pub wallet: AccountInfo<'info>,
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ScoreEventProject<'info> {
+    #[account(
+        seeds = [b"event", event.organizer.as_ref(), event.name.as_bytes()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"event_entry", event.key().as_ref(), project.key().as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, EventEntry>,
+
+    #[account(
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump
+    )]
+    pub project: Account<'info, Project>,
+
+    pub judge: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimEventPrize<'info> {
+    #[account(
+        mut,
+        seeds = [b"event", event.organizer.as_ref(), event.name.as_bytes()],
+        bump = event.bump
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(
+        mut,
+        seeds = [b"event_entry", event.key().as_ref(), project.key().as_ref()],
+        bump = entry.bump
+    )]
+    pub entry: Account<'info, EventEntry>,
+
+    #[account(
+        mut,
+        seeds = [b"project", project.seed_creator.as_ref(), project.name.as_bytes()],
+        bump = project.bump,
+        has_one = creator
+    )]
+    pub project: Account<'info, Project>,
+
+    /// CHECK: Receives the prize; must be the project's creator
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseEvent<'info> {
+    #[account(
+        mut,
+        close = organizer,
+        seeds = [b"event", event.organizer.as_ref(), event.name.as_bytes()],
+        bump = event.bump,
+        has_one = organizer @ ErrorCode::Unauthorized
+    )]
+    pub event: Account<'info, Event>,
+
+    #[account(mut)]
+    pub organizer: Signer<'info>,
+}
+
+// ==================== ACCOUNT STRUCTURES ====================
+
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub admin: Pubkey,                       // 32 bytes
+    pub limits: RateLimits,                  // 6 bytes
+    pub verified_limits: RateLimits,         // 6 bytes (applied to is_verified users)
+    pub bump: u8,                            // 1 byte
+    #[max_len(16)]
+    pub allowed_mints: Vec<Pubkey>,          // 4 + 32 * 16 = 516 bytes (SPL mints accepted for payments)
+    #[max_len(8)]
+    pub arbiters: Vec<Pubkey>,               // 4 + 32 * 8 = 260 bytes (disputes are assigned from this set)
+    pub arbiter_fee_bps: u16,                // 2 bytes (cut of a disputed escrow paid to the arbiter)
+    pub dispute_timeout: i64,                // 8 bytes (time an arbiter has to rule)
+    pub disputes_opened: u64,                // 8 bytes (rotates arbiter assignment)
+}
+// Total: ~839 bytes
+
+impl Config {
+    pub fn limits_for(&self, user: &User) -> RateLimits {
+        if user.is_verified { self.verified_limits } else { self.limits }
+    }
+
+    /// Arbiter for a new dispute: rotates through the set by dispute count, skipping the parties
+    ///
+    /// The count moves with every dispute program-wide, so a claimant can't time their
+    /// submission to land on an arbiter the way a slot- or clock-based draw would allow.
+    pub fn draw_arbiter(&mut self, parties: &[Pubkey]) -> Result<Pubkey> {
+        let n = self.arbiters.len();
+        let seed = self.disputes_opened;
+        self.disputes_opened += 1;
+        (0..n)
+            .map(|i| self.arbiters[(seed as usize + i) % n])
+            .find(|a| !parties.contains(a))
+            .ok_or(error!(ErrorCode::NoArbiterAvailable))
+    }
+}
+
+/// Project creator record for program-owned authorities that cannot hold a `User` profile
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramCreator {
+    pub authority: Pubkey,                   // 32 bytes
+    #[max_len(64)]
+    pub display_name: String,                // 4 + 64 = 68 bytes
+    pub projects_count: u32,                 // 4 bytes
+    pub project_window: RateWindow,          // 12 bytes
+    pub update_window: RateWindow,           // 12 bytes
+    pub created_at: i64,                     // 8 bytes
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~133 bytes
+
+#[account]
+#[derive(InitSpace)]
+pub struct User {
+    pub wallet: Pubkey,                      // 32 bytes
+    #[max_len(32)]
+    pub username: String,                    // 4 + 32 = 36 bytes
+    #[max_len(64)]
+    pub display_name: String,                // 4 + 64 = 68 bytes
+    #[max_len(50)]
+    pub role: String,                        // 4 + 50 = 54 bytes
+    #[max_len(50)]
+    pub location: String,                    // 4 + 50 = 54 bytes
+    #[max_len(200)]
+    pub bio: String,                         // 4 + 200 = 204 bytes
+    #[max_len(100)]
+    pub github_link: String,                 // 4 + 100 = 104 bytes
+    #[max_len(64)]
+    pub ipfs_metadata_hash: String,          // 4 + 64 = 68 bytes (link to IPFS for extended data)
+    #[max_len(200)]
+    pub contact_info: String,                // 4 + 200 = 204 bytes (legacy plaintext; empty once sealed)
+    pub reputation: u32,                     // 4 bytes (points from approved tasks)
+    pub projects_count: u32,                 // 4 bytes
+    pub collabs_count: u32,                  // 4 bytes (accepted collaboration requests)
+    pub member_since: i64,                   // 8 bytes
+    pub last_active: i64,                    // 8 bytes
+    pub is_verified: bool,                   // 1 byte
+    pub open_to_collab: bool,                // 1 byte
+    pub profile_visibility: ProfileVisibility, // 1 byte
+    pub bump: u8,                            // 1 byte
+    pub encryption_pubkey: [u8; 32],         // 32 bytes (x25519; all zeros = not registered)
+    #[max_len(240)]
+    pub sealed_contact_info: Vec<u8>,        // 4 + 240 = 244 bytes (nonce || XChaCha20-Poly1305 ciphertext)
+    #[max_len(80)]
+    pub contact_key_envelope: Vec<u8>,       // 4 + 80 = 84 bytes (contact key sealed to encryption_pubkey)
+    pub followers_count: u32,                // 4 bytes
+    pub following_count: u32,                // 4 bytes
+}
+// Total: ~1228 bytes (well under 4KB!)
+
+/// Per-wallet rate limit counters, kept apart from `User` so deleting a profile doesn't reset them
+#[account]
+#[derive(InitSpace)]
+pub struct RateState {
+    pub wallet: Pubkey,                      // 32 bytes
+    pub request_window: RateWindow,          // 12 bytes (collab requests sent, per day)
+    pub project_window: RateWindow,          // 12 bytes (projects created, per week)
+    pub update_window: RateWindow,           // 12 bytes (profile/project/request edits, per hour)
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~77 bytes
+
+/// Per-wallet action limits; 0 disables a limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct RateLimits {
+    pub requests_per_day: u16,
+    pub projects_per_week: u16,
+    pub updates_per_hour: u16,
+}
+
+/// Rolling-window action counter
+///
+/// Counts are kept per fixed `period`; the rolling count is the current period's plus the
+/// previous period's, weighted by how much of it still falls within the last `period`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct RateWindow {
+    pub window_start: i64,
+    pub count: u16,
+    pub prev_count: u16,
+}
+
+impl RateWindow {
+    /// Count one action; returns false (without counting) if `limit` is already reached
+    pub fn try_hit(&mut self, now: i64, period: i64, limit: u16) -> bool {
+        let elapsed = now.saturating_sub(self.window_start);
+        if elapsed >= period {
+            let periods = elapsed / period;
+            self.prev_count = if periods == 1 { self.count } else { 0 };
+            self.count = 0;
+            self.window_start = self.window_start.saturating_add(periods.saturating_mul(period));
+        }
+        let remaining = (period - now.saturating_sub(self.window_start)).clamp(0, period) as u64;
+        let carried = (self.prev_count as u64 * remaining).div_ceil(period as u64);
+        if limit != 0 && carried + self.count as u64 >= limit as u64 {
+            return false;
+        }
+        self.count = self.count.saturating_add(1);
+        true
+    }
+}
+
+impl User {
+    /// Whether `viewer` may see this profile's extended data and contact info
+    pub fn visible_to(&self, viewer: &Pubkey, connected: bool) -> bool {
+        if &self.wallet == viewer {
+            return true;
+        }
+        match self.profile_visibility {
+            ProfileVisibility::Public => true,
+            ProfileVisibility::FriendsOnly => connected,
+            ProfileVisibility::Private => false,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Project {
+    pub creator: Pubkey,                        // 32 bytes (current owner)
+    #[max_len(50)]
+    pub name: String,                           // 4 + 50 = 54 bytes
+    #[max_len(1000)]
+    pub description: String,                    // 4 + 1000 = 1004 bytes
+    #[max_len(100)]
+    pub github_link: String,                    // 4 + 100 = 104 bytes
+    #[max_len(64)]
+    pub logo_ipfs_hash: String,                 // 4 + 64 = 68 bytes (IPFS hash for project logo)
+    // Tech stack as tags (up to 12 tags, each up to 24 chars)
+    #[max_len(12)]
+    pub tech_stack: Vec<ShortText>,             // variable
+    // Contribution needs as tags (up to 10 tags, each up to 24 chars)
+    #[max_len(10)]
+    pub contribution_needs: Vec<ShortText>,     // variable
+    // Free text intent
+    #[max_len(300)]
+    pub collab_intent: String,                  // 4 + 300 = 304 bytes
+    pub collaboration_level: CollaborationLevel, // 1 byte (enum)
+    pub project_status: ProjectStatus,          // 1 byte (enum)
+    pub accepting_collaborations: CollaborationAcceptance, // 1 byte (open/closed)
+    pub timestamp: i64,                         // 8 bytes
+    pub last_updated: i64,                      // 8 bytes
+    pub contributors_count: u16,                // 2 bytes
+    pub is_active: bool,                        // 1 byte
+    pub bump: u8,                               // 1 byte
+    // Role-based contribution slots (max 8 roles)
+    #[max_len(8)]
+    pub required_roles: Vec<RoleRequirement>,   // 4 + (3 * 8) = 28 bytes max
+    pub stars_count: u32,                       // 4 bytes
+    pub application_bond: u64,                  // 8 bytes (lamports required to apply; 0 = none)
+    pub response_window: i64,                   // 8 bytes (SLA response window in seconds; 0 = none)
+    pub avg_response_time: i64,                 // 8 bytes (running average seconds to accept/reject)
+    pub responses_count: u32,                   // 4 bytes (accepts + rejects counted in the average)
+    pub sla_breach_count: u32,                  // 4 bytes
+    pub applicant_requirements: ApplicantRequirements, // 17 bytes (sybil gates for applicants)
+    pub seed_creator: Pubkey,                   // 32 bytes (creator at creation; PDA seed, never changes)
+    pub pending_owner: Pubkey,                  // 32 bytes (proposed new owner; default = none)
+    pub approval_threshold: u8,                 // 1 byte (mirrors ApproverSet.threshold; 0 = no approvals needed)
+    pub proposal_count: u64,                    // 8 bytes (next proposal index)
+    pub organization: Pubkey,                   // 32 bytes (owning Organization; default = none)
+    pub bounty_count: u32,                      // 4 bytes (next bounty index)
+    pub donations_raised: u64,                  // 8 bytes (lifetime SOL donations in lamports)
+    pub donor_count: u32,                       // 4 bytes (distinct donors, SOL or token)
+    pub team_size_cap: u8,                      // 1 byte (max creator + role slots; 0 = none, set by events)
+    pub milestone_count: u32,                   // 4 bytes (next milestone index; milestones are never closed)
+    pub milestones_completed: u32,              // 4 bytes
+    pub completion_pct: u8,                     // 1 byte (milestones_completed / milestone_count, derived)
+    pub status_from_milestones: bool,           // 1 byte (keep project_status at the suggested status)
+    pub task_count: u32,                        // 4 bytes (next task index)
+    pub owner_epoch: u32,                       // 4 bytes (ownership transfers so far; delegations from older epochs lapse)
+    pub team_cap_ends_at: i64,                  // 8 bytes (team_size_cap lapses here: latest end of an entered event)
+    pub live_escrows: u32,                      // 4 bytes (unsettled bounties, SLA bond, donation vault, grant and event entries)
+}
+// Total: ~<= 4KB (well under limit with tagged vectors + roles)
+
+impl Project {
+    /// Status matching milestone progress, if the project has milestones
+    pub fn suggested_status(&self) -> Option<ProjectStatus> {
+        if self.milestone_count == 0 {
+            return None;
+        }
+        Some(match self.completion_pct {
+            0..=24 => ProjectStatus::JustStarted,
+            25..=74 => ProjectStatus::InProgress,
+            75..=99 => ProjectStatus::NearlyComplete,
+            _ => ProjectStatus::Completed,
+        })
+    }
+
+    /// Recompute `completion_pct`, and `project_status` if it follows milestones
+    pub fn refresh_progress(&mut self) {
+        self.completion_pct = match self.milestone_count {
+            0 => 0,
+            total => (self.milestones_completed as u64 * 100 / total as u64) as u8,
+        };
+        if let (true, Some(status)) = (self.status_from_milestones, self.suggested_status()) {
+            self.project_status = status;
+        }
+    }
+
+    /// Event team cap in force at `now`, if any
+    pub fn active_team_cap(&self, now: i64) -> Option<u8> {
+        (self.team_size_cap != 0 && now < self.team_cap_ends_at).then_some(self.team_size_cap)
+    }
+
+    /// Fails while the project is listed in an organization's index; org projects are
+    /// managed by the organization and can't be deleted or handed to an outsider
+    pub fn check_not_in_organization(&self) -> Result<()> {
+        require!(
+            self.organization == Pubkey::default(),
+            ErrorCode::ProjectInOrganization
+        );
+        Ok(())
+    }
+
+    /// Fails if the creator plus every slot in `roles` exceeds the active team cap
+    pub fn check_team_cap(&self, roles: &[RoleRequirement], now: i64) -> Result<()> {
+        if let Some(cap) = self.active_team_cap(now) {
+            let team_size = 1 + roles.iter().map(|r| r.needed as u32).sum::<u32>();
+            require!(team_size <= cap as u32, ErrorCode::TeamSizeCapExceeded);
+        }
+        Ok(())
+    }
+
+    /// True if `key` is the creator, an admin of the owning organization, or `role` is its
+    /// current delegation on this project with `permission`. `org` must be `self.organization`.
+    pub fn is_authorized(
+        &self,
+        key: &Pubkey,
+        role: Option<&ProjectRole>,
+        org: Option<&Organization>,
+        permission: u8,
+    ) -> bool {
+        if key == &self.creator || self.is_org_admin(key, org) {
+            return true;
+        }
+        role.is_some_and(|r| self.is_current_delegation(r, key) && r.has(permission))
+    }
+
+    /// Permissions `key` may hand out or take away on this project
+    pub fn grantable_permissions(
+        &self,
+        key: &Pubkey,
+        role: Option<&ProjectRole>,
+        org: Option<&Organization>,
+    ) -> u8 {
+        if key == &self.creator || self.is_org_admin(key, org) {
+            return ProjectRole::ALL;
+        }
+        match role {
+            Some(r) if self.is_current_delegation(r, key) && r.has(ProjectRole::MANAGE_ROLES) => {
+                r.permissions
+            }
+            _ => 0,
+        }
+    }
+
+    /// `role` is `key`'s delegation, granted or renewed under the current owner
+    fn is_current_delegation(&self, role: &ProjectRole, key: &Pubkey) -> bool {
+        &role.delegate == key && role.owner_epoch == self.owner_epoch
+    }
+
+    fn is_org_admin(&self, key: &Pubkey, org: Option<&Organization>) -> bool {
+        self.organization != Pubkey::default() && org.is_some_and(|o| o.is_admin(key))
+    }
+
+    /// Fold one accept/reject latency into the running average
+    pub fn record_response(&mut self, elapsed: i64) {
+        let n = self.responses_count as i128;
+        let total = self.avg_response_time as i128 * n + elapsed.max(0) as i128;
+        self.responses_count = self.responses_count.saturating_add(1);
+        self.avg_response_time = (total / (n + 1)) as i64;
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CollaborationRequest {
+    pub from: Pubkey,             // 32 bytes
+    pub to: Pubkey,               // 32 bytes
+    pub project: Pubkey,          // 32 bytes
+    #[max_len(500)]
+    pub message: String,          // 4 + 500 = 504 bytes (sender's message)
+    #[max_len(500)]
+    pub owner_message: String,    // 4 + 500 = 504 bytes (owner's reply message)
+    pub status: RequestStatus,    // 1 byte
+    pub timestamp: i64,           // 8 bytes (request sent timestamp)
+    pub reply_timestamp: i64,     // 8 bytes (owner reply timestamp)
+    pub bump: u8,                 // 1 byte
+    pub desired_role: Option<Role>, // 2 bytes (1 discriminant + 1 enum)
+    pub message_count: u32,       // 4 bytes (thread messages posted; next message seq)
+    pub last_activity: i64,       // 8 bytes (last status change or message edit)
+    pub bond_amount: u64,         // 8 bytes (application bond backing this request; 0 = none)
+    pub response_deadline: i64,   // 8 bytes (owner must answer by then under an SLA; 0 = none)
+    pub thread_activity: i64,     // 8 bytes (last thread message; 0 = none)
+    pub left_at: i64,             // 8 bytes (when the member left; 0 = never)
+}
+// Total: ~1176 bytes (well under 4KB)
+
+impl CollaborationRequest {
+    /// Still waiting on the project owner
+    pub fn is_open(&self) -> bool {
+        self.status == RequestStatus::Pending || self.status == RequestStatus::UnderReview
+    }
+
+    /// The other party of the request from `wallet`'s point of view
+    pub fn counterpart(&self, wallet: &Pubkey) -> Pubkey {
+        if &self.from == wallet { self.to } else { self.from }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RequestMessage {
+    pub request: Pubkey,          // 32 bytes (parent CollaborationRequest)
+    pub thread: i64,              // 8 bytes (parent's send timestamp; PDA seed, new per re-sent request)
+    pub author: Pubkey,           // 32 bytes (request sender or project owner)
+    pub seq: u32,                 // 4 bytes (position in the thread, starts at 0)
+    pub timestamp: i64,           // 8 bytes
+    #[max_len(500)]
+    pub text: String,             // 4 + 500 = 504 bytes
+    #[max_len(64)]
+    pub ipfs_hash: String,        // 4 + 64 = 68 bytes (optional attachment / long-form body)
+    pub bump: u8,                 // 1 byte
+}
+// Total: ~657 bytes
+
+#[account]
+#[derive(InitSpace)]
+pub struct ContactEnvelope {
+    pub owner: Pubkey,                       // 32 bytes (profile whose contact info is shared)
+    pub recipient: Pubkey,                   // 32 bytes (accepted counterpart wallet)
+    pub request: Pubkey,                     // 32 bytes (accepted CollaborationRequest)
+    pub recipient_encryption_pubkey: [u8; 32], // 32 bytes (key the envelope was sealed to)
+    #[max_len(80)]
+    pub sealed_key: Vec<u8>,                 // 4 + 80 = 84 bytes
+    pub granted_at: i64,                     // 8 bytes
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~225 bytes
+
+#[account]
+#[derive(InitSpace)]
+pub struct Connection {
+    pub user_a: Pubkey,                      // 32 bytes (lower of the two wallets)
+    pub user_b: Pubkey,                      // 32 bytes (higher of the two wallets)
+    pub requester: Pubkey,                   // 32 bytes (paid rent; receives refund on removal)
+    pub status: ConnectionStatus,            // 1 byte
+    pub created_at: i64,                     // 8 bytes
+    pub connected_at: i64,                   // 8 bytes (0 while pending)
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~114 bytes
+
+impl Connection {
+    /// Order two wallets the way connection PDA seeds expect them
+    pub fn ordered(a: Pubkey, b: Pubkey) -> (Pubkey, Pubkey) {
+        if a <= b { (a, b) } else { (b, a) }
+    }
+
+    pub fn involves(&self, wallet: &Pubkey) -> bool {
+        &self.user_a == wallet || &self.user_b == wallet
+    }
+
+    /// True if this is an accepted connection between `a` and `b` (in either order)
+    pub fn is_connected(&self, a: &Pubkey, b: &Pubkey) -> bool {
+        self.status == ConnectionStatus::Connected
+            && (self.user_a, self.user_b) == Connection::ordered(*a, *b)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Follow {
+    pub follower: Pubkey,                    // 32 bytes
+    pub followed: Pubkey,                    // 32 bytes
+    pub timestamp: i64,                      // 8 bytes
+    pub bump: u8,                            // 1 byte
+    pub follower_since: i64,                 // 8 bytes (follower's member_since when followed)
+    pub followed_since: i64,                 // 8 bytes (followed user's member_since; PDA seed)
+}
+// Total: ~89 bytes
+
+#[account]
+#[derive(InitSpace)]
+pub struct Star {
+    pub user: Pubkey,                        // 32 bytes
+    pub project: Pubkey,                     // 32 bytes
+    pub timestamp: i64,                      // 8 bytes
+    pub bump: u8,                            // 1 byte
+    pub project_since: i64,                  // 8 bytes (project's creation timestamp; PDA seed)
+}
+// Total: ~81 bytes
+
+#[account]
+#[derive(InitSpace)]
+pub struct Block {
+    pub blocker: Pubkey,                     // 32 bytes
+    pub blocked: Pubkey,                     // 32 bytes
+    pub timestamp: i64,                      // 8 bytes
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~73 bytes (not reflected in any profile counter)
+
+#[account]
+#[derive(InitSpace)]
+pub struct ApplicationBond {
+    pub applicant: Pubkey,                   // 32 bytes
+    pub project: Pubkey,                     // 32 bytes
+    pub amount: u64,                         // 8 bytes (held on top of rent)
+    pub deposited_at: i64,                   // 8 bytes
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~81 bytes
+
+#[account]
+#[derive(InitSpace)]
+pub struct SlaBond {
+    pub project: Pubkey,                     // 32 bytes
+    pub owner: Pubkey,                       // 32 bytes (staker; receives the remainder)
+    pub amount: u64,                         // 8 bytes (staked lamports left, on top of rent)
+    pub penalty: u64,                        // 8 bytes (paid per breached request)
+    pub response_window: i64,                // 8 bytes
+    pub retire_at: i64,                      // 8 bytes (withdrawable from; 0 = active)
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~97 bytes
+
+#[account]
+#[derive(InitSpace)]
+pub struct Organization {
+    #[max_len(32)]
+    pub name: String,                        // 4 + 32 = 36 bytes (PDA seed, never changes)
+    #[max_len(64)]
+    pub logo_ipfs_hash: String,              // 4 + 64 = 68 bytes
+    pub created_by: Pubkey,                  // 32 bytes
+    #[max_len(20)]
+    pub members: Vec<OrgMember>,             // 4 + 33 * 20 = 664 bytes
+    pub is_verified: bool,                   // 1 byte
+    pub project_index: u32,                  // 4 bytes (next OrgProject index; entries may be closed)
+    pub project_count: u32,                  // 4 bytes (projects currently in the organization)
+    pub created_at: i64,                     // 8 bytes
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~822 bytes
+
+impl Organization {
+    pub fn is_admin(&self, wallet: &Pubkey) -> bool {
+        self.members
+            .iter()
+            .any(|m| &m.wallet == wallet && m.role == OrgRole::Admin)
+    }
+
+    pub fn admin_count(&self) -> usize {
+        self.members.iter().filter(|m| m.role == OrgRole::Admin).count()
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OrgProject {
+    pub organization: Pubkey,                // 32 bytes
+    pub project: Pubkey,                     // 32 bytes
+    pub index: u32,                          // 4 bytes
+    pub added_at: i64,                       // 8 bytes
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~77 bytes
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProjectRole {
+    pub project: Pubkey,                     // 32 bytes
+    pub delegate: Pubkey,                    // 32 bytes
+    pub permissions: u8,                     // 1 byte (ProjectRole::* bitflags)
+    pub granted_by: Pubkey,                  // 32 bytes (paid rent; receives refund on revoke)
+    pub granted_at: i64,                     // 8 bytes
+    pub bump: u8,                            // 1 byte
+    pub owner_epoch: u32,                    // 4 bytes (Project.owner_epoch when granted or renewed)
+}
+// Total: ~110 bytes
+
+impl ProjectRole {
+    /// Mark, accept, reject and flag collaboration requests
+    pub const REVIEW_REQUESTS: u8 = 1 << 0;
+    /// Edit project details, milestones and tasks
+    pub const EDIT_PROJECT: u8 = 1 << 1;
+    /// Grant, change and revoke delegations, up to the holder's own permissions
+    pub const MANAGE_ROLES: u8 = 1 << 2;
+    /// Change the team's role slots and fund member streams
+    pub const MANAGE_MEMBERS: u8 = 1 << 3;
+    /// Open, award and cancel bounties
+    pub const MANAGE_BOUNTIES: u8 = 1 << 4;
+    pub const ALL: u8 = Self::REVIEW_REQUESTS
+        | Self::EDIT_PROJECT
+        | Self::MANAGE_ROLES
+        | Self::MANAGE_MEMBERS
+        | Self::MANAGE_BOUNTIES;
+
+    pub fn has(&self, permission: u8) -> bool {
+        self.permissions & permission == permission
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ApproverSet {
+    pub project: Pubkey,                     // 32 bytes
+    #[max_len(10)]
+    pub approvers: Vec<Pubkey>,              // 4 + 32 * 10 = 324 bytes
+    pub threshold: u8,                       // 1 byte
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~358 bytes
+
+impl ApproverSet {
+    pub fn is_approver(&self, key: &Pubkey) -> bool {
+        self.approvers.contains(key)
+    }
+
+    /// Fails unless `proposal` is live and approved by at least `threshold` current approvers
+    pub fn check_executable(&self, proposal: &Proposal, now: i64) -> Result<()> {
+        require!(now <= proposal.expires_at, ErrorCode::ProposalExpired);
+        let approvals = proposal
+            .approvals
+            .iter()
+            .filter(|a| self.is_approver(a))
+            .count();
+        require!(
+            self.threshold > 0 && approvals >= self.threshold as usize,
+            ErrorCode::NotEnoughApprovals
+        );
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub project: Pubkey,                     // 32 bytes
+    pub index: u64,                          // 8 bytes
+    pub proposer: Pubkey,                    // 32 bytes (paid rent; receives refund on execute/cancel)
+    pub action: ProposalAction,              // 1 + 536 bytes max (AcceptRequest)
+    #[max_len(10)]
+    pub approvals: Vec<Pubkey>,              // 4 + 32 * 10 = 324 bytes
+    pub created_at: i64,                     // 8 bytes
+    pub expires_at: i64,                     // 8 bytes
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~950 bytes
+
+/// Planned piece of work on a project; completed milestones drive `Project.completion_pct`
+#[account]
+#[derive(InitSpace)]
+pub struct Milestone {
+    pub project: Pubkey,                     // 32 bytes
+    pub index: u32,                          // 4 bytes (PDA seed; position in project.milestone_count)
+    #[max_len(64)]
+    pub title: String,                       // 4 + 64 = 68 bytes
+    #[max_len(64)]
+    pub spec_ipfs_hash: String,              // 4 + 64 = 68 bytes
+    pub due_date: i64,                       // 8 bytes
+    #[max_len(4)]
+    pub assignees: Vec<Pubkey>,              // 4 + 32 * 4 = 132 bytes (creator or accepted members)
+    pub status: MilestoneStatus,             // 1 byte
+    pub created_at: i64,                     // 8 bytes
+    pub completed_at: i64,                   // 8 bytes (0 while open)
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~334 bytes
+
+/// Claimable item on a project's task board
+#[account]
+#[derive(InitSpace)]
+pub struct Task {
+    pub project: Pubkey,                     // 32 bytes
+    pub index: u32,                          // 4 bytes (PDA seed; position in project.task_count)
+    #[max_len(64)]
+    pub title: String,                       // 4 + 64 = 68 bytes
+    #[max_len(4)]
+    pub labels: Vec<ShortText>,              // 4 + 28 * 4 = 116 bytes (from the project's tech stack)
+    pub estimate: u16,                       // 2 bytes (story points credited on approval)
+    pub status: TaskStatus,                  // 1 byte
+    pub assignee: Pubkey,                    // 32 bytes (default = unclaimed)
+    #[max_len(64)]
+    pub submission_ipfs_hash: String,        // 4 + 64 = 68 bytes
+    pub created_at: i64,                     // 8 bytes
+    pub completed_at: i64,                   // 8 bytes (0 until approved)
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~348 bytes
+
+/// A member's contribution credit on one project, kept apart from their (deletable) request
+#[account]
+#[derive(InitSpace)]
+pub struct MemberStats {
+    pub project: Pubkey,                     // 32 bytes
+    pub member: Pubkey,                      // 32 bytes
+    pub tasks_completed: u32,                // 4 bytes
+    pub task_points: u64,                    // 8 bytes (sum of approved task estimates)
+    pub last_completed_at: i64,              // 8 bytes
+    pub bump: u8,                            // 1 byte
+    pub reputation_awarded: u32,             // 4 bytes (credited by this project; at most MAX_PROJECT_REPUTATION)
+    pub collab_counted: bool,                // 1 byte (an accept here already bumped the member's collabs_count)
+}
+// Total: ~98 bytes
+
+impl MemberStats {
+    /// Fill in the key fields the first time the record is used
+    pub fn open(&mut self, project: Pubkey, member: Pubkey, bump: u8) {
+        if self.member == Pubkey::default() {
+            self.project = project;
+            self.member = member;
+            self.bump = bump;
+        }
+    }
+}
+
+/// Project task paid in SOL or an allowlisted SPL token
+///
+/// SOL is escrowed in the PDA itself on top of its rent; tokens sit in the project's vault ATA.
+#[account]
+#[derive(InitSpace)]
+pub struct Bounty {
+    pub project: Pubkey,                     // 32 bytes
+    pub funder: Pubkey,                      // 32 bytes (escrow and rent refunded here)
+    pub index: u32,                          // 4 bytes (PDA seed; position in project.bounty_count)
+    #[max_len(64)]
+    pub title: String,                       // 4 + 64 = 68 bytes
+    #[max_len(64)]
+    pub spec_ipfs_hash: String,              // 4 + 64 = 68 bytes
+    pub amount: u64,                         // 8 bytes (escrowed lamports)
+    pub deadline: i64,                       // 8 bytes
+    pub assignee: Pubkey,                    // 32 bytes (default = unassigned)
+    #[max_len(64)]
+    pub submission_ipfs_hash: String,        // 4 + 64 = 68 bytes
+    pub status: BountyStatus,                // 1 byte
+    pub created_at: i64,                     // 8 bytes
+    pub bump: u8,                            // 1 byte
+    pub mint: Pubkey,                        // 32 bytes (SPL mint paid in; default = SOL)
+    pub lapsed_disputes: u8,                 // 1 byte (parties whose dispute timed out; see Dispute::claimant_flag)
+}
+// Total: ~363 bytes
+
+impl Bounty {
+    /// Paid in SOL (escrowed in the bounty PDA) rather than an SPL token
+    pub fn is_sol(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+
+    /// Cancellable while open, or once an assignee missed the deadline
+    pub fn ensure_cancellable(&self, now: i64) -> Result<()> {
+        match self.status {
+            BountyStatus::Open => Ok(()),
+            BountyStatus::Assigned => {
+                require!(now > self.deadline, ErrorCode::BountyDeadlineNotReached);
+                Ok(())
+            }
+            _ => err!(ErrorCode::InvalidBountyStatus),
+        }
+    }
+}
+
+/// Linear SOL stream to an accepted member, escrowed in the PDA on top of its rent
+#[account]
+#[derive(InitSpace)]
+pub struct Stream {
+    pub project: Pubkey,                     // 32 bytes
+    pub collab_request: Pubkey,              // 32 bytes (membership being paid; PDA seed)
+    pub funder: Pubkey,                      // 32 bytes (unvested escrow returned here)
+    pub recipient: Pubkey,                   // 32 bytes (the member)
+    pub start: i64,                          // 8 bytes
+    pub cliff: i64,                          // 8 bytes (nothing withdrawable before)
+    pub end: i64,                            // 8 bytes
+    pub rate: u64,                           // 8 bytes (lamports per second)
+    pub total: u64,                          // 8 bytes (rate * (end - start))
+    pub withdrawn: u64,                      // 8 bytes
+    pub paused_at: i64,                      // 8 bytes (vesting stops here once the member leaves; 0 = running)
+    pub bump: u8,                            // 1 byte
+    pub frozen: bool,                        // 1 byte (under dispute; no withdrawals or cancellation)
+    pub lapsed_disputes: u8,                 // 1 byte (parties whose dispute timed out; see Dispute::claimant_flag)
+}
+// Total: ~195 bytes
+
+impl Stream {
+    /// Amount vested at `now`, honoring the cliff, the end and any pause
+    pub fn vested(&self, now: i64) -> u64 {
+        let mut until = now.min(self.end);
+        if self.paused_at != 0 {
+            until = until.min(self.paused_at);
+        }
+        if until < self.cliff || until <= self.start {
+            return 0;
+        }
+        self.rate.saturating_mul((until - self.start) as u64).min(self.total)
+    }
+
+    pub fn pause(&mut self, at: i64) {
+        if self.paused_at == 0 {
+            self.paused_at = at;
+        }
+    }
+
+    /// Pause if the member behind `request_info` is gone: left (as of leaving) or removed (as of now)
+    pub fn sync_membership(&mut self, request_info: &AccountInfo, now: i64) -> Result<()> {
+        if self.paused_at != 0 {
+            return Ok(());
+        }
+        if request_info.data_is_empty() || request_info.owner != &crate::ID {
+            self.pause(now);
+            return Ok(());
+        }
+        let request = CollaborationRequest::try_deserialize(&mut &request_info.data.borrow()[..])?;
+        match request.status {
+            RequestStatus::Accepted => {}
+            RequestStatus::Left => self.pause(request.left_at),
+            _ => self.pause(now),
+        }
+        Ok(())
+    }
+}
+
+/// Per-project donation vault: holds SOL on top of its rent and owns the donation token accounts
+#[account]
+#[derive(InitSpace)]
+pub struct DonationVault {
+    pub project: Pubkey,                     // 32 bytes
+    #[max_len(16)]
+    pub token_raised: Vec<MintTotal>,        // 4 + 40 * 16 = 644 bytes (lifetime token donations per mint)
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~677 bytes
+
+impl DonationVault {
+    pub fn add_token_raised(&mut self, mint: Pubkey, amount: u64) -> Result<()> {
+        if let Some(total) = self.token_raised.iter_mut().find(|t| t.mint == mint) {
+            total.amount = total.amount.saturating_add(amount);
+            return Ok(());
+        }
+        require!(self.token_raised.len() < MAX_ALLOWED_MINTS, ErrorCode::DonationMintLimit);
+        self.token_raised.push(MintTotal { mint, amount });
+        Ok(())
+    }
+}
+
+/// A supporter's running totals on one project; created on their first donation
+#[account]
+#[derive(InitSpace)]
+pub struct DonorRecord {
+    pub project: Pubkey,                     // 32 bytes
+    pub donor: Pubkey,                       // 32 bytes
+    pub lamports_donated: u64,               // 8 bytes (SOL only; token amounts are per receipt)
+    pub donation_count: u32,                 // 4 bytes (next receipt index)
+    pub first_donated_at: i64,               // 8 bytes
+    pub last_donated_at: i64,                // 8 bytes
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~101 bytes
+
+/// Optional on-chain receipt for a single donation
+#[account]
+#[derive(InitSpace)]
+pub struct Donation {
+    pub project: Pubkey,                     // 32 bytes
+    pub donor: Pubkey,                       // 32 bytes
+    pub index: u32,                          // 4 bytes (PDA seed; donor's donation number)
+    pub mint: Pubkey,                        // 32 bytes (default = SOL)
+    pub amount: u64,                         // 8 bytes (as received by the vault)
+    #[max_len(140)]
+    pub memo: String,                        // 4 + 140 = 144 bytes
+    pub donated_at: i64,                     // 8 bytes
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~269 bytes
+
+/// Quadratic-funding round; the matching pool and all contributions are escrowed in the PDA
+#[account]
+#[derive(InitSpace)]
+pub struct GrantRound {
+    pub operator: Pubkey,                    // 32 bytes (PDA seed)
+    #[max_len(32)]
+    pub name: String,                        // 4 + 32 = 36 bytes (PDA seed)
+    pub matching_pool: u64,                  // 8 bytes
+    pub registration_start: i64,             // 8 bytes
+    pub registration_end: i64,               // 8 bytes
+    pub contribution_start: i64,             // 8 bytes
+    pub contribution_end: i64,               // 8 bytes
+    #[max_len(16)]
+    pub projects: Vec<RoundProject>,         // 4 + 62 * 16 = 996 bytes (registered projects and tallies)
+    pub total_score: u128,                   // 16 bytes (sum of project scores at finalization)
+    pub status: GrantRoundStatus,            // 1 byte
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~1130 bytes
+
+impl GrantRound {
+    pub fn entry(&self, project: &Pubkey) -> Option<&RoundProject> {
+        self.projects.iter().find(|e| &e.project == project)
+    }
+
+    pub fn entry_mut(&mut self, project: &Pubkey) -> Option<&mut RoundProject> {
+        self.projects.iter_mut().find(|e| &e.project == project)
+    }
+}
+
+/// Hackathon grouping projects into tracks; prizes are escrowed in the PDA
+#[account]
+#[derive(InitSpace)]
+pub struct Event {
+    pub organizer: Pubkey,                   // 32 bytes (PDA seed)
+    #[max_len(32)]
+    pub name: String,                        // 4 + 32 = 36 bytes (PDA seed)
+    #[max_len(8)]
+    pub judges: Vec<Pubkey>,                 // 4 + 32 * 8 = 260 bytes
+    #[max_len(8)]
+    pub tracks: Vec<ShortText>,              // 4 + 28 * 8 = 228 bytes
+    pub start_time: i64,                     // 8 bytes
+    pub end_time: i64,                       // 8 bytes (registration closes, judging opens)
+    pub max_team_size: u8,                   // 1 byte (creator + role slots)
+    #[max_len(8)]
+    pub prizes: Vec<u64>,                    // 4 + 8 * 8 = 68 bytes (lamports for 1st, 2nd, ...)
+    pub entries_settled: u32,                // 4 bytes (prizes paid plus entries closed out without one)
+    pub project_count: u32,                  // 4 bytes
+    pub status: EventStatus,                 // 1 byte
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~659 bytes
+
+/// A project's entry in an event with its judges' scores and final rank
+#[account]
+#[derive(InitSpace)]
+pub struct EventEntry {
+    pub event: Pubkey,                       // 32 bytes
+    pub project: Pubkey,                     // 32 bytes
+    pub track: u8,                           // 1 byte (index into Event.tracks)
+    #[max_len(8)]
+    pub scores: Vec<JudgeScore>,             // 4 + 33 * 8 = 268 bytes
+    pub rank: u8,                            // 1 byte (1 = first; 0 = unranked)
+    pub prize_paid: bool,                    // 1 byte (settled, with or without a prize)
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~340 bytes
+
+impl EventEntry {
+    /// Mean judge score scaled by 100 (0 if unscored)
+    pub fn average_score(&self) -> u64 {
+        if self.scores.is_empty() {
+            return 0;
+        }
+        let total: u64 = self.scores.iter().map(|s| s.score as u64).sum();
+        total * 100 / self.scores.len() as u64
+    }
+}
+
+/// One contributor's running total to one project in a round
+#[account]
+#[derive(InitSpace)]
+pub struct GrantContribution {
+    pub round: Pubkey,                       // 32 bytes
+    pub project: Pubkey,                     // 32 bytes
+    pub contributor: Pubkey,                 // 32 bytes
+    pub amount: u64,                         // 8 bytes (lamports contributed so far)
+    pub weighted_sqrt: u64,                  // 8 bytes (sqrt(amount) * sybil weight; counted in the project tally)
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~113 bytes
+
+/// Contested bounty or stream escrow, frozen until its arbiter rules or the window runs out
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    pub escrow: Pubkey,                      // 32 bytes (bounty or stream; PDA seed)
+    pub kind: DisputeKind,                   // 1 byte
+    pub project: Pubkey,                     // 32 bytes
+    pub funder: Pubkey,                      // 32 bytes (gets what the ruling doesn't award)
+    pub recipient: Pubkey,                   // 32 bytes (assignee or stream recipient)
+    pub claimant: Pubkey,                    // 32 bytes (opened it and paid the rent)
+    pub respondent: Pubkey,                  // 32 bytes
+    pub arbiter: Pubkey,                     // 32 bytes (drawn from the config set)
+    #[max_len(64)]
+    pub claimant_evidence: String,           // 4 + 64 = 68 bytes (IPFS hash)
+    #[max_len(64)]
+    pub respondent_evidence: String,         // 4 + 64 = 68 bytes (IPFS hash; empty until answered)
+    pub amount: u64,                         // 8 bytes (escrow frozen; lamports or token units)
+    pub fee_bps: u16,                        // 2 bytes (arbiter fee, fixed at opening)
+    pub opened_at: i64,                      // 8 bytes
+    pub deadline: i64,                       // 8 bytes (ruling window; expirable after)
+    pub status: DisputeStatus,               // 1 byte
+    pub recipient_bps: u16,                  // 2 bytes (ruling: recipient's share after the fee)
+    pub bounty_status: BountyStatus,         // 1 byte (restored on timeout; unused for streams)
+    pub bump: u8,                            // 1 byte
+}
+// Total: ~439 bytes
+
+impl Dispute {
+    /// Record a ruling and split the escrow into (arbiter fee, recipient share, funder share)
+    pub fn rule(&mut self, recipient_bps: u16, now: i64) -> Result<(u64, u64, u64)> {
+        require!(self.status == DisputeStatus::Open, ErrorCode::DisputeNotOpen);
+        require!(now <= self.deadline, ErrorCode::DisputeTimedOut);
+        require!(recipient_bps <= 10_000, ErrorCode::InvalidDisputeSplit);
+
+        let fee = (self.amount as u128 * self.fee_bps as u128 / 10_000) as u64;
+        let to_recipient = ((self.amount - fee) as u128 * recipient_bps as u128 / 10_000) as u64;
+        self.status = DisputeStatus::Resolved;
+        self.recipient_bps = recipient_bps;
+        Ok((fee, to_recipient, self.amount - fee - to_recipient))
+    }
+
+    /// Close an unruled dispute once its window has passed
+    pub fn expire(&mut self, now: i64) -> Result<()> {
+        require!(self.status == DisputeStatus::Open, ErrorCode::DisputeNotOpen);
+        require!(now > self.deadline, ErrorCode::DisputeTimeoutNotReached);
+        self.status = DisputeStatus::TimedOut;
+        Ok(())
+    }
+
+    /// Bit marking the claimant in an escrow's `lapsed_disputes`: 1 = funder, 2 = recipient
+    pub fn claimant_flag(&self) -> u8 {
+        if self.claimant == self.funder {
+            1
+        } else {
+            2
+        }
+    }
+}
+
+// ==================== ENUMS ====================
+
+/// Optional gates an applicant's profile must pass to send a request; zero/false disables each
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct ApplicantRequirements {
+    pub min_account_age: i64,   // seconds since `member_since`
+    pub min_reputation: u32,
+    pub verified_only: bool,
+    pub min_collabs: u32,
+}
+
+impl ApplicantRequirements {
+    /// Fails with the error naming the first gate `user` does not pass
+    pub fn check(&self, user: &User, now: i64) -> Result<()> {
+        require!(
+            now.saturating_sub(user.member_since) >= self.min_account_age,
+            ErrorCode::ApplicantAccountTooNew
+        );
+        require!(
+            user.reputation >= self.min_reputation,
+            ErrorCode::ApplicantReputationTooLow
+        );
+        require!(
+            !self.verified_only || user.is_verified,
+            ErrorCode::ApplicantNotVerified
+        );
+        require!(
+            user.collabs_count >= self.min_collabs,
+            ErrorCode::ApplicantTooFewCollabs
+        );
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    Frontend,
+    Backend,
+    Fullstack,
+    DevOps,
+    QA,
+    Designer,
+    Others,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct RoleRequirement {
+    pub role: Role,
+    pub needed: u8,
+    pub accepted: u8,
+    // Optional label for "Others" roles (max 24 chars)
+    #[max_len(24)]
+    pub label: Option<String>,
+}
+
+/// Returned by `assert_membership`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct Membership {
+    pub project: Pubkey,
+    pub member: Pubkey,
+    pub is_owner: bool,
+    pub role: Option<Role>,   // Role of the accepted request; None for the owner
+    pub joined_at: i64,       // Acceptance time, or project creation for the owner
+}
+
+/// Returned by `get_open_slots`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct OpenSlots {
+    pub accepting: bool,      // Active and open for collaboration
+    pub slots: Vec<OpenSlot>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct OpenSlot {
+    pub role: Role,
+    pub label: Option<String>,
+    pub open: u8,             // needed - accepted
 }
 
-#[derive(Accounts)]
-pub struct MigrateUser<'info> {
-    #[account(mut)]
-    pub user: Account<'info, User>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
+/// Returned by `can_apply`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Eligibility {
+    pub eligible: bool,
+    pub error_code: u64,      // Error `send_collab_request` would fail with; 0 when eligible
 }
 
-#[derive(Accounts)]
-#[instruction(name: String)]
-pub struct CreateProject<'info> {
-    #[account(
-        init,
-        payer = creator,
-        space = 8 + Project::INIT_SPACE,
-        seeds = [b"project", creator.key().as_ref(), name.as_bytes()],
-        bump
-    )]
-    pub project: Account<'info, Project>,
+impl Eligibility {
+    /// Report a check's outcome; builtin `ProgramError`s keep their full code, which lives
+    /// in the upper 32 bits
+    pub fn from_check(result: Result<()>) -> Self {
+        let error_code = match result {
+            Ok(()) => 0,
+            Err(Error::AnchorError(e)) => e.error_code_number.into(),
+            Err(Error::ProgramError(e)) => u64::from(e.program_error),
+        };
+        Eligibility { eligible: error_code == 0, error_code }
+    }
+}
 
-    // Require that the creator already has a User profile
-    pub user: Account<'info, User>,
+/// Returned by `get_profile_summary`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ProfileSummary {
+    pub wallet: Pubkey,
+    pub username: String,
+    pub display_name: String,
+    pub reputation: u32,
+    pub projects_count: u32,
+    pub collabs_count: u32,
+    pub followers_count: u32,
+    pub following_count: u32,
+    pub member_since: i64,
+    pub is_verified: bool,
+    pub open_to_collab: bool,
+    pub profile_visibility: ProfileVisibility,
+}
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
+/// Project fields supplied at creation, shared by `create_project`, `create_program_project`
+/// and `create_organization_project`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateProjectArgs {
+    pub name: String,
+    pub description: String,
+    pub github_link: String,
+    pub logo_ipfs_hash: String,
+    pub tech_stack: Vec<String>,
+    pub contribution_needs: Vec<String>,
+    pub collab_intent: String,
+    pub collaboration_level: CollaborationLevel,
+    pub project_status: ProjectStatus,
+    pub required_roles: Vec<RoleRequirement>,
+}
 
-    pub system_program: Program<'info, System>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum RequestStatus {
+    Pending,
+    UnderReview,
+    Accepted,
+    Rejected,
+    Spam,
+    Expired,     // Unanswered past the SLA deadline and compensated
+    Left,        // Accepted member left the project (left_at = when)
 }
 
-#[derive(Accounts)]
-pub struct UpdateProject<'info> {
-    #[account(
-        mut,
-        seeds = [b"project", creator.key().as_ref(), project.name.as_bytes()],
-        bump = project.bump,
-        has_one = creator
-    )]
-    pub project: Account<'info, Project>,
-    
-    #[account(mut)]
-    pub creator: Signer<'info>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ProfileVisibility {
+    Public,
+    Private,
+    FriendsOnly,
 }
 
-#[derive(Accounts)]
-pub struct SendCollabRequest<'info> {
-    #[account(
-        init,
-        payer = sender,
-        space = 8 + CollaborationRequest::INIT_SPACE,
-        seeds = [
-            b"collab_request",
-            sender.key().as_ref(),
-            project.key().as_ref()
-        ],
-        bump
-    )]
-    pub collab_request: Account<'info, CollaborationRequest>,
-    
-    #[account(mut)]
-    pub sender: Signer<'info>,
-    
-    pub project: Account<'info, Project>,
-    
-    pub system_program: Program<'info, System>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct OrgMember {
+    pub wallet: Pubkey,
+    pub role: OrgRole,
 }
 
-#[derive(Accounts)]
-pub struct UpdateCollabRequest<'info> {
-    #[account(
-        mut,
-        seeds = [
-            b"collab_request",
-            collab_request.from.as_ref(),
-            collab_request.project.as_ref()
-        ],
-        bump = collab_request.bump,
-        has_one = to
-    )]
-    pub collab_request: Account<'info, CollaborationRequest>,
-    
-    #[account(mut)]
-    pub project_owner: Signer<'info>,
-    
-    /// CHECK: This is the 'to' address that must match
-    pub to: AccountInfo<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"project", project.creator.as_ref(), project.name.as_bytes()],
-        bump = project.bump
-    )]
-    pub project: Account<'info, Project>,
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum OrgRole {
+    Admin,   // Manages the org and all of its projects
+    Member,
 }
 
-#[derive(Accounts)]
-pub struct DeleteUser<'info> {
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"user", signer.key().as_ref()],
-        bump = user.bump,
-        has_one = wallet
-    )]
-    pub user: Account<'info, User>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    
-    /// CHECK: This is the wallet that must match the user's wallet
-    pub wallet: AccountInfo<'info>,
+/// Sensitive project actions that need approver sign-off once an approver set exists
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ProposalAction {
+    AcceptRequest {
+        request: Pubkey,
+        #[max_len(500)]
+        owner_message: String,
+    },
+    DeleteProject,
+    UpdateProjectRoles {
+        #[max_len(8)]
+        role_requirements: Vec<RoleRequirement>,
+    },
+    TransferOwnership {
+        new_owner: Pubkey,
+    },
+    UpdateApprovers {
+        #[max_len(10)]
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    WithdrawDonations {
+        mint: Pubkey, // default = SOL
+        amount: u64,
+        recipient: Pubkey,
+    },
 }
 
-// ==================== ACCOUNT STRUCTURES ====================
+impl ProposalAction {
+    /// Same checks the direct instruction would apply
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::AcceptRequest { owner_message, .. } => {
+                require!(owner_message.len() <= 500, ErrorCode::MessageTooLong);
+            }
+            Self::DeleteProject | Self::TransferOwnership { .. } => {}
+            Self::UpdateProjectRoles { role_requirements } => {
+                require!(role_requirements.len() <= 8, ErrorCode::TooManyRoles);
+                validate_role_requirements(role_requirements)?;
+            }
+            Self::UpdateApprovers { approvers, threshold } => {
+                validate_approvers(approvers, *threshold)?;
+            }
+            Self::WithdrawDonations { amount, .. } => {
+                require!(*amount > 0, ErrorCode::InvalidDonationAmount);
+            }
+        }
+        Ok(())
+    }
+}
 
-#[account]
-#[derive(InitSpace)]
-pub struct User {
-    pub wallet: Pubkey,                      // 32 bytes
-    #[max_len(32)]
-    pub username: String,                    // 4 + 32 = 36 bytes
-    #[max_len(64)]
-    pub display_name: String,                // 4 + 64 = 68 bytes
-    #[max_len(50)]
-    pub role: String,                        // 4 + 50 = 54 bytes
-    #[max_len(50)]
-    pub location: String,                    // 4 + 50 = 54 bytes
-    #[max_len(200)]
-    pub bio: String,                         // 4 + 200 = 204 bytes
-    #[max_len(100)]
-    pub github_link: String,                 // 4 + 100 = 104 bytes
-    #[max_len(64)]
-    pub ipfs_metadata_hash: String,          // 4 + 64 = 68 bytes (link to IPFS for extended data)
-    #[max_len(200)]
-    pub contact_info: String,                // 4 + 200 = 204 bytes (WhatsApp, Discord, meeting link, etc.)
-    pub reputation: u32,                     // 4 bytes
-    pub projects_count: u32,                 // 4 bytes
-    pub collabs_count: u32,                  // 4 bytes
-    pub member_since: i64,                   // 8 bytes
-    pub last_active: i64,                    // 8 bytes
-    pub is_verified: bool,                   // 1 byte
-    pub open_to_collab: bool,                // 1 byte
-    pub profile_visibility: ProfileVisibility, // 1 byte
-    pub bump: u8,                            // 1 byte
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ConnectionStatus {
+    Pending,    // Requested, waiting for the other side
+    Connected,  // Accepted (or created from an accepted collaboration)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum CollaborationLevel {
+    Beginner,       // Just starting out
+    Intermediate,   // 1-2 years experience
+    Advanced,       // 3+ years experience
+    AllLevels,      // Open to all skill levels
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct ShortText {
+    #[max_len(24)]
+    pub value: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, InitSpace)]
+pub enum CollaborationAcceptance {
+    #[default]
+    Open,    // Accepting collaboration requests
+    Closed,  // No longer accepting requests
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ProjectStatus {
+    JustStarted,    // 0-25% complete
+    InProgress,     // 25-75% complete
+    NearlyComplete, // 75-95% complete
+    Completed,      // 100% complete, maintenance mode
+    ActiveDev,      // Ongoing active development
+    OnHold,         // Paused/inactive
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MilestoneStatus {
+    Open,
+    Completed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum TaskStatus {
+    Open,       // On the board
+    Claimed,    // A member is working on it
+    Submitted,  // Awaiting review
+    Done,       // Approved and credited
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BountyStatus {
+    Open,       // Funded, no assignee yet
+    Assigned,   // Assignee working on it
+    Submitted,  // Waiting on approval
+    Paid,       // Escrow paid to the assignee
+    Cancelled,  // Escrow refunded to the funder
+    Disputed,   // Escrow frozen until the dispute is ruled on or times out
+    Resolved,   // Escrow split by an arbiter ruling
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct RoundProject {
+    pub project: Pubkey,
+    pub eligible: bool,    // Set by the operator; only eligible projects take contributions
+    pub contributed: u64,  // Lamports contributed
+    pub contributors: u32,
+    pub sum_sqrt: u64,     // Sum of contributors' weighted square roots
+    pub matched: u64,      // Share of the matching pool, set at finalization
+    pub paid: bool,
+}
+
+impl RoundProject {
+    /// Quadratic-funding score: (sum of weighted square roots)^2
+    pub fn score(&self) -> u128 {
+        let sum = self.sum_sqrt as u128;
+        sum * sum
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct JudgeScore {
+    pub judge: Pubkey,
+    pub score: u8,  // 0..=MAX_JUDGE_SCORE
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum EventStatus {
+    Open,    // Registration until end_time, then judging
+    Ranked,  // Rankings final; prizes claimable
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum GrantRoundStatus {
+    Active,     // Registration and contributions
+    Finalized,  // Matches fixed; paying out
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct MintTotal {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DisputeKind {
+    Bounty,
+    Stream,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DisputeStatus {
+    Open,      // Escrow frozen, waiting on the arbiter
+    Resolved,  // Arbiter ruled and the escrow was split
+    TimedOut,  // No ruling in time; escrow back to its normal rules
+}
+
+// ==================== HELPERS ====================
+
+/// Whether `key` is off the ed25519 curve, i.e. a PDA no private key can sign for
+fn is_off_curve(key: &Pubkey) -> bool {
+    !validate_edwards(&PodEdwardsPoint(key.to_bytes()))
+}
+
+/// Grow a program account to `new_size`, topping up rent from `payer`
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_size: usize,
+) -> Result<()> {
+    let current_size = account.data_len();
+    if current_size >= new_size {
+        return Ok(());
+    }
+    account.resize(new_size)?;
+
+    let minimum_balance = Rent::get()?.minimum_balance(new_size);
+    let current_balance = account.lamports();
+    if current_balance < minimum_balance {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            minimum_balance - current_balance,
+        )?;
+    }
+
+    msg!("Account migrated from {} to {} bytes", current_size, new_size);
+    Ok(())
+}
+
+/// Validate `args` and fill a freshly initialized project account
+fn init_project(
+    project: &mut Project,
+    creator: Pubkey,
+    bump: u8,
+    args: CreateProjectArgs,
+    now: i64,
+) -> Result<()> {
+    let CreateProjectArgs {
+        name,
+        description,
+        github_link,
+        logo_ipfs_hash,
+        tech_stack,
+        contribution_needs,
+        collab_intent,
+        collaboration_level,
+        project_status,
+        required_roles,
+    } = args;
+
+    require!(name.len() <= 50, ErrorCode::NameTooLong);
+    require!(description.len() <= 1000, ErrorCode::DescriptionTooLong);
+    require!(github_link.len() <= 100, ErrorCode::GithubLinkTooLong);
+    require!(tech_stack.len() <= 12, ErrorCode::TechTagCountExceeded);
+    require!(contribution_needs.len() <= 10, ErrorCode::NeedTagCountExceeded);
+    require!(collab_intent.len() <= 300, ErrorCode::CollabIntentTooLong);
+    require!(required_roles.len() <= 8, ErrorCode::TooManyRoles);
+    for t in tech_stack.iter() { require!(t.len() <= 24, ErrorCode::TechTagTooLong); }
+    for n in contribution_needs.iter() { require!(n.len() <= 24, ErrorCode::NeedTagTooLong); }
+    for r in required_roles.iter() {
+        // Validate needed range
+        require!(r.needed > 0, ErrorCode::InvalidRoleCounts);
+        require!(r.needed <= 10, ErrorCode::RoleNeededTooLarge);
+        // If role is Others then a label is encouraged (optionally enforce non-empty)
+        if let Role::Others = r.role {
+            if let Some(lbl) = &r.label {
+                require!(lbl.len() <= 24, ErrorCode::RoleLabelTooLong);
+            }
+        }
+    }
+
+    project.creator = creator;
+    project.seed_creator = project.creator;
+    project.pending_owner = Pubkey::default();
+    project.approval_threshold = 0;
+    project.proposal_count = 0;
+    project.organization = Pubkey::default();
+    project.bounty_count = 0;
+    project.donations_raised = 0;
+    project.donor_count = 0;
+    project.team_size_cap = 0;
+    project.team_cap_ends_at = 0;
+    project.live_escrows = 0;
+    project.milestone_count = 0;
+    project.milestones_completed = 0;
+    project.completion_pct = 0;
+    project.status_from_milestones = false;
+    project.task_count = 0;
+    project.name = name;
+    project.description = description;
+    project.github_link = github_link;
+    project.logo_ipfs_hash = logo_ipfs_hash;
+    project.tech_stack = tech_stack.into_iter().map(|v| ShortText{ value: v }).collect();
+    project.contribution_needs = contribution_needs.into_iter().map(|v| ShortText{ value: v }).collect();
+    project.collab_intent = collab_intent;
+    project.collaboration_level = collaboration_level;
+    project.project_status = project_status;
+    project.accepting_collaborations = CollaborationAcceptance::Open; // Default: open for collaboration
+    project.timestamp = now;
+    project.last_updated = now;
+    project.contributors_count = 1; // Creator is first contributor
+    project.is_active = true;
+    project.stars_count = 0;
+    project.application_bond = 0;
+    project.response_window = 0;
+    project.avg_response_time = 0;
+    project.responses_count = 0;
+    project.sla_breach_count = 0;
+    project.applicant_requirements = ApplicantRequirements::default();
+    project.bump = bump;
+    // Normalize labels: keep labels only for Others, clear for fixed roles
+    project.required_roles = required_roles
+        .into_iter()
+        .map(|mut rr| {
+            match rr.role {
+                Role::Others => {
+                    // Keep label (already validated if present)
+                },
+                _ => {
+                    rr.label = None;
+                }
+            }
+            rr
+        })
+        .collect();
+    Ok(())
+}
+
+/// Check a milestone's assignees against the project's membership
+///
+/// Each assignee must be unique and either the creator or an accepted member, proven by
+/// their collaboration request (in `requests`, in order, skipping the creator)
+fn check_milestone_assignees(project: &Account<Project>, assignees: &[Pubkey], requests: &[AccountInfo]) -> Result<()> {
+    require!(assignees.len() <= MAX_MILESTONE_ASSIGNEES, ErrorCode::TooManyMilestoneAssignees);
+    let mut requests = requests.iter();
+    for (i, assignee) in assignees.iter().enumerate() {
+        require!(!assignees[..i].contains(assignee), ErrorCode::TooManyMilestoneAssignees);
+        if assignee == &project.creator {
+            continue;
+        }
+        let info = requests.next().ok_or(ErrorCode::NotAMember)?;
+        require!(info.owner == &crate::ID, ErrorCode::InvalidRequestAccount);
+        let request = CollaborationRequest::try_deserialize(&mut &info.data.borrow()[..])?;
+        require!(
+            request.from == *assignee
+                && request.project == project.key()
+                && request.status == RequestStatus::Accepted,
+            ErrorCode::NotAMember
+        );
+    }
+    Ok(())
+}
+
+/// Validate and fill a new bounty and advance the project's bounty index
+fn open_bounty(
+    project: &mut Account<Project>,
+    bounty: &mut Bounty,
+    funder: Pubkey,
+    mint: Pubkey,
+    title: String,
+    spec_ipfs_hash: String,
+    amount: u64,
+    deadline: i64,
+    now: i64,
+) -> Result<()> {
+    require!(title.len() <= 64, ErrorCode::BountyTitleTooLong);
+    require!(spec_ipfs_hash.len() <= 64, ErrorCode::IpfsHashTooLong);
+    require!(amount > 0, ErrorCode::InvalidBountyAmount);
+    require!(deadline > now, ErrorCode::InvalidBountyDeadline);
+
+    bounty.project = project.key();
+    bounty.funder = funder;
+    bounty.index = project.bounty_count;
+    bounty.title = title;
+    bounty.spec_ipfs_hash = spec_ipfs_hash;
+    bounty.amount = amount;
+    bounty.deadline = deadline;
+    bounty.assignee = Pubkey::default();
+    bounty.submission_ipfs_hash = String::new();
+    bounty.status = BountyStatus::Open;
+    bounty.created_at = now;
+    bounty.mint = mint;
+    bounty.lapsed_disputes = 0;
+    project.bounty_count = project.bounty_count.checked_add(1).ok_or(ErrorCode::BountyCountOverflow)?;
+    project.live_escrows = project.live_escrows.saturating_add(1);
+    Ok(())
+}
+
+/// Floor of the square root of `n`
+fn integer_sqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
+
+/// Weight (bps) of a contributor's square-root term in quadratic funding
+fn sybil_weight_bps(user: &User) -> u64 {
+    if user.is_verified {
+        return 10_000;
+    }
+    (UNVERIFIED_WEIGHT_BPS + user.reputation as u64 * REPUTATION_WEIGHT_BPS).min(10_000)
+}
+
+/// Update the donor's record (counting new donors), fill the optional receipt and emit the event
+fn record_donation(
+    project: &mut Account<Project>,
+    donor_record: &mut DonorRecord,
+    receipt: Option<&mut Donation>,
+    donor: Pubkey,
+    mint: Pubkey,
+    amount: u64,
+    memo: String,
+    donor_record_bump: u8,
+    receipt_bump: Option<u8>,
+) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidDonationAmount);
+    require!(memo.len() <= DONATION_MEMO_MAX_LEN, ErrorCode::DonationMemoTooLong);
+    let now = Clock::get()?.unix_timestamp;
+
+    if donor_record.donation_count == 0 {
+        donor_record.project = project.key();
+        donor_record.donor = donor;
+        donor_record.first_donated_at = now;
+        donor_record.bump = donor_record_bump;
+        project.donor_count = project.donor_count.saturating_add(1);
+    }
+    if let (Some(receipt), Some(bump)) = (receipt, receipt_bump) {
+        receipt.project = project.key();
+        receipt.donor = donor;
+        receipt.index = donor_record.donation_count;
+        receipt.mint = mint;
+        receipt.amount = amount;
+        receipt.memo = memo.clone();
+        receipt.donated_at = now;
+        receipt.bump = bump;
+    }
+    if mint == Pubkey::default() {
+        donor_record.lamports_donated = donor_record.lamports_donated.saturating_add(amount);
+    }
+    donor_record.donation_count = donor_record.donation_count.saturating_add(1);
+    donor_record.last_donated_at = now;
+
+    emit!(DonationReceived { project: project.key(), donor, mint, amount, memo });
+    Ok(())
 }
-// Total: ~860 bytes (well under 4KB!)
 
-#[account]
-#[derive(InitSpace)]
-pub struct Project {
-    pub creator: Pubkey,                        // 32 bytes
-    #[max_len(50)]
-    pub name: String,                           // 4 + 50 = 54 bytes
-    #[max_len(1000)]
-    pub description: String,                    // 4 + 1000 = 1004 bytes
-    #[max_len(100)]
-    pub github_link: String,                    // 4 + 100 = 104 bytes
-    #[max_len(64)]
-    pub logo_ipfs_hash: String,                 // 4 + 64 = 68 bytes (IPFS hash for project logo)
-    // Tech stack as tags (up to 12 tags, each up to 24 chars)
-    #[max_len(12)]
-    pub tech_stack: Vec<ShortText>,             // variable
-    // Contribution needs as tags (up to 10 tags, each up to 24 chars)
-    #[max_len(10)]
-    pub contribution_needs: Vec<ShortText>,     // variable
-    // Free text intent
-    #[max_len(300)]
-    pub collab_intent: String,                  // 4 + 300 = 304 bytes
-    pub collaboration_level: CollaborationLevel, // 1 byte (enum)
-    pub project_status: ProjectStatus,          // 1 byte (enum)
-    pub accepting_collaborations: CollaborationAcceptance, // 1 byte (open/closed)
-    pub timestamp: i64,                         // 8 bytes
-    pub last_updated: i64,                      // 8 bytes
-    pub contributors_count: u16,                // 2 bytes
-    pub is_active: bool,                        // 1 byte
-    pub bump: u8,                               // 1 byte
-    // Role-based contribution slots (max 8 roles)
-    #[max_len(8)]
-    pub required_roles: Vec<RoleRequirement>,   // 4 + (3 * 8) = 28 bytes max
+/// Pay `amount` lamports out of a donation vault, keeping the vault rent-exempt
+fn withdraw_sol_donations<'info>(
+    vault: &Account<'info, DonationVault>,
+    to: &SystemAccount<'info>,
+    amount: u64,
+) -> Result<()> {
+    let info = vault.to_account_info();
+    let available = info.lamports().saturating_sub(Rent::get()?.minimum_balance(info.data_len()));
+    require!(amount > 0 && amount <= available, ErrorCode::InsufficientDonations);
+    vault.sub_lamports(amount)?;
+    to.add_lamports(amount)?;
+    Ok(())
 }
-// Total: ~<= 4KB (well under limit with tagged vectors + roles)
 
-#[account]
-#[derive(InitSpace)]
-pub struct CollaborationRequest {
-    pub from: Pubkey,             // 32 bytes
-    pub to: Pubkey,               // 32 bytes
-    pub project: Pubkey,          // 32 bytes
-    #[max_len(500)]
-    pub message: String,          // 4 + 500 = 504 bytes (sender's message)
-    #[max_len(500)]
-    pub owner_message: String,    // 4 + 500 = 504 bytes (owner's reply message)
-    pub status: RequestStatus,    // 1 byte
-    pub timestamp: i64,           // 8 bytes (request sent timestamp)
-    pub reply_timestamp: i64,     // 8 bytes (owner reply timestamp)
-    pub bump: u8,                 // 1 byte
-    pub desired_role: Option<Role>, // 2 bytes (1 discriminant + 1 enum)
+/// Move donated tokens out of the vault's token account, signed by the vault PDA
+fn transfer_from_donation_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    vault_token: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    vault: &Account<'info, DonationVault>,
+    amount: u64,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"donation_vault", vault.project.as_ref(), &[vault.bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vault_token.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+        mint.decimals,
+    )
 }
-// Total: ~1132 bytes (well under 4KB)
 
-// ==================== ENUMS ====================
+/// Fill a new dispute between an escrow's funder and recipient, drawing its arbiter
+fn open_dispute(
+    dispute: &mut Dispute,
+    config: &mut Config,
+    escrow: Pubkey,
+    kind: DisputeKind,
+    project: Pubkey,
+    funder: Pubkey,
+    recipient: Pubkey,
+    claimant: Pubkey,
+    evidence_ipfs_hash: String,
+    amount: u64,
+    clock: &Clock,
+) -> Result<()> {
+    require!(evidence_ipfs_hash.len() <= 64, ErrorCode::IpfsHashTooLong);
+    let respondent = if claimant == funder {
+        recipient
+    } else if claimant == recipient {
+        funder
+    } else {
+        return err!(ErrorCode::NotADisputeParty);
+    };
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
-pub enum Role {
-    Frontend,
-    Backend,
-    Fullstack,
-    DevOps,
-    QA,
-    Designer,
-    Others,
+    dispute.escrow = escrow;
+    dispute.kind = kind;
+    dispute.project = project;
+    dispute.funder = funder;
+    dispute.recipient = recipient;
+    dispute.claimant = claimant;
+    dispute.respondent = respondent;
+    dispute.arbiter = config.draw_arbiter(&[funder, recipient])?;
+    dispute.claimant_evidence = evidence_ipfs_hash;
+    dispute.respondent_evidence = String::new();
+    dispute.amount = amount;
+    dispute.fee_bps = config.arbiter_fee_bps;
+    dispute.opened_at = clock.unix_timestamp;
+    dispute.deadline = clock.unix_timestamp + config.dispute_timeout;
+    dispute.status = DisputeStatus::Open;
+    dispute.recipient_bps = 0;
+    dispute.bounty_status = BountyStatus::Open;
+    Ok(())
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
-pub struct RoleRequirement {
-    pub role: Role,
-    pub needed: u8,
-    pub accepted: u8,
-    // Optional label for "Others" roles (max 24 chars)
-    #[max_len(24)]
-    pub label: Option<String>,
+/// Move `amount` out of a project's token vault, signed by its `vault_authority` PDA
+///
+/// Uses `transfer_checked`, so Token-2022 transfer fees are withheld from what `to` receives.
+fn transfer_from_project_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    vault_authority: &UncheckedAccount<'info>,
+    project: &Pubkey,
+    bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"vault_authority", project.as_ref(), &[bump]];
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: vault.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: vault_authority.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+        mint.decimals,
+    )
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
-pub enum RequestStatus {
-    Pending,
-    UnderReview,
-    Accepted,
-    Rejected,
+/// Eligibility rules shared by `send_collab_request` and `can_apply`
+fn check_application(
+    project: &Project,
+    applicant: &User,
+    desired_role: Option<&Role>,
+    now: i64,
+) -> Result<()> {
+    require!(
+        project.is_active && project.accepting_collaborations == CollaborationAcceptance::Open,
+        ErrorCode::ProjectNotAccepting
+    );
+    project.applicant_requirements.check(applicant, now)?;
+
+    // If roles are defined on the project and a role is specified, validate capacity
+    if let Some(role) = desired_role {
+        if !project.required_roles.is_empty() {
+            let role_req = project.required_roles.iter()
+                .find(|r| &r.role == role)
+                .ok_or(ErrorCode::RoleNotFound)?;
+            require!(role_req.accepted < role_req.needed, ErrorCode::RoleSlotFull);
+        }
+    }
+    Ok(())
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
-pub enum ProfileVisibility {
-    Public,
-    Private,
-    FriendsOnly,
+/// `name` as a PDA seed, refusing names over the seed limit before address derivation can fail
+fn name_seed(name: &str) -> Result<&[u8]> {
+    require!(name.len() <= SEED_NAME_MAX_LEN, ErrorCode::SeedNameTooLong);
+    Ok(name.as_bytes())
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
-pub enum CollaborationLevel {
-    Beginner,       // Just starting out
-    Intermediate,   // 1-2 years experience
-    Advanced,       // 3+ years experience
-    AllLevels,      // Open to all skill levels
+/// Amount backing an application to `project`; 0 when the project requires no bond
+///
+/// Bonded projects require the deposit to be made first (same transaction is fine).
+fn posted_application_bond(project: &Project, bond_info: &AccountInfo) -> Result<u64> {
+    if project.application_bond == 0 {
+        return Ok(0);
+    }
+    require!(
+        !bond_info.data_is_empty() && bond_info.owner == &crate::ID,
+        ErrorCode::ApplicationBondMissing
+    );
+    let bond = ApplicationBond::try_deserialize(&mut &bond_info.data.borrow()[..])?;
+    require!(bond.amount >= project.application_bond, ErrorCode::ApplicationBondMissing);
+    Ok(bond.amount)
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
-pub struct ShortText {
-    #[max_len(24)]
-    pub value: String,
+/// Accept `request`, filling its desired role slot on `project` and counting the sender's collab
+///
+/// A member counts once per project however often they leave and are re-accepted; `stats`
+/// is their MemberStats for the project, remembering that across deleted requests.
+fn apply_accept(
+    project: &mut Project,
+    request: &mut CollaborationRequest,
+    stats: &mut MemberStats,
+    stats_bump: u8,
+    sender_user: &AccountInfo,
+    owner_message: String,
+) -> Result<()> {
+    require!(owner_message.len() <= 500, ErrorCode::MessageTooLong);
+    require!(request.is_open(), ErrorCode::InvalidRequestStatus);
+
+    // Increment accepted count for the role if specified
+    if let Some(ref role) = request.desired_role {
+        if let Some(role_req) = project.required_roles.iter_mut().find(|r| &r.role == role) {
+            require!(role_req.accepted < role_req.needed, ErrorCode::RoleSlotFull);
+            role_req.accepted += 1;
+        }
+    }
+
+    request.status = RequestStatus::Accepted;
+    request.owner_message = owner_message;
+    request.reply_timestamp = Clock::get()?.unix_timestamp;
+    request.last_activity = request.reply_timestamp;
+    project.record_response(request.reply_timestamp - request.timestamp);
+    stats.open(request.project, request.from, stats_bump);
+    if !stats.collab_counted {
+        stats.collab_counted = true;
+        // The sender may have deleted their profile since applying
+        update_if_exists::<User>(sender_user, |u| {
+            u.collabs_count = u.collabs_count.saturating_add(1);
+        })?;
+    }
+    Ok(())
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
-pub enum CollaborationAcceptance {
-    Open,    // Accepting collaboration requests
-    Closed,  // No longer accepting requests
+fn validate_role_requirements(role_requirements: &[RoleRequirement]) -> Result<()> {
+    // Memory guard: limit number of roles to keep account size safe
+    require!(role_requirements.len() <= 12, ErrorCode::TooManyRoles);
+
+    // Validate each role requirement
+    for r in role_requirements.iter() {
+        // Cap needed to a sane upper bound (e.g., 10)
+        require!(r.needed <= 10, ErrorCode::RoleNeededTooLarge);
+        // accepted must be <= needed
+        require!(r.accepted <= r.needed, ErrorCode::InvalidRoleCounts);
+    }
+    Ok(())
 }
 
-impl Default for CollaborationAcceptance {
-    fn default() -> Self {
-        Self::Open
+/// Threshold 0 is only meaningful in an UpdateApprovers proposal (removes the set)
+fn validate_approvers(approvers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(approvers.len() <= MAX_APPROVERS, ErrorCode::TooManyApprovers);
+    for (i, a) in approvers.iter().enumerate() {
+        require!(!approvers[..i].contains(a), ErrorCode::DuplicateApprover);
     }
+    require!(
+        threshold as usize <= approvers.len(),
+        ErrorCode::InvalidApprovalThreshold
+    );
+    Ok(())
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
-pub enum ProjectStatus {
-    JustStarted,    // 0-25% complete
-    InProgress,     // 25-75% complete
-    NearlyComplete, // 75-95% complete
-    Completed,      // 100% complete, maintenance mode
-    ActiveDev,      // Ongoing active development
-    OnHold,         // Paused/inactive
+/// Apply `update` to a program account that may already have been closed (no-op if so)
+fn update_if_exists<T>(info: &AccountInfo, update: impl FnOnce(&mut T)) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Owner,
+{
+    if info.data_is_empty() || info.owner != &T::owner() {
+        return Ok(());
+    }
+    let mut data = info.try_borrow_mut_data()?;
+    let mut account = T::try_deserialize(&mut &data[..])?;
+    update(&mut account);
+    account.try_serialize(&mut &mut data[..])?;
+    Ok(())
+}
+
+/// Checks both delete paths share, then drop the project from its creator's project count
+fn retire_project(project: &Project, creator_user: &AccountInfo, program_creator: &AccountInfo) -> Result<()> {
+    // The org index entry would otherwise point at a closed project
+    project.check_not_in_organization()?;
+    // Escrows still paying out through this account would be stranded
+    require!(project.live_escrows == 0, ErrorCode::ProjectHasLiveEscrows);
+    update_if_exists::<User>(creator_user, |u| {
+        u.projects_count = u.projects_count.saturating_sub(1);
+    })?;
+    update_if_exists::<ProgramCreator>(program_creator, |c| {
+        c.projects_count = c.projects_count.saturating_sub(1);
+    })?;
+    Ok(())
+}
+
+/// Drop one of the project's live escrows once it no longer pays out through the project
+fn release_escrow(project: &mut Project) {
+    project.live_escrows = project.live_escrows.saturating_sub(1);
+}
+
+/// Close a program account that may not exist, returning the lamports moved to `destination`
+fn close_if_exists<'info>(info: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<u64> {
+    if info.data_is_empty() || info.owner != &crate::ID {
+        return Ok(0);
+    }
+    let lamports = info.lamports();
+    info.sub_lamports(lamports)?;
+    destination.add_lamports(lamports)?;
+    info.assign(&anchor_lang::system_program::ID);
+    info.resize(0)?;
+    Ok(lamports)
+}
+
+// ==================== EVENTS ====================
+
+#[event]
+pub struct DonationReceived {
+    pub project: Pubkey,
+    pub donor: Pubkey,
+    pub mint: Pubkey, // default = SOL
+    pub amount: u64,
+    pub memo: String,
+}
+
+#[event]
+pub struct DonationWithdrawn {
+    pub project: Pubkey,
+    pub mint: Pubkey, // default = SOL
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub proposal: Option<u64>, // index of the approving proposal; None = creator withdrawal
 }
 
 // ==================== ERROR CODES ====================
@@ -985,4 +8744,403 @@ pub enum ErrorCode {
 
     #[msg("Role label must be 24 characters or less")]
     RoleLabelTooLong,
+
+    #[msg("Only the request sender or the project owner can do this")]
+    NotRequestParty,
+
+    #[msg("Message must contain text or an IPFS hash")]
+    EmptyMessage,
+
+    #[msg("Request thread has reached its message limit")]
+    MessageCountOverflow,
+
+    #[msg("Collaboration request is still open")]
+    RequestStillOpen,
+
+    #[msg("Encryption key is not registered on the profile")]
+    EncryptionKeyNotRegistered,
+
+    #[msg("Sealed contact info must be 240 bytes or less")]
+    SealedContactTooLong,
+
+    #[msg("Contact key envelope must be 80 bytes or less")]
+    ContactEnvelopeTooLong,
+
+    #[msg("Contact key envelope is required")]
+    ContactEnvelopeRequired,
+
+    #[msg("Contact info must be stored sealed through set_sealed_contact_info")]
+    ContactInfoMustBeSealed,
+
+    #[msg("Contact access requires an accepted collaboration request between both parties")]
+    NotAcceptedCollaborator,
+
+    #[msg("Cannot connect to yourself")]
+    CannotConnectToSelf,
+
+    #[msg("Invalid connection status for this operation")]
+    InvalidConnectionStatus,
+
+    #[msg("Signer is not allowed to act on this connection")]
+    NotConnectionParty,
+
+    #[msg("Cannot follow yourself")]
+    CannotFollowSelf,
+
+    #[msg("Cannot block yourself")]
+    CannotBlockSelf,
+
+    #[msg("You have been blocked by this user")]
+    BlockedByUser,
+
+    #[msg("This project requires an application bond; deposit it first")]
+    ApplicationBondMissing,
+
+    #[msg("This project does not require an application bond")]
+    ApplicationBondNotRequired,
+
+    #[msg("Bonded requests can only be withdrawn within the grace period or after the review period")]
+    BondGracePeriodElapsed,
+
+    #[msg("Forfeited bond must go to the project owner or the incinerator")]
+    InvalidForfeitDestination,
+
+    #[msg("Response window must be positive")]
+    InvalidResponseWindow,
+
+    #[msg("SLA penalty must be positive and covered by the stake")]
+    InvalidSlaStake,
+
+    #[msg("The response SLA is being retired")]
+    SlaRetiring,
+
+    #[msg("The response SLA is still active or within its claim period")]
+    SlaStillActive,
+
+    #[msg("The response deadline has not passed")]
+    SlaNotBreached,
+
+    #[msg("Signer is not authorized for this action")]
+    Unauthorized,
+
+    #[msg("Too many collaboration requests today; try again later")]
+    RequestRateLimited,
+
+    #[msg("Too many projects created this week; try again later")]
+    ProjectRateLimited,
+
+    #[msg("Too many updates this hour; try again later")]
+    UpdateRateLimited,
+
+    #[msg("Applicant requirements are invalid")]
+    InvalidApplicantRequirements,
+
+    #[msg("Your profile is too new to apply to this project")]
+    ApplicantAccountTooNew,
+
+    #[msg("Your reputation is below this project's minimum")]
+    ApplicantReputationTooLow,
+
+    #[msg("This project only accepts requests from verified users")]
+    ApplicantNotVerified,
+
+    #[msg("You need more completed collaborations to apply to this project")]
+    ApplicantTooFewCollabs,
+
+    #[msg("The project creator cannot be a delegate")]
+    InvalidDelegate,
+
+    #[msg("Invalid permission flags")]
+    InvalidPermissions,
+
+    #[msg("Project is already owned by this user")]
+    InvalidTransferTarget,
+
+    #[msg("No matching ownership transfer is pending")]
+    NoPendingTransfer,
+
+    #[msg("Account is not an open collaboration request for this project")]
+    InvalidRequestAccount,
+
+    #[msg("This action requires an approved proposal")]
+    ApprovalRequired,
+
+    #[msg("Too many approvers (max 10)")]
+    TooManyApprovers,
+
+    #[msg("Approver listed more than once")]
+    DuplicateApprover,
+
+    #[msg("Approval threshold must be between 1 and the number of approvers")]
+    InvalidApprovalThreshold,
+
+    #[msg("Signer is not an approver for this project")]
+    NotAnApprover,
+
+    #[msg("Proposal lifetime must be between 1 second and 30 days")]
+    InvalidProposalTtl,
+
+    #[msg("Proposal has expired")]
+    ProposalExpired,
+
+    #[msg("Proposal already approved by this signer")]
+    AlreadyApproved,
+
+    #[msg("Proposal does not have enough approvals")]
+    NotEnoughApprovals,
+
+    #[msg("Proposal action does not match this instruction or its accounts")]
+    ProposalActionMismatch,
+
+    #[msg("Name is required")]
+    NameRequired,
+
+    #[msg("Organization account does not match the project")]
+    OrganizationMismatch,
+
+    #[msg("Signer is not an admin of this organization")]
+    NotAnOrgAdmin,
+
+    #[msg("Wallet is not a member of this organization")]
+    NotAnOrgMember,
+
+    #[msg("Too many organization members (max 20)")]
+    TooManyOrgMembers,
+
+    #[msg("An organization must keep at least one admin")]
+    LastOrgAdmin,
+
+    #[msg("Project already belongs to an organization")]
+    ProjectAlreadyInOrganization,
+
+    #[msg("Signer has neither a User profile nor a ProgramCreator record")]
+    ProfileRequired,
+
+    #[msg("Wallet is not a member of this project")]
+    NotAMember,
+
+    #[msg("Member does not hold the requested role")]
+    MemberRoleMismatch,
+
+    #[msg("Project is not accepting collaboration requests")]
+    ProjectNotAccepting,
+
+    #[msg("A request for this project was already sent")]
+    RequestAlreadySent,
+
+    #[msg("Bounty title exceeds maximum length of 64 characters")]
+    BountyTitleTooLong,
+
+    #[msg("Bounty amount must be greater than zero")]
+    InvalidBountyAmount,
+
+    #[msg("Bounty deadline must be in the future")]
+    InvalidBountyDeadline,
+
+    #[msg("Bounty count overflow")]
+    BountyCountOverflow,
+
+    #[msg("Invalid bounty status for this operation")]
+    InvalidBountyStatus,
+
+    #[msg("Bounty deadline has passed")]
+    BountyDeadlinePassed,
+
+    #[msg("Bounty deadline has not passed yet")]
+    BountyDeadlineNotReached,
+
+    #[msg("Maximum number of allowlisted mints reached")]
+    TooManyAllowedMints,
+
+    #[msg("Mint is not allowlisted for payments")]
+    MintNotAllowed,
+
+    #[msg("Bounty is paid in a different currency")]
+    BountyCurrencyMismatch,
+
+    #[msg("Token account is not owned by the expected wallet")]
+    TokenAccountOwnerMismatch,
+
+    #[msg("Stream needs start <= cliff <= end, start < end and a non-zero rate")]
+    InvalidStreamSchedule,
+
+    #[msg("Nothing to withdraw yet")]
+    NothingToWithdraw,
+
+    #[msg("Arbiter set is full")]
+    TooManyArbiters,
+
+    #[msg("Arbiter fee exceeds the cap or the ruling window is not positive")]
+    InvalidDisputeTerms,
+
+    #[msg("No arbiter outside the disputing parties is configured")]
+    NoArbiterAvailable,
+
+    #[msg("Only the escrow's funder and recipient are parties to a dispute")]
+    NotADisputeParty,
+
+    #[msg("Dispute is no longer open")]
+    DisputeNotOpen,
+
+    #[msg("Ruling window has passed; the dispute can only be expired")]
+    DisputeTimedOut,
+
+    #[msg("Ruling window has not passed yet")]
+    DisputeTimeoutNotReached,
+
+    #[msg("Ruling split must be at most 10000 basis points")]
+    InvalidDisputeSplit,
+
+    #[msg("Escrow is frozen by an open dispute")]
+    EscrowFrozen,
+
+    #[msg("Donation amount must be greater than zero")]
+    InvalidDonationAmount,
+
+    #[msg("Donation memo exceeds maximum length")]
+    DonationMemoTooLong,
+
+    #[msg("Not enough donations in the vault")]
+    InsufficientDonations,
+
+    #[msg("Donation vault already tracks the maximum number of mints")]
+    DonationMintLimit,
+
+    #[msg("Grant round needs registration_start < registration_end <= contribution_start < contribution_end")]
+    InvalidGrantSchedule,
+
+    #[msg("Amount must be greater than zero")]
+    InvalidGrantAmount,
+
+    #[msg("This grant round window is not open")]
+    GrantWindowClosed,
+
+    #[msg("Contributions to this grant round are still open")]
+    GrantWindowOpen,
+
+    #[msg("Project is already registered for this grant round")]
+    GrantProjectAlreadyRegistered,
+
+    #[msg("Grant round has reached its maximum number of projects")]
+    TooManyGrantProjects,
+
+    #[msg("Project is not registered or not eligible in this grant round")]
+    GrantProjectNotEligible,
+
+    #[msg("Invalid grant round status for this action")]
+    InvalidGrantRoundStatus,
+
+    #[msg("Payout accounts must be (project, project creator) pairs from this round")]
+    InvalidGrantPayoutAccounts,
+
+    #[msg("Some projects in this round have not been paid yet")]
+    GrantPayoutsPending,
+
+    #[msg("Event must start before it ends")]
+    InvalidEventSchedule,
+
+    #[msg("Team size cap must be at least 1")]
+    InvalidTeamSizeCap,
+
+    #[msg("Too many or duplicate judges")]
+    TooManyJudges,
+
+    #[msg("Events need 1-8 tracks of at most 24 characters, and a valid track index")]
+    InvalidEventTracks,
+
+    #[msg("Too many prize places or prize total overflows")]
+    InvalidEventPrizes,
+
+    #[msg("Event has ended")]
+    EventEnded,
+
+    #[msg("Event has not ended yet")]
+    EventNotEnded,
+
+    #[msg("Event has reached its maximum number of projects")]
+    EventFull,
+
+    #[msg("Project team would exceed its event team size cap")]
+    TeamSizeCapExceeded,
+
+    #[msg("Signer is not a judge of this event or judges their own project")]
+    NotAJudge,
+
+    #[msg("Score must be between 0 and 100")]
+    InvalidJudgeScore,
+
+    #[msg("Event rankings are already final")]
+    EventAlreadyRanked,
+
+    #[msg("Event rankings are not final yet")]
+    EventNotRanked,
+
+    #[msg("Rankings must list every entry of the event once, best average score first")]
+    InvalidEventRanking,
+
+    #[msg("Entry is already settled")]
+    NoEventPrize,
+
+    #[msg("Some entries have not been settled yet")]
+    EventPrizesPending,
+
+    #[msg("Milestone title must be 64 characters or less")]
+    MilestoneTitleTooLong,
+
+    #[msg("Milestone due date must be in the future")]
+    InvalidMilestoneDueDate,
+
+    #[msg("Milestones take at most 4 distinct assignees")]
+    TooManyMilestoneAssignees,
+
+    #[msg("Invalid milestone status for this action")]
+    InvalidMilestoneStatus,
+
+    #[msg("Project milestone count overflow")]
+    MilestoneCountOverflow,
+
+    #[msg("Task title must be 64 characters or less")]
+    TaskTitleTooLong,
+
+    #[msg("Task labels must be up to 4 distinct tags from the project's tech stack")]
+    InvalidTaskLabel,
+
+    #[msg("Invalid task status for this action")]
+    InvalidTaskStatus,
+
+    #[msg("Project task count overflow")]
+    TaskCountOverflow,
+
+    #[msg("Profile visibility does not allow sharing contact info with this user")]
+    ContactNotVisible,
+
+    #[msg("You have blocked this user")]
+    SenderBlocked,
+
+    #[msg("The claim period for this SLA breach has passed")]
+    SlaClaimPeriodElapsed,
+
+    #[msg("Rate limit state account is required for profile authorities")]
+    RateStateRequired,
+
+    #[msg("Remove the project from its organization first")]
+    ProjectInOrganization,
+
+    #[msg("Accepting a request needs the sender's MemberStats account")]
+    MemberStatsRequired,
+
+    #[msg("Project still holds unsettled bounties, an SLA bond, a donation vault or grant/event entries")]
+    ProjectHasLiveEscrows,
+
+    #[msg("Withdraw all SOL and token donations and pass every vault token account first")]
+    DonationsPending,
+
+    #[msg("Program creator authority must be a program-derived address")]
+    AuthorityOnCurve,
+
+    #[msg("Your earlier dispute on this escrow timed out")]
+    DisputeAlreadyLapsed,
+
+    #[msg("Name must be 32 bytes or less")]
+    SeedNameTooLong,
 }
@@ -0,0 +1,120 @@
+//! Applicant gates are met through accepted collaborations and approved work
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, ApplicantRequirements, Role};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signature::Keypair, signer::Signer as _};
+
+/// Have `member` complete one approved task on `project`
+async fn complete_task(ctx: &mut ProgramTestContext, owner: &Keypair, project: Pubkey, member: &Keypair) {
+    let task = pda(&[b"task", project.as_ref(), &0u32.to_le_bytes()]);
+    let create = devcol_ix(
+        accounts::CreateTask {
+            task,
+            project,
+            authority: owner.pubkey(),
+            project_role: None,
+            organization: None,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::CreateTask { title: "Docs".to_string(), labels: vec![], estimate: 2 },
+    );
+    send(ctx, &[create], &[owner]).await.unwrap();
+    let claim = devcol_ix(
+        accounts::ClaimTask {
+            task,
+            project,
+            member_request: request_pda(&member.pubkey(), &project),
+            member: member.pubkey(),
+        },
+        instruction::ClaimTask {},
+    );
+    let submit = devcol_ix(
+        accounts::WorkOnTask { task, assignee: member.pubkey() },
+        instruction::SubmitTask { submission_ipfs_hash: "QmDocs".to_string() },
+    );
+    send(ctx, &[claim, submit], &[member]).await.unwrap();
+    let approve = devcol_ix(
+        accounts::ApproveTask {
+            task,
+            project,
+            member_stats: pda(&[b"member_stats", project.as_ref(), member.pubkey().as_ref()]),
+            assignee_user: user_pda(&member.pubkey()),
+            authority: owner.pubkey(),
+            project_role: None,
+            organization: None,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::ApproveTask {},
+    );
+    send(ctx, &[approve], &[owner]).await.unwrap();
+}
+
+#[tokio::test]
+async fn experienced_applicant_passes_the_gates() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let starter = create_project(&mut ctx, &owner, "starter", vec![role_slot(Role::Frontend, 1)]).await;
+    let gated = create_project(&mut ctx, &owner, "gated", vec![role_slot(Role::Frontend, 2)]).await;
+    let gate = devcol_ix(
+        accounts::UpdateProject {
+            project: gated,
+            authority: owner.pubkey(),
+            project_role: None,
+            organization: None,
+            authority_user: Some(user_pda(&owner.pubkey())),
+            rate_state: Some(rate_state_pda(&owner.pubkey())),
+            program_creator: None,
+            config: config_pda(),
+        },
+        instruction::UpdateProject {
+            name: None,
+            description: None,
+            github_link: None,
+            tech_stack: None,
+            contribution_needs: None,
+            collab_intent: None,
+            collaboration_level: None,
+            project_status: None,
+            is_active: None,
+            application_bond: None,
+            applicant_requirements: Some(ApplicantRequirements {
+                min_account_age: 0,
+                min_reputation: 1,
+                verified_only: false,
+                min_collabs: 1,
+            }),
+        },
+    );
+    send(&mut ctx, &[gate], &[&owner]).await.unwrap();
+
+    let alice = create_user(&mut ctx, "alice").await;
+    let newcomer = create_user(&mut ctx, "newcomer").await;
+    let request = send_request(&mut ctx, &alice, starter, &owner.pubkey(), Some(Role::Frontend)).await;
+    accept_request(&mut ctx, &owner, starter, request).await;
+    complete_task(&mut ctx, &owner, starter, &alice).await;
+
+    let apply = |wallet: &Keypair| {
+        devcol_ix(
+            accounts::SendCollabRequest {
+                collab_request: request_pda(&wallet.pubkey(), &gated),
+                sender: wallet.pubkey(),
+                sender_user: user_pda(&wallet.pubkey()),
+                rate_state: rate_state_pda(&wallet.pubkey()),
+                config: config_pda(),
+                project: gated,
+                block: pda(&[b"block", owner.pubkey().as_ref(), wallet.pubkey().as_ref()]),
+                application_bond: pda(&[b"application_bond", wallet.pubkey().as_ref(), gated.as_ref()]),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::SendCollabRequest { message: "Hi".to_string(), desired_role: Some(Role::Frontend) },
+        )
+    };
+    // A fresh profile is turned away...
+    assert!(send(&mut ctx, &[apply(&newcomer)], &[&newcomer]).await.is_err());
+    // ...while one with an accepted collab and approved work gets through
+    send(&mut ctx, &[apply(&alice)], &[&alice]).await.unwrap();
+}
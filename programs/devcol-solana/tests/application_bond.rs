@@ -0,0 +1,170 @@
+//! Application bonds: deposit, withdrawal windows, reclaim and spam forfeits
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{
+    accounts, instruction, ApplicationBond, Role, BOND_REVIEW_PERIOD,
+    BOND_WITHDRAW_GRACE_PERIOD,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+const BOND: u64 = 5_000_000;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    owner: Keypair,
+    alice: Keypair,
+    project: Pubkey,
+    request: Pubkey,
+}
+
+impl Harness {
+    /// Bonded project with a pending request from alice
+    async fn start() -> Self {
+        let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+        let owner = create_user(&mut ctx, "owner").await;
+        let alice = create_user(&mut ctx, "alice").await;
+        let project = create_project(&mut ctx, &owner, "vault", vec![role_slot(Role::Backend, 1)]).await;
+        let require_bond = devcol_ix(
+            accounts::UpdateProject {
+                project,
+                authority: owner.pubkey(),
+                project_role: None,
+                organization: None,
+                authority_user: Some(user_pda(&owner.pubkey())),
+                rate_state: Some(rate_state_pda(&owner.pubkey())),
+                program_creator: None,
+                config: config_pda(),
+            },
+            instruction::UpdateProject {
+                name: None,
+                description: None,
+                github_link: None,
+                tech_stack: None,
+                contribution_needs: None,
+                collab_intent: None,
+                collaboration_level: None,
+                project_status: None,
+                is_active: None,
+                application_bond: Some(BOND),
+                applicant_requirements: None,
+            },
+        );
+        send(&mut ctx, &[require_bond], &[&owner]).await.unwrap();
+
+        let deposit = devcol_ix(
+            accounts::DepositApplicationBond {
+                application_bond: bond_pda(&alice.pubkey(), &project),
+                project,
+                applicant: alice.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::DepositApplicationBond {},
+        );
+        send(&mut ctx, &[deposit], &[&alice]).await.unwrap();
+        let request = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Backend)).await;
+        Self { ctx, owner, alice, project, request }
+    }
+
+    fn withdraw_ix(&self) -> Instruction {
+        devcol_ix(
+            accounts::WithdrawCollabRequest {
+                collab_request: self.request,
+                sender: self.alice.pubkey(),
+                project: self.project,
+            },
+            instruction::WithdrawCollabRequest {},
+        )
+    }
+
+    fn flag_ix(&self, destination: Pubkey, burn: bool) -> Instruction {
+        devcol_ix(
+            accounts::FlagRequestSpam {
+                collab_request: self.request,
+                project: self.project,
+                project_role: None,
+                organization: None,
+                application_bond: bond_pda(&self.alice.pubkey(), &self.project),
+                reviewer: self.owner.pubkey(),
+                applicant: self.alice.pubkey(),
+                forfeit_destination: destination,
+            },
+            instruction::FlagRequestSpam { burn },
+        )
+    }
+}
+
+fn bond_pda(applicant: &Pubkey, project: &Pubkey) -> Pubkey {
+    pda(&[b"application_bond", applicant.as_ref(), project.as_ref()])
+}
+
+#[tokio::test]
+async fn unanswered_request_can_be_withdrawn_after_review_period() {
+    let mut h = Harness::start().await;
+    let alice = h.alice.insecure_clone();
+    advance_clock(&mut h.ctx, BOND_WITHDRAW_GRACE_PERIOD + 1).await;
+    let withdraw = h.withdraw_ix();
+    assert!(send(&mut h.ctx, std::slice::from_ref(&withdraw), &[&alice]).await.is_err());
+
+    advance_clock(&mut h.ctx, BOND_REVIEW_PERIOD).await;
+    send(&mut h.ctx, &[withdraw], &[&alice]).await.unwrap();
+    let before = lamports(&mut h.ctx, alice.pubkey()).await;
+    let reclaim = devcol_ix(
+        accounts::ReclaimApplicationBond {
+            application_bond: bond_pda(&alice.pubkey(), &h.project),
+            collab_request: h.request,
+            applicant: alice.pubkey(),
+        },
+        instruction::ReclaimApplicationBond {},
+    );
+    send(&mut h.ctx, &[reclaim], &[&alice]).await.unwrap();
+    assert!(lamports(&mut h.ctx, alice.pubkey()).await > before + BOND);
+}
+
+#[tokio::test]
+async fn spam_bond_is_forfeited_to_the_project() {
+    let mut h = Harness::start().await;
+    let owner = h.owner.insecure_clone();
+    // The owner's wallet is no longer a valid destination
+    let to_owner = h.flag_ix(owner.pubkey(), false);
+    assert!(send(&mut h.ctx, &[to_owner], &[&owner]).await.is_err());
+
+    let before = lamports(&mut h.ctx, h.project).await;
+    let to_project = h.flag_ix(h.project, false);
+    send(&mut h.ctx, &[to_project], &[&owner]).await.unwrap();
+    assert_eq!(lamports(&mut h.ctx, h.project).await, before + BOND);
+    let bond = bond_pda(&h.alice.pubkey(), &h.project);
+    assert!(fetch::<ApplicationBond>(&mut h.ctx, bond).await.is_none());
+}
+
+#[tokio::test]
+async fn bond_is_reclaimable_after_leaving() {
+    let mut h = Harness::start().await;
+    let (owner, alice) = (h.owner.insecure_clone(), h.alice.insecure_clone());
+    accept_request(&mut h.ctx, &owner, h.project, h.request).await;
+    let leave = devcol_ix(
+        accounts::LeaveProject {
+            collab_request: h.request,
+            project: h.project,
+            member: alice.pubkey(),
+            stream: pda(&[b"stream", h.request.as_ref()]),
+        },
+        instruction::LeaveProject {},
+    );
+    send(&mut h.ctx, &[leave], &[&alice]).await.unwrap();
+
+    let before = lamports(&mut h.ctx, alice.pubkey()).await;
+    let reclaim = devcol_ix(
+        accounts::ReclaimApplicationBond {
+            application_bond: bond_pda(&alice.pubkey(), &h.project),
+            collab_request: h.request,
+            applicant: alice.pubkey(),
+        },
+        instruction::ReclaimApplicationBond {},
+    );
+    send(&mut h.ctx, &[reclaim], &[&alice]).await.unwrap();
+    assert!(lamports(&mut h.ctx, alice.pubkey()).await > before + BOND);
+}
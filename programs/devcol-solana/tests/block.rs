@@ -0,0 +1,123 @@
+//! Blocks are honoured by whoever reviews a request, not only the project owner
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, CollaborationRequest, ProjectRole, RequestStatus, Role};
+use solana_sdk::signer::Signer as _;
+
+#[tokio::test]
+async fn delegate_cannot_accept_a_sender_they_blocked() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let delegate = create_user(&mut ctx, "delegate").await;
+    let alice = create_user(&mut ctx, "alice").await;
+    let project = create_project(&mut ctx, &owner, "vault", vec![role_slot(Role::Backend, 1)]).await;
+    let delegate_role = pda(&[b"project_role", project.as_ref(), delegate.pubkey().as_ref()]);
+    let grant = devcol_ix(
+        accounts::GrantProjectRole {
+            project_role: delegate_role,
+            project,
+            authority: owner.pubkey(),
+            authority_role: None,
+            organization: None,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::GrantProjectRole {
+            delegate: delegate.pubkey(),
+            permissions: ProjectRole::REVIEW_REQUESTS,
+        },
+    );
+    send(&mut ctx, &[grant], &[&owner]).await.unwrap();
+
+    let request = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Backend)).await;
+    let block = pda(&[b"block", delegate.pubkey().as_ref(), alice.pubkey().as_ref()]);
+    let block_ix = devcol_ix(
+        accounts::BlockUser {
+            block,
+            blocker: delegate.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::BlockUser { blocked: alice.pubkey() },
+    );
+    send(&mut ctx, &[block_ix], &[&delegate]).await.unwrap();
+
+    let accept = devcol_ix(
+        accounts::UpdateCollabRequest {
+            collab_request: request,
+            reviewer: delegate.pubkey(),
+            project_role: Some(delegate_role),
+            organization: None,
+            project,
+            block,
+            owner_block: pda(&[b"block", owner.pubkey().as_ref(), alice.pubkey().as_ref()]),
+            sender_user: user_pda(&alice.pubkey()),
+            member_stats: Some(member_stats_pda(&project, &alice.pubkey())),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::AcceptCollabRequest { owner_message: "Welcome".to_string() },
+    );
+    assert!(send(&mut ctx, &[accept], &[&delegate]).await.is_err());
+
+    // The owner hasn't blocked alice and can still accept
+    accept_request(&mut ctx, &owner, project, request).await;
+    let state: CollaborationRequest = fetch(&mut ctx, request).await.unwrap();
+    assert!(state.status == RequestStatus::Accepted);
+}
+
+#[tokio::test]
+async fn owner_block_after_send_stops_a_delegate_accepting() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let delegate = create_user(&mut ctx, "delegate").await;
+    let alice = create_user(&mut ctx, "alice").await;
+    let project = create_project(&mut ctx, &owner, "vault", vec![role_slot(Role::Backend, 1)]).await;
+    let delegate_role = pda(&[b"project_role", project.as_ref(), delegate.pubkey().as_ref()]);
+    let grant = devcol_ix(
+        accounts::GrantProjectRole {
+            project_role: delegate_role,
+            project,
+            authority: owner.pubkey(),
+            authority_role: None,
+            organization: None,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::GrantProjectRole {
+            delegate: delegate.pubkey(),
+            permissions: ProjectRole::REVIEW_REQUESTS,
+        },
+    );
+    send(&mut ctx, &[grant], &[&owner]).await.unwrap();
+
+    let request = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Backend)).await;
+    let owner_block = pda(&[b"block", owner.pubkey().as_ref(), alice.pubkey().as_ref()]);
+    let block_ix = devcol_ix(
+        accounts::BlockUser {
+            block: owner_block,
+            blocker: owner.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::BlockUser { blocked: alice.pubkey() },
+    );
+    send(&mut ctx, &[block_ix], &[&owner]).await.unwrap();
+
+    let accept = devcol_ix(
+        accounts::UpdateCollabRequest {
+            collab_request: request,
+            reviewer: delegate.pubkey(),
+            project_role: Some(delegate_role),
+            organization: None,
+            project,
+            block: pda(&[b"block", delegate.pubkey().as_ref(), alice.pubkey().as_ref()]),
+            owner_block,
+            sender_user: user_pda(&alice.pubkey()),
+            member_stats: Some(member_stats_pda(&project, &alice.pubkey())),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::AcceptCollabRequest { owner_message: "Welcome".to_string() },
+    );
+    assert!(send(&mut ctx, &[accept], &[&delegate]).await.is_err());
+    let state: CollaborationRequest = fetch(&mut ctx, request).await.unwrap();
+    assert!(state.status == RequestStatus::Pending);
+}
@@ -0,0 +1,190 @@
+//! SOL bounty lifecycle
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, Bounty, BountyStatus, Project, Role};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signature::Keypair, signer::Signer as _};
+
+const AMOUNT: u64 = 250_000_000;
+const WEEK: i64 = 7 * 24 * 60 * 60;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    owner: Keypair,
+    project: Pubkey,
+    bounty: Pubkey,
+}
+
+impl Harness {
+    /// Project with one bounty posted by its owner
+    async fn start() -> Self {
+        let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+        let owner = create_user(&mut ctx, "owner").await;
+        let project = create_project(&mut ctx, &owner, "wallet-sdk", vec![role_slot(Role::Backend, 2)]).await;
+        let bounty = pda(&[b"bounty", project.as_ref(), &0u32.to_le_bytes()]);
+
+        let now = unix_timestamp(&mut ctx).await;
+        let ix = devcol_ix(
+            accounts::CreateBounty {
+                bounty,
+                project,
+                manager: owner.pubkey(),
+                project_role: None,
+                organization: None,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateBounty {
+                title: "Ledger support".to_string(),
+                spec_ipfs_hash: "QmSpec".to_string(),
+                amount: AMOUNT,
+                deadline: now + WEEK,
+            },
+        );
+        send(&mut ctx, &[ix], &[&owner]).await.unwrap();
+        Self { ctx, owner, project, bounty }
+    }
+
+    async fn member(&mut self, username: &str) -> Keypair {
+        let wallet = create_user(&mut self.ctx, username).await;
+        let owner = self.owner.pubkey();
+        let request = send_request(&mut self.ctx, &wallet, self.project, &owner, Some(Role::Backend)).await;
+        accept_request(&mut self.ctx, &self.owner, self.project, request).await;
+        wallet
+    }
+
+    async fn assign(&mut self, assignee: Pubkey) -> bool {
+        let ix = devcol_ix(
+            accounts::AssignBounty {
+                bounty: self.bounty,
+                project: self.project,
+                member_request: request_pda(&assignee, &self.project),
+                manager: self.owner.pubkey(),
+                project_role: None,
+                organization: None,
+            },
+            instruction::AssignBounty { assignee },
+        );
+        send(&mut self.ctx, &[ix], &[&self.owner]).await.is_ok()
+    }
+
+    async fn submit(&mut self, assignee: &Keypair) -> bool {
+        let ix = devcol_ix(
+            accounts::SubmitBounty { bounty: self.bounty, assignee: assignee.pubkey() },
+            instruction::SubmitBounty { submission_ipfs_hash: "QmWork".to_string() },
+        );
+        send(&mut self.ctx, &[ix], &[assignee]).await.is_ok()
+    }
+
+    async fn cancel(&mut self) -> bool {
+        let ix = devcol_ix(
+            accounts::CancelBounty {
+                bounty: self.bounty,
+                project: self.project,
+                funder: self.owner.pubkey(),
+                manager: self.owner.pubkey(),
+                project_role: None,
+                organization: None,
+            },
+            instruction::CancelBounty {},
+        );
+        send(&mut self.ctx, &[ix], &[&self.owner]).await.is_ok()
+    }
+
+    async fn bounty(&mut self) -> Option<Bounty> {
+        fetch(&mut self.ctx, self.bounty).await
+    }
+}
+
+#[tokio::test]
+async fn approved_submission_pays_assignee() {
+    let mut h = Harness::start().await;
+    let bounty_rent = lamports(&mut h.ctx, h.bounty).await - AMOUNT;
+    let alice = h.member("alice").await;
+
+    assert!(h.assign(alice.pubkey()).await);
+    assert!(h.submit(&alice).await);
+    assert!(h.bounty().await.unwrap().status == BountyStatus::Submitted);
+
+    let before = lamports(&mut h.ctx, alice.pubkey()).await;
+    let ix = devcol_ix(
+        accounts::ApproveBounty {
+            bounty: h.bounty,
+            project: h.project,
+            assignee: alice.pubkey(),
+            manager: h.owner.pubkey(),
+            project_role: None,
+            organization: None,
+        },
+        instruction::ApproveBounty {},
+    );
+    send(&mut h.ctx, &[ix], &[&h.owner]).await.unwrap();
+
+    assert_eq!(lamports(&mut h.ctx, alice.pubkey()).await, before + AMOUNT);
+    assert_eq!(lamports(&mut h.ctx, h.bounty).await, bounty_rent);
+    assert!(h.bounty().await.unwrap().status == BountyStatus::Paid);
+
+    // Settled bounties can be closed for their rent
+    let ix = devcol_ix(
+        accounts::ReclaimBounty { bounty: h.bounty, funder: h.owner.pubkey() },
+        instruction::ReclaimBounty {},
+    );
+    send(&mut h.ctx, &[ix], &[&h.owner]).await.unwrap();
+    assert!(h.bounty().await.is_none());
+}
+
+#[tokio::test]
+async fn only_accepted_members_are_assignable() {
+    let mut h = Harness::start().await;
+    let outsider = create_user(&mut h.ctx, "outsider").await;
+    assert!(!h.assign(outsider.pubkey()).await);
+
+    let owner = h.owner.pubkey();
+    let pending = create_user(&mut h.ctx, "pending").await;
+    send_request(&mut h.ctx, &pending, h.project, &owner, Some(Role::Backend)).await;
+    assert!(!h.assign(pending.pubkey()).await);
+
+    assert!(h.bounty().await.unwrap().status == BountyStatus::Open);
+}
+
+#[tokio::test]
+async fn cancel_refunds_only_after_missed_deadline() {
+    let mut h = Harness::start().await;
+    let alice = h.member("alice").await;
+    assert!(h.assign(alice.pubkey()).await);
+
+    assert!(!h.cancel().await);
+
+    advance_clock(&mut h.ctx, WEEK + 1).await;
+    assert!(!h.submit(&alice).await);
+
+    let before = lamports(&mut h.ctx, h.owner.pubkey()).await;
+    assert!(h.cancel().await);
+    assert_eq!(lamports(&mut h.ctx, h.owner.pubkey()).await, before + AMOUNT);
+    assert!(h.bounty().await.unwrap().status == BountyStatus::Cancelled);
+}
+
+#[tokio::test]
+async fn open_bounty_blocks_project_delete() {
+    let mut h = Harness::start().await;
+    let owner = h.owner.insecure_clone();
+    let delete = devcol_ix(
+        accounts::DeleteProject {
+            project: h.project,
+            creator: owner.pubkey(),
+            creator_user: user_pda(&owner.pubkey()),
+            program_creator: pda(&[b"program_creator", owner.pubkey().as_ref()]),
+        },
+        instruction::DeleteProject {},
+    );
+    assert!(send(&mut h.ctx, std::slice::from_ref(&delete), &[&owner]).await.is_err());
+    assert_eq!(fetch::<Project>(&mut h.ctx, h.project).await.unwrap().live_escrows, 1);
+
+    // The refund goes out through the project, after which nothing is left to strand
+    assert!(h.cancel().await);
+    refresh_blockhash(&mut h.ctx).await;
+    send(&mut h.ctx, &[delete], &[&owner]).await.unwrap();
+    assert!(fetch::<Project>(&mut h.ctx, h.project).await.is_none());
+}
@@ -0,0 +1,317 @@
+//! Shared harness for the in-process program tests
+#![allow(dead_code)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use devcol_solana::{
+    accounts, instruction, CollaborationLevel, CollaborationRequest, Config, ErrorCode, Project,
+    ProjectStatus, RateLimits, Role, RoleRequirement,
+};
+use solana_system_interface::instruction as system_instruction;
+use solana_program_test::{
+    processor, BanksClientError, ProgramTest, ProgramTestBanksClientExt, ProgramTestContext,
+};
+use solana_sdk::{
+    account::Account as SolanaAccount,
+    instruction::{Instruction, InstructionError},
+    signature::Keypair,
+    signer::Signer as _,
+    transaction::{Transaction, TransactionError},
+};
+
+/// Limits used by the injected config; generous enough not to interfere with tests
+pub const TEST_LIMITS: RateLimits = RateLimits {
+    requests_per_day: 10,
+    projects_per_week: 10,
+    updates_per_hour: 10,
+};
+
+/// Native entry point for devcol-solana
+///
+/// Anchor's `entry` ties the account slice and account lifetimes together, which the
+/// `processor!` signature can't express.
+pub fn devcol_entry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts: &[AccountInfo] = unsafe { std::mem::transmute(accounts) };
+    devcol_solana::entry(program_id, accounts, data)
+}
+
+/// Program test with devcol-solana loaded natively and a config account in place
+pub fn program_test(admin: &Pubkey) -> ProgramTest {
+    let mut pt = ProgramTest::new("devcol_solana", devcol_solana::ID, processor!(devcol_entry));
+    pt.prefer_bpf(false);
+    let (config, bump) = Pubkey::find_program_address(&[b"config"], &devcol_solana::ID);
+    add_anchor_account(
+        &mut pt,
+        config,
+        8 + Config::INIT_SPACE,
+        &Config {
+            admin: *admin,
+            limits: TEST_LIMITS,
+            verified_limits: TEST_LIMITS,
+            bump,
+            allowed_mints: vec![],
+            arbiters: vec![],
+            arbiter_fee_bps: 0,
+            dispute_timeout: devcol_solana::DEFAULT_DISPUTE_TIMEOUT,
+            disputes_opened: 0,
+        },
+    );
+    pt
+}
+
+/// Inject a pre-initialized account of `space` bytes owned by devcol-solana
+pub fn add_anchor_account<T: AccountSerialize + Discriminator>(
+    pt: &mut ProgramTest,
+    address: Pubkey,
+    space: usize,
+    account: &T,
+) {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data.resize(space, 0);
+    pt.add_account(
+        address,
+        SolanaAccount {
+            lamports: 1_000_000_000,
+            data,
+            owner: devcol_solana::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+}
+
+pub async fn send(
+    ctx: &mut ProgramTestContext,
+    ixs: &[Instruction],
+    signers: &[&Keypair],
+) -> std::result::Result<(), BanksClientError> {
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+    let mut all: Vec<&Keypair> = vec![&ctx.payer];
+    all.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(ixs, Some(&ctx.payer.pubkey()), &all, blockhash);
+    ctx.banks_client.process_transaction(tx).await
+}
+
+/// Whether `result` is a transaction that failed with the program's `error`
+pub fn failed_with(result: std::result::Result<(), BanksClientError>, error: ErrorCode) -> bool {
+    let expected = anchor_lang::error::ERROR_CODE_OFFSET + error as u32;
+    matches!(
+        result,
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        ))) if code == expected
+    )
+}
+
+/// Simulate `ix` and decode the value it returns through `set_return_data`
+pub async fn simulate_return<T: AnchorDeserialize>(ctx: &mut ProgramTestContext, ix: Instruction) -> T {
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&ctx.payer.pubkey()), &[&ctx.payer], blockhash);
+    let simulation = ctx.banks_client.simulate_transaction(tx).await.unwrap();
+    simulation.result.unwrap().unwrap();
+    let return_data = simulation.simulation_details.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, devcol_solana::ID);
+    T::try_from_slice(&return_data.data).unwrap()
+}
+
+pub async fn fetch<T: AccountDeserialize>(ctx: &mut ProgramTestContext, address: Pubkey) -> Option<T> {
+    let account = ctx.banks_client.get_account(address).await.unwrap()?;
+    Some(T::try_deserialize(&mut account.data.as_slice()).unwrap())
+}
+
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &devcol_solana::ID).0
+}
+
+pub fn config_pda() -> Pubkey {
+    pda(&[b"config"])
+}
+
+pub fn user_pda(wallet: &Pubkey) -> Pubkey {
+    pda(&[b"user", wallet.as_ref()])
+}
+
+pub fn project_pda(creator: &Pubkey, name: &str) -> Pubkey {
+    pda(&[b"project", creator.as_ref(), name.as_bytes()])
+}
+
+pub fn rate_state_pda(wallet: &Pubkey) -> Pubkey {
+    pda(&[b"rate_state", wallet.as_ref()])
+}
+
+pub fn request_pda(sender: &Pubkey, project: &Pubkey) -> Pubkey {
+    pda(&[b"collab_request", sender.as_ref(), project.as_ref()])
+}
+
+pub fn member_stats_pda(project: &Pubkey, member: &Pubkey) -> Pubkey {
+    pda(&[b"member_stats", project.as_ref(), member.as_ref()])
+}
+
+pub fn devcol_ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: devcol_solana::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// New wallet funded by the test payer
+pub async fn funded_wallet(ctx: &mut ProgramTestContext, lamports: u64) -> Keypair {
+    let wallet = Keypair::new();
+    let ix = system_instruction::transfer(&ctx.payer.pubkey(), &wallet.pubkey(), lamports);
+    send(ctx, &[ix], &[]).await.unwrap();
+    wallet
+}
+
+/// Funded wallet with a User profile
+pub async fn create_user(ctx: &mut ProgramTestContext, username: &str) -> Keypair {
+    let wallet = funded_wallet(ctx, 1_000_000_000).await;
+    create_profile(ctx, &wallet, username).await;
+    wallet
+}
+
+/// User profile for an existing wallet
+pub async fn create_profile(ctx: &mut ProgramTestContext, wallet: &Keypair, username: &str) {
+    let ix = devcol_ix(
+        accounts::CreateUser {
+            user: user_pda(&wallet.pubkey()),
+            rate_state: rate_state_pda(&wallet.pubkey()),
+            signer: wallet.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::CreateUser {
+            username: username.to_string(),
+            display_name: username.to_string(),
+            role: "developer".to_string(),
+            location: String::new(),
+            bio: String::new(),
+            github_link: String::new(),
+            ipfs_metadata_hash: String::new(),
+            contact_info: String::new(),
+        },
+    );
+    send(ctx, &[ix], &[wallet]).await.unwrap();
+}
+
+pub async fn create_project(
+    ctx: &mut ProgramTestContext,
+    creator: &Keypair,
+    name: &str,
+    required_roles: Vec<RoleRequirement>,
+) -> Pubkey {
+    let project = project_pda(&creator.pubkey(), name);
+    let ix = devcol_ix(
+        accounts::CreateProject {
+            project,
+            user: user_pda(&creator.pubkey()),
+            rate_state: rate_state_pda(&creator.pubkey()),
+            config: config_pda(),
+            creator: creator.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::CreateProject {
+            name: name.to_string(),
+            description: String::new(),
+            github_link: String::new(),
+            logo_ipfs_hash: String::new(),
+            tech_stack: vec![],
+            contribution_needs: vec![],
+            collab_intent: String::new(),
+            collaboration_level: CollaborationLevel::AllLevels,
+            project_status: ProjectStatus::InProgress,
+            required_roles,
+        },
+    );
+    send(ctx, &[ix], &[creator]).await.unwrap();
+    project
+}
+
+pub fn role_slot(role: Role, needed: u8) -> RoleRequirement {
+    RoleRequirement { role, needed, accepted: 0, label: None }
+}
+
+pub async fn send_request(
+    ctx: &mut ProgramTestContext,
+    sender: &Keypair,
+    project: Pubkey,
+    owner: &Pubkey,
+    desired_role: Option<Role>,
+) -> Pubkey {
+    let request = request_pda(&sender.pubkey(), &project);
+    let ix = devcol_ix(
+        accounts::SendCollabRequest {
+            collab_request: request,
+            sender: sender.pubkey(),
+            sender_user: user_pda(&sender.pubkey()),
+            rate_state: rate_state_pda(&sender.pubkey()),
+            config: config_pda(),
+            project,
+            block: pda(&[b"block", owner.as_ref(), sender.pubkey().as_ref()]),
+            application_bond: pda(&[b"application_bond", sender.pubkey().as_ref(), project.as_ref()]),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::SendCollabRequest {
+            message: "I'd like to help".to_string(),
+            desired_role,
+        },
+    );
+    send(ctx, &[ix], &[sender]).await.unwrap();
+    request
+}
+
+pub async fn accept_request(
+    ctx: &mut ProgramTestContext,
+    owner: &Keypair,
+    project: Pubkey,
+    request: Pubkey,
+) {
+    let sender = fetch::<CollaborationRequest>(ctx, request).await.unwrap().from;
+    let creator = fetch::<Project>(ctx, project).await.unwrap().creator;
+    let ix = devcol_ix(
+        accounts::UpdateCollabRequest {
+            collab_request: request,
+            reviewer: owner.pubkey(),
+            project_role: None,
+            organization: None,
+            project,
+            block: pda(&[b"block", owner.pubkey().as_ref(), sender.as_ref()]),
+            owner_block: pda(&[b"block", creator.as_ref(), sender.as_ref()]),
+            sender_user: user_pda(&sender),
+            member_stats: Some(member_stats_pda(&project, &sender)),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::AcceptCollabRequest { owner_message: "Welcome".to_string() },
+    );
+    send(ctx, &[ix], &[owner]).await.unwrap();
+}
+
+pub async fn unix_timestamp(ctx: &mut ProgramTestContext) -> i64 {
+    ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp
+}
+
+/// Move the bank clock forward by `seconds`
+pub async fn advance_clock(ctx: &mut ProgramTestContext, seconds: i64) {
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += seconds;
+    ctx.set_sysvar(&clock);
+    refresh_blockhash(ctx).await;
+}
+
+/// Wait for a new blockhash so a retried instruction isn't answered from the status cache
+/// with its earlier result
+pub async fn refresh_blockhash(ctx: &mut ProgramTestContext) {
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+    ctx.banks_client.get_new_latest_blockhash(&blockhash).await.unwrap();
+}
+
+pub async fn lamports(ctx: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    ctx.banks_client.get_balance(address).await.unwrap()
+}
@@ -0,0 +1,150 @@
+//! Contact envelopes respect the owner's profile visibility
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{
+    accounts, instruction, Connection, ContactEnvelope, ProfileVisibility, Role, User,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+fn update_user_ix(wallet: &Keypair, data: impl anchor_lang::InstructionData) -> Instruction {
+    devcol_ix(
+        accounts::UpdateUser {
+            user: user_pda(&wallet.pubkey()),
+            signer: wallet.pubkey(),
+            wallet: wallet.pubkey(),
+            rate_state: rate_state_pda(&wallet.pubkey()),
+            config: config_pda(),
+        },
+        data,
+    )
+}
+
+async fn set_visibility(ctx: &mut ProgramTestContext, wallet: &Keypair, visibility: ProfileVisibility) {
+    let ix = update_user_ix(
+        wallet,
+        instruction::UpdateUser {
+            display_name: None,
+            role: None,
+            location: None,
+            bio: None,
+            github_link: None,
+            ipfs_metadata_hash: None,
+            contact_info: None,
+            open_to_collab: None,
+            profile_visibility: Some(visibility),
+        },
+    );
+    send(ctx, &[ix], &[wallet]).await.unwrap();
+}
+
+#[tokio::test]
+async fn envelopes_follow_profile_visibility() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let project = create_project(&mut ctx, &owner, "vault", vec![role_slot(Role::Backend, 1)]).await;
+    let alice = create_user(&mut ctx, "alice").await;
+    let request = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Backend)).await;
+    accept_request(&mut ctx, &owner, project, request).await;
+    for (wallet, key) in [(&owner, [1u8; 32]), (&alice, [2u8; 32])] {
+        let ix = update_user_ix(
+            wallet,
+            instruction::SetSealedContactInfo {
+                encryption_pubkey: key,
+                sealed_contact_info: vec![7; 40],
+                contact_key_envelope: vec![9; 48],
+            },
+        );
+        send(&mut ctx, &[ix], &[wallet]).await.unwrap();
+    }
+
+    let (user_a, user_b) = Connection::ordered(owner.pubkey(), alice.pubkey());
+    let connection = pda(&[b"connection", user_a.as_ref(), user_b.as_ref()]);
+    let envelope = pda(&[b"contact_envelope", owner.pubkey().as_ref(), alice.pubkey().as_ref()]);
+    let grant = |connection: Option<Pubkey>| {
+        devcol_ix(
+            accounts::GrantContactAccess {
+                contact_envelope: envelope,
+                owner_user: user_pda(&owner.pubkey()),
+                recipient_user: user_pda(&alice.pubkey()),
+                collab_request: request,
+                connection,
+                owner: owner.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::GrantContactAccess { sealed_key: vec![3; 48] },
+        )
+    };
+
+    // FriendsOnly needs a connection, Private shares with no one
+    set_visibility(&mut ctx, &owner, ProfileVisibility::FriendsOnly).await;
+    assert!(send(&mut ctx, &[grant(None)], &[&owner]).await.is_err());
+    let connect = devcol_ix(
+        accounts::ConnectViaCollaboration {
+            connection,
+            collab_request: request,
+            signer: alice.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::ConnectViaCollaboration {},
+    );
+    send(&mut ctx, &[connect], &[&alice]).await.unwrap();
+    set_visibility(&mut ctx, &owner, ProfileVisibility::Private).await;
+    assert!(send(&mut ctx, &[grant(Some(connection))], &[&owner]).await.is_err());
+
+    set_visibility(&mut ctx, &owner, ProfileVisibility::FriendsOnly).await;
+    refresh_blockhash(&mut ctx).await;
+    send(&mut ctx, &[grant(Some(connection))], &[&owner]).await.unwrap();
+    let state: ContactEnvelope = fetch(&mut ctx, envelope).await.unwrap();
+    assert_eq!(state.recipient, alice.pubkey());
+}
+
+#[tokio::test]
+async fn plaintext_contact_info_is_refused() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let wallet = funded_wallet(&mut ctx, 1_000_000_000).await;
+    let create = |contact_info: &str| {
+        devcol_ix(
+            accounts::CreateUser {
+                user: user_pda(&wallet.pubkey()),
+                rate_state: rate_state_pda(&wallet.pubkey()),
+                signer: wallet.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateUser {
+                username: "alice".to_string(),
+                display_name: "alice".to_string(),
+                role: "developer".to_string(),
+                location: String::new(),
+                bio: String::new(),
+                github_link: String::new(),
+                ipfs_metadata_hash: String::new(),
+                contact_info: contact_info.to_string(),
+            },
+        )
+    };
+    assert!(send(&mut ctx, &[create("discord: alice#0001")], &[&wallet]).await.is_err());
+    send(&mut ctx, &[create("")], &[&wallet]).await.unwrap();
+
+    // Without a registered key the profile still refuses plaintext
+    let update = update_user_ix(
+        &wallet,
+        instruction::UpdateUser {
+            display_name: None,
+            role: None,
+            location: None,
+            bio: None,
+            github_link: None,
+            ipfs_metadata_hash: None,
+            contact_info: Some("discord: alice#0001".to_string()),
+            open_to_collab: None,
+            profile_visibility: None,
+        },
+    );
+    assert!(send(&mut ctx, &[update], &[&wallet]).await.is_err());
+    let user: User = fetch(&mut ctx, user_pda(&wallet.pubkey())).await.unwrap();
+    assert!(user.contact_info.is_empty());
+}
@@ -0,0 +1,362 @@
+//! Disputes freezing bounty and stream escrows, arbiter rulings and the timeout fallback
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{
+    accounts, instruction, Bounty, BountyStatus, Config, Dispute, Role, Stream,
+    DEFAULT_DISPUTE_TIMEOUT,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signature::Keypair, signer::Signer as _};
+
+const AMOUNT: u64 = 200_000_000;
+const FEE_BPS: u16 = 500;
+const DAY: i64 = 24 * 60 * 60;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    admin: Keypair,
+    arbiter: Keypair,
+    owner: Keypair,
+    member: Keypair,
+    project: Pubkey,
+    request: Pubkey,
+}
+
+impl Harness {
+    /// Project with one accepted member and a single arbiter charging `FEE_BPS`
+    async fn start() -> Self {
+        let admin = Keypair::new();
+        let mut ctx = program_test(&admin.pubkey()).start_with_context().await;
+        let arbiter = funded_wallet(&mut ctx, 10_000_000).await;
+        let config_ixs = [
+            devcol_ix(
+                accounts::UpdateConfig { config: config_pda(), admin: admin.pubkey() },
+                instruction::SetArbiter { arbiter: arbiter.pubkey(), allowed: true },
+            ),
+            devcol_ix(
+                accounts::UpdateConfig { config: config_pda(), admin: admin.pubkey() },
+                instruction::SetDisputeTerms { arbiter_fee_bps: FEE_BPS, dispute_timeout: DEFAULT_DISPUTE_TIMEOUT },
+            ),
+        ];
+        send(&mut ctx, &config_ixs, &[&admin]).await.unwrap();
+
+        let owner = create_user(&mut ctx, "owner").await;
+        let project = create_project(&mut ctx, &owner, "bridge", vec![role_slot(Role::Backend, 1)]).await;
+        let member = create_user(&mut ctx, "member").await;
+        let request = send_request(&mut ctx, &member, project, &owner.pubkey(), Some(Role::Backend)).await;
+        accept_request(&mut ctx, &owner, project, request).await;
+        Self { ctx, admin, arbiter, owner, member, project, request }
+    }
+
+    /// Bounty assigned to the member, who submits work
+    async fn submitted_bounty(&mut self) -> Pubkey {
+        let bounty = pda(&[b"bounty", self.project.as_ref(), &0u32.to_le_bytes()]);
+        let owner = self.owner.pubkey();
+        let member = self.member.pubkey();
+        let deadline = unix_timestamp(&mut self.ctx).await + 7 * DAY;
+        let ixs = [
+            devcol_ix(
+                accounts::CreateBounty {
+                    bounty,
+                    project: self.project,
+                    manager: owner,
+                    project_role: None,
+                    organization: None,
+                    system_program: anchor_lang::system_program::ID,
+                },
+                instruction::CreateBounty {
+                    title: "Relayer".to_string(),
+                    spec_ipfs_hash: "QmSpec".to_string(),
+                    amount: AMOUNT,
+                    deadline,
+                },
+            ),
+            devcol_ix(
+                accounts::AssignBounty {
+                    bounty,
+                    project: self.project,
+                    member_request: self.request,
+                    manager: owner,
+                    project_role: None,
+                    organization: None,
+                },
+                instruction::AssignBounty { assignee: member },
+            ),
+            devcol_ix(
+                accounts::SubmitBounty { bounty, assignee: member },
+                instruction::SubmitBounty { submission_ipfs_hash: "QmWork".to_string() },
+            ),
+        ];
+        let (owner_kp, member_kp) = (self.owner.insecure_clone(), self.member.insecure_clone());
+        send(&mut self.ctx, &ixs, &[&owner_kp, &member_kp]).await.unwrap();
+        bounty
+    }
+
+    fn approve_ix(&self, bounty: Pubkey) -> solana_sdk::instruction::Instruction {
+        devcol_ix(
+            accounts::ApproveBounty {
+                bounty,
+                project: self.project,
+                assignee: self.member.pubkey(),
+                manager: self.owner.pubkey(),
+                project_role: None,
+                organization: None,
+            },
+            instruction::ApproveBounty {},
+        )
+    }
+}
+
+#[tokio::test]
+async fn arbiter_splits_frozen_bounty() {
+    let mut h = Harness::start().await;
+    let bounty = h.submitted_bounty().await;
+    let dispute = pda(&[b"dispute", bounty.as_ref()]);
+
+    // Outsiders can't open a dispute
+    let outsider = create_user(&mut h.ctx, "outsider").await;
+    let open = |claimant: Pubkey| {
+        devcol_ix(
+            accounts::OpenBountyDispute {
+                dispute,
+                bounty,
+                config: config_pda(),
+                claimant,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::OpenBountyDispute { evidence_ipfs_hash: "QmEvidence".to_string() },
+        )
+    };
+    assert!(send(&mut h.ctx, &[open(outsider.pubkey())], &[&outsider]).await.is_err());
+
+    let member = h.member.insecure_clone();
+    send(&mut h.ctx, &[open(member.pubkey())], &[&member]).await.unwrap();
+    let state: Dispute = fetch(&mut h.ctx, dispute).await.unwrap();
+    assert_eq!(state.arbiter, h.arbiter.pubkey());
+    assert_eq!(state.respondent, h.owner.pubkey());
+    assert!(fetch::<Bounty>(&mut h.ctx, bounty).await.unwrap().status == BountyStatus::Disputed);
+
+    // Frozen: the funder can no longer approve (or otherwise move the escrow)
+    let approve = h.approve_ix(bounty);
+    let owner = h.owner.insecure_clone();
+    assert!(send(&mut h.ctx, &[approve], &[&owner]).await.is_err());
+
+    let respond = devcol_ix(
+        accounts::RespondToDispute { dispute, respondent: owner.pubkey() },
+        instruction::RespondToDispute { evidence_ipfs_hash: "QmRebuttal".to_string() },
+    );
+    send(&mut h.ctx, &[respond], &[&owner]).await.unwrap();
+    let state: Dispute = fetch(&mut h.ctx, dispute).await.unwrap();
+    assert_eq!(state.respondent_evidence, "QmRebuttal");
+
+    let resolve = |arbiter: Pubkey| {
+        devcol_ix(
+            accounts::ResolveBountyDispute {
+                dispute,
+                bounty,
+                project: h.project,
+                funder: h.owner.pubkey(),
+                assignee: h.member.pubkey(),
+                arbiter,
+                claimant: h.member.pubkey(),
+            },
+            instruction::ResolveBountyDispute { recipient_bps: 7_500 },
+        )
+    };
+    // Only the drawn arbiter rules
+    assert!(send(&mut h.ctx, &[resolve(owner.pubkey())], &[&owner]).await.is_err());
+
+    let rent = lamports(&mut h.ctx, dispute).await;
+    let before = [
+        lamports(&mut h.ctx, h.arbiter.pubkey()).await,
+        lamports(&mut h.ctx, h.member.pubkey()).await,
+        lamports(&mut h.ctx, h.owner.pubkey()).await,
+    ];
+    let arbiter = h.arbiter.insecure_clone();
+    send(&mut h.ctx, &[resolve(arbiter.pubkey())], &[&arbiter]).await.unwrap();
+
+    let fee = AMOUNT * FEE_BPS as u64 / 10_000;
+    let to_member = (AMOUNT - fee) * 3 / 4;
+    assert_eq!(lamports(&mut h.ctx, h.arbiter.pubkey()).await, before[0] + fee);
+    // The claimant gets the dispute's rent back with their share
+    assert_eq!(lamports(&mut h.ctx, h.member.pubkey()).await, before[1] + to_member + rent);
+    assert_eq!(lamports(&mut h.ctx, h.owner.pubkey()).await, before[2] + AMOUNT - fee - to_member);
+    assert!(fetch::<Bounty>(&mut h.ctx, bounty).await.unwrap().status == BountyStatus::Resolved);
+    assert!(fetch::<Dispute>(&mut h.ctx, dispute).await.is_none());
+}
+
+#[tokio::test]
+async fn unruled_stream_dispute_times_out() {
+    let mut h = Harness::start().await;
+    let stream = pda(&[b"stream", h.request.as_ref()]);
+    let start = unix_timestamp(&mut h.ctx).await;
+    let create = devcol_ix(
+        accounts::CreateStream {
+            stream,
+            collab_request: h.request,
+            project: h.project,
+            funder: h.owner.pubkey(),
+            project_role: None,
+            organization: None,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::CreateStream { start, cliff: start, end: start + 30 * DAY, rate: 10 },
+    );
+    let owner = h.owner.insecure_clone();
+    send(&mut h.ctx, &[create], &[&owner]).await.unwrap();
+
+    let dispute = pda(&[b"dispute", stream.as_ref()]);
+    let open = || {
+        devcol_ix(
+            accounts::OpenStreamDispute {
+                dispute,
+                stream,
+                config: config_pda(),
+                claimant: owner.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::OpenStreamDispute { evidence_ipfs_hash: "QmNoShow".to_string() },
+        )
+    };
+    send(&mut h.ctx, &[open()], &[&owner]).await.unwrap();
+    assert!(fetch::<Stream>(&mut h.ctx, stream).await.unwrap().frozen);
+
+    let withdraw = || {
+        devcol_ix(
+            accounts::WithdrawStream { stream, collab_request: h.request, recipient: h.member.pubkey() },
+            instruction::WithdrawStream {},
+        )
+    };
+    let member = h.member.insecure_clone();
+    advance_clock(&mut h.ctx, DAY).await;
+    assert!(send(&mut h.ctx, &[withdraw()], &[&member]).await.is_err());
+
+    let expire = || {
+        devcol_ix(
+            accounts::ExpireStreamDispute { dispute, stream, claimant: owner.pubkey() },
+            instruction::ExpireStreamDispute {},
+        )
+    };
+    assert!(send(&mut h.ctx, &[expire()], &[]).await.is_err());
+
+    // Past the window the arbiter can no longer rule; anyone can unfreeze the stream
+    advance_clock(&mut h.ctx, DEFAULT_DISPUTE_TIMEOUT).await;
+    let resolve = devcol_ix(
+        accounts::ResolveStreamDispute {
+            dispute,
+            stream,
+            funder: owner.pubkey(),
+            recipient: member.pubkey(),
+            arbiter: h.arbiter.pubkey(),
+            claimant: owner.pubkey(),
+        },
+        instruction::ResolveStreamDispute { recipient_bps: 0 },
+    );
+    let arbiter = h.arbiter.insecure_clone();
+    assert!(send(&mut h.ctx, &[resolve], &[&arbiter]).await.is_err());
+
+    let rent = lamports(&mut h.ctx, dispute).await;
+    let before = lamports(&mut h.ctx, owner.pubkey()).await;
+    send(&mut h.ctx, &[expire()], &[]).await.unwrap();
+    assert!(fetch::<Dispute>(&mut h.ctx, dispute).await.is_none());
+    assert_eq!(lamports(&mut h.ctx, owner.pubkey()).await, before + rent);
+    assert!(!fetch::<Stream>(&mut h.ctx, stream).await.unwrap().frozen);
+    send(&mut h.ctx, &[withdraw()], &[&member]).await.unwrap();
+
+    // The funder's dispute lapsed for good; the recipient can still raise one
+    refresh_blockhash(&mut h.ctx).await;
+    assert!(send(&mut h.ctx, &[open()], &[&owner]).await.is_err());
+    let recipient_open = devcol_ix(
+        accounts::OpenStreamDispute {
+            dispute,
+            stream,
+            config: config_pda(),
+            claimant: member.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::OpenStreamDispute { evidence_ipfs_hash: "QmUnpaid".to_string() },
+    );
+    send(&mut h.ctx, &[recipient_open], &[&member]).await.unwrap();
+    assert!(fetch::<Stream>(&mut h.ctx, stream).await.unwrap().frozen);
+}
+
+#[tokio::test]
+async fn lapsed_bounty_dispute_cannot_be_reopened_by_its_claimant() {
+    let mut h = Harness::start().await;
+    let bounty = h.submitted_bounty().await;
+    let dispute = pda(&[b"dispute", bounty.as_ref()]);
+    let open = |claimant: Pubkey| {
+        devcol_ix(
+            accounts::OpenBountyDispute {
+                dispute,
+                bounty,
+                config: config_pda(),
+                claimant,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::OpenBountyDispute { evidence_ipfs_hash: "QmEvidence".to_string() },
+        )
+    };
+    let expire = |claimant: Pubkey| {
+        devcol_ix(
+            accounts::ExpireBountyDispute { dispute, bounty, claimant },
+            instruction::ExpireBountyDispute {},
+        )
+    };
+    let (member, owner) = (h.member.insecure_clone(), h.owner.insecure_clone());
+    send(&mut h.ctx, &[open(member.pubkey())], &[&member]).await.unwrap();
+    advance_clock(&mut h.ctx, DEFAULT_DISPUTE_TIMEOUT + 1).await;
+    send(&mut h.ctx, &[expire(member.pubkey())], &[]).await.unwrap();
+    let state: Bounty = fetch(&mut h.ctx, bounty).await.unwrap();
+    assert!(state.status == BountyStatus::Submitted);
+    assert_eq!(state.lapsed_disputes, 2);
+
+    // The assignee can't freeze the bounty again; the funder keeps their one dispute
+    refresh_blockhash(&mut h.ctx).await;
+    assert!(send(&mut h.ctx, &[open(member.pubkey())], &[&member]).await.is_err());
+    send(&mut h.ctx, &[open(owner.pubkey())], &[&owner]).await.unwrap();
+    assert!(fetch::<Bounty>(&mut h.ctx, bounty).await.unwrap().status == BountyStatus::Disputed);
+}
+
+#[tokio::test]
+async fn arbiters_rotate_by_dispute_count() {
+    let mut h = Harness::start().await;
+    let second = Keypair::new();
+    let add = devcol_ix(
+        accounts::UpdateConfig { config: config_pda(), admin: h.admin.pubkey() },
+        instruction::SetArbiter { arbiter: second.pubkey(), allowed: true },
+    );
+    let admin = h.admin.insecure_clone();
+    send(&mut h.ctx, &[add], &[&admin]).await.unwrap();
+
+    let bounty = h.submitted_bounty().await;
+    let mut drawn = Vec::new();
+    // Each party gets one dispute that may lapse
+    for claimant in [h.member.insecure_clone(), h.owner.insecure_clone()] {
+        let dispute = pda(&[b"dispute", bounty.as_ref()]);
+        let open = devcol_ix(
+            accounts::OpenBountyDispute {
+                dispute,
+                bounty,
+                config: config_pda(),
+                claimant: claimant.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::OpenBountyDispute { evidence_ipfs_hash: "QmEvidence".to_string() },
+        );
+        send(&mut h.ctx, &[open], &[&claimant]).await.unwrap();
+        drawn.push(fetch::<Dispute>(&mut h.ctx, dispute).await.unwrap().arbiter);
+
+        advance_clock(&mut h.ctx, DEFAULT_DISPUTE_TIMEOUT + 1).await;
+        let expire = devcol_ix(
+            accounts::ExpireBountyDispute { dispute, bounty, claimant: claimant.pubkey() },
+            instruction::ExpireBountyDispute {},
+        );
+        send(&mut h.ctx, &[expire], &[]).await.unwrap();
+    }
+    assert_eq!(drawn, vec![h.arbiter.pubkey(), second.pubkey()]);
+    assert_eq!(fetch::<Config>(&mut h.ctx, config_pda()).await.unwrap().disputes_opened, 2);
+}
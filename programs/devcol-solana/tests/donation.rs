@@ -0,0 +1,307 @@
+//! Donation vault: SOL and token donations, donor accounting and gated withdrawals
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id as ata,
+    spl_associated_token_account::instruction::create_associated_token_account,
+};
+use anchor_spl::token::spl_token;
+use common::*;
+use devcol_solana::{accounts, instruction, Donation, DonationVault, DonorRecord, Project, ProposalAction, Role};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, program_pack::Pack, signature::Keypair, signer::Signer as _};
+use solana_system_interface::instruction as system_instruction;
+
+const GIFT: u64 = 50_000_000;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    admin: Keypair,
+    owner: Keypair,
+    project: Pubkey,
+    vault: Pubkey,
+}
+
+impl Harness {
+    /// Project with its donation vault open
+    async fn start() -> Self {
+        let admin = Keypair::new();
+        let mut ctx = program_test(&admin.pubkey()).start_with_context().await;
+        let owner = create_user(&mut ctx, "owner").await;
+        let project = create_project(&mut ctx, &owner, "compiler", vec![role_slot(Role::Backend, 1)]).await;
+        let vault = pda(&[b"donation_vault", project.as_ref()]);
+        let ix = devcol_ix(
+            accounts::OpenDonationVault {
+                vault,
+                project,
+                creator: owner.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::OpenDonationVault {},
+        );
+        send(&mut ctx, &[ix], &[&owner]).await.unwrap();
+        Self { ctx, admin, owner, project, vault }
+    }
+
+    fn donor_record(&self, donor: &Pubkey) -> Pubkey {
+        pda(&[b"donor", self.project.as_ref(), donor.as_ref()])
+    }
+
+    fn receipt(&self, donor: &Pubkey, index: u32) -> Pubkey {
+        pda(&[b"donation", self.donor_record(donor).as_ref(), &index.to_le_bytes()])
+    }
+
+    async fn donate(&mut self, donor: &Keypair, amount: u64, receipt: Option<u32>) {
+        let ix = devcol_ix(
+            accounts::Donate {
+                vault: self.vault,
+                project: self.project,
+                donor_record: self.donor_record(&donor.pubkey()),
+                receipt: receipt.map(|i| self.receipt(&donor.pubkey(), i)),
+                donor: donor.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::Donate { amount, memo: "keep shipping".to_string() },
+        );
+        send(&mut self.ctx, &[ix], &[donor]).await.unwrap();
+    }
+
+    fn withdraw_ix(&self, creator: Pubkey, recipient: Pubkey, amount: u64) -> Instruction {
+        devcol_ix(
+            accounts::WithdrawDonations { vault: self.vault, project: self.project, creator, recipient },
+            instruction::WithdrawDonations { amount },
+        )
+    }
+
+    fn close_ix(&self, vault_tokens: &[Pubkey]) -> Instruction {
+        let mut ix = devcol_ix(
+            accounts::CloseDonationVault { vault: self.vault, project: self.project, creator: self.owner.pubkey() },
+            instruction::CloseDonationVault {},
+        );
+        ix.accounts.extend(vault_tokens.iter().map(|t| AccountMeta::new_readonly(*t, false)));
+        ix
+    }
+
+    async fn project(&mut self) -> Project {
+        fetch(&mut self.ctx, self.project).await.unwrap()
+    }
+}
+
+#[tokio::test]
+async fn donations_are_tallied_and_withdrawn_by_creator() {
+    let mut h = Harness::start().await;
+    let alice = funded_wallet(&mut h.ctx, 500_000_000).await;
+    let bob = funded_wallet(&mut h.ctx, 500_000_000).await;
+
+    h.donate(&alice, GIFT, Some(0)).await;
+    h.donate(&alice, GIFT, None).await;
+    h.donate(&bob, 2 * GIFT, Some(0)).await;
+
+    let project = h.project().await;
+    assert_eq!(project.donations_raised, 4 * GIFT);
+    assert_eq!(project.donor_count, 2);
+    let address = h.donor_record(&alice.pubkey());
+    let record: DonorRecord = fetch(&mut h.ctx, address).await.unwrap();
+    assert_eq!(record.lamports_donated, 2 * GIFT);
+    assert_eq!(record.donation_count, 2);
+    let address = h.receipt(&bob.pubkey(), 0);
+    let receipt: Donation = fetch(&mut h.ctx, address).await.unwrap();
+    assert_eq!(receipt.amount, 2 * GIFT);
+    assert_eq!(receipt.memo, "keep shipping");
+
+    // Only the creator withdraws, and never into the vault's rent
+    let owner = h.owner.pubkey();
+    let ix = h.withdraw_ix(alice.pubkey(), alice.pubkey(), GIFT);
+    assert!(send(&mut h.ctx, &[ix], &[&alice]).await.is_err());
+    let ix = h.withdraw_ix(owner, owner, 4 * GIFT + 1);
+    let owner_kp = h.owner.insecure_clone();
+    assert!(send(&mut h.ctx, &[ix], &[&owner_kp]).await.is_err());
+
+    let treasury = Pubkey::new_unique();
+    let ix = h.withdraw_ix(owner, treasury, 4 * GIFT);
+    send(&mut h.ctx, &[ix], &[&owner_kp]).await.unwrap();
+    assert_eq!(lamports(&mut h.ctx, treasury).await, 4 * GIFT);
+    // Lifetime total is unaffected by withdrawals
+    assert_eq!(h.project().await.donations_raised, 4 * GIFT);
+}
+
+#[tokio::test]
+async fn open_vault_blocks_project_delete_until_emptied_and_closed() {
+    let mut h = Harness::start().await;
+    let donor = funded_wallet(&mut h.ctx, 500_000_000).await;
+    h.donate(&donor, GIFT, None).await;
+
+    let owner = h.owner.insecure_clone();
+    let delete = devcol_ix(
+        accounts::DeleteProject {
+            project: h.project,
+            creator: owner.pubkey(),
+            creator_user: user_pda(&owner.pubkey()),
+            program_creator: pda(&[b"program_creator", owner.pubkey().as_ref()]),
+        },
+        instruction::DeleteProject {},
+    );
+    assert!(send(&mut h.ctx, std::slice::from_ref(&delete), &[&owner]).await.is_err());
+    let close = h.close_ix(&[]);
+    assert!(send(&mut h.ctx, std::slice::from_ref(&close), &[&owner]).await.is_err());
+
+    let ix = h.withdraw_ix(owner.pubkey(), owner.pubkey(), GIFT);
+    send(&mut h.ctx, &[ix], &[&owner]).await.unwrap();
+    refresh_blockhash(&mut h.ctx).await;
+    send(&mut h.ctx, &[close], &[&owner]).await.unwrap();
+    assert!(fetch::<DonationVault>(&mut h.ctx, h.vault).await.is_none());
+    assert_eq!(h.project().await.live_escrows, 0);
+    refresh_blockhash(&mut h.ctx).await;
+    send(&mut h.ctx, &[delete], &[&owner]).await.unwrap();
+}
+
+#[tokio::test]
+async fn approver_threshold_gates_withdrawals() {
+    let mut h = Harness::start().await;
+    let maintainer = funded_wallet(&mut h.ctx, 100_000_000).await;
+    let donor = funded_wallet(&mut h.ctx, 500_000_000).await;
+    h.donate(&donor, GIFT, None).await;
+
+    let owner = h.owner.insecure_clone();
+    let approver_set = pda(&[b"approver_set", h.project.as_ref()]);
+    let configure = devcol_ix(
+        accounts::ConfigureApprovers {
+            approver_set,
+            project: h.project,
+            creator: owner.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::ConfigureApprovers { approvers: vec![owner.pubkey(), maintainer.pubkey()], threshold: 2 },
+    );
+    send(&mut h.ctx, &[configure], &[&owner]).await.unwrap();
+
+    let ix = h.withdraw_ix(owner.pubkey(), owner.pubkey(), GIFT);
+    assert!(send(&mut h.ctx, &[ix], &[&owner]).await.is_err());
+
+    let treasury = Pubkey::new_unique();
+    let proposal = pda(&[b"proposal", h.project.as_ref(), &0u64.to_le_bytes()]);
+    let create = devcol_ix(
+        accounts::CreateProposal {
+            proposal,
+            project: h.project,
+            approver_set,
+            proposer: owner.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::CreateProposal {
+            action: ProposalAction::WithdrawDonations { mint: Pubkey::default(), amount: GIFT, recipient: treasury },
+            ttl: 24 * 60 * 60,
+        },
+    );
+    send(&mut h.ctx, &[create], &[&owner]).await.unwrap();
+
+    let executor = h.ctx.payer.pubkey();
+    let execute = || {
+        devcol_ix(
+            accounts::ExecuteWithdrawDonations {
+                proposal,
+                project: h.project,
+                approver_set,
+                proposer: h.owner.pubkey(),
+                executor,
+                vault: h.vault,
+                recipient: treasury,
+            },
+            instruction::ExecuteWithdrawDonations {},
+        )
+    };
+    assert!(send(&mut h.ctx, &[execute()], &[]).await.is_err());
+
+    let approve = devcol_ix(
+        accounts::ApproveProposal { proposal, approver_set, approver: maintainer.pubkey() },
+        instruction::ApproveProposal {},
+    );
+    send(&mut h.ctx, &[approve], &[&maintainer]).await.unwrap();
+    refresh_blockhash(&mut h.ctx).await;
+    send(&mut h.ctx, &[execute()], &[]).await.unwrap();
+    assert_eq!(lamports(&mut h.ctx, treasury).await, GIFT);
+}
+
+#[tokio::test]
+async fn token_donations_land_in_vault_account() {
+    let mut h = Harness::start().await;
+    let token_program = spl_token::ID;
+    let donor = funded_wallet(&mut h.ctx, 500_000_000).await;
+
+    // Plain SPL mint, allowlisted, with 10 tokens in the donor's account
+    let mint = Keypair::new();
+    let payer = h.ctx.payer.pubkey();
+    let rent = h.ctx.banks_client.get_rent().await.unwrap().minimum_balance(spl_token::state::Mint::LEN);
+    let donor_token = ata(&donor.pubkey(), &mint.pubkey(), &token_program);
+    let setup = [
+        system_instruction::create_account(
+            &payer,
+            &mint.pubkey(),
+            rent,
+            spl_token::state::Mint::LEN as u64,
+            &token_program,
+        ),
+        spl_token::instruction::initialize_mint2(&token_program, &mint.pubkey(), &payer, None, 6).unwrap(),
+        create_associated_token_account(&payer, &donor.pubkey(), &mint.pubkey(), &token_program),
+        spl_token::instruction::mint_to(&token_program, &mint.pubkey(), &donor_token, &payer, &[], 10_000_000)
+            .unwrap(),
+        devcol_ix(
+            accounts::UpdateConfig { config: config_pda(), admin: h.admin.pubkey() },
+            instruction::SetMintAllowed { mint: mint.pubkey(), allowed: true },
+        ),
+    ];
+    let admin = h.admin.insecure_clone();
+    send(&mut h.ctx, &setup, &[&mint, &admin]).await.unwrap();
+
+    let vault_token = ata(&h.vault, &mint.pubkey(), &token_program);
+    let ix = devcol_ix(
+        accounts::DonateToken {
+            vault: h.vault,
+            project: h.project,
+            donor_record: h.donor_record(&donor.pubkey()),
+            receipt: None,
+            donor: donor.pubkey(),
+            config: config_pda(),
+            mint: mint.pubkey(),
+            donor_token,
+            vault_token,
+            token_program,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::DonateToken { amount: 4_000_000, memo: String::new() },
+    );
+    send(&mut h.ctx, &[ix], &[&donor]).await.unwrap();
+
+    let vault: DonationVault = fetch(&mut h.ctx, h.vault).await.unwrap();
+    assert_eq!(vault.token_raised.len(), 1);
+    assert_eq!(vault.token_raised[0].amount, 4_000_000);
+    assert_eq!(h.project().await.donor_count, 1);
+
+    // Creator pays the tokens out to the donor's own account for simplicity
+    let ix = devcol_ix(
+        accounts::WithdrawTokenDonations {
+            vault: h.vault,
+            project: h.project,
+            creator: h.owner.pubkey(),
+            mint: mint.pubkey(),
+            vault_token,
+            recipient_token: donor_token,
+            token_program,
+        },
+        instruction::WithdrawTokenDonations { amount: 4_000_000 },
+    );
+    let owner = h.owner.insecure_clone();
+    send(&mut h.ctx, &[ix], &[&owner]).await.unwrap();
+    let account = h.ctx.banks_client.get_account(donor_token).await.unwrap().unwrap();
+    assert_eq!(spl_token::state::Account::unpack(&account.data).unwrap().amount, 10_000_000);
+
+    // Closing needs the (now empty) token account of every mint the vault received
+    let ix = h.close_ix(&[]);
+    assert!(send(&mut h.ctx, &[ix], &[&owner]).await.is_err());
+    let ix = h.close_ix(&[vault_token]);
+    send(&mut h.ctx, &[ix], &[&owner]).await.unwrap();
+    assert!(fetch::<DonationVault>(&mut h.ctx, h.vault).await.is_none());
+}
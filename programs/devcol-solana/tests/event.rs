@@ -0,0 +1,228 @@
+//! Hackathon events: team caps, judging, rankings and prize payouts
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{
+    accounts, instruction, ErrorCode, Event, EventEntry, EventStatus, Project, Role, MAX_JUDGE_SCORE,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+const FIRST: u64 = 300_000_000;
+const SECOND: u64 = 100_000_000;
+const DAY: i64 = 24 * 60 * 60;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    organizer: Keypair,
+    judges: [Keypair; 2],
+    event: Pubkey,
+}
+
+impl Harness {
+    /// Two day event with two judges, teams of at most 3 and prizes for the top two
+    async fn start() -> Self {
+        let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+        let organizer = funded_wallet(&mut ctx, 1_000_000_000).await;
+        let judges = [funded_wallet(&mut ctx, 10_000_000).await, funded_wallet(&mut ctx, 10_000_000).await];
+        let name = "solana-summer";
+        let event = pda(&[b"event", organizer.pubkey().as_ref(), name.as_bytes()]);
+        let now = unix_timestamp(&mut ctx).await;
+        let ix = devcol_ix(
+            accounts::CreateEvent {
+                event,
+                organizer: organizer.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateEvent {
+                name: name.to_string(),
+                judges: judges.iter().map(|j| j.pubkey()).collect(),
+                tracks: vec!["defi".to_string(), "tooling".to_string()],
+                start_time: now,
+                end_time: now + 2 * DAY,
+                max_team_size: 3,
+                prizes: vec![FIRST, SECOND],
+            },
+        );
+        send(&mut ctx, &[ix], &[&organizer]).await.unwrap();
+        Self { ctx, organizer, judges, event }
+    }
+
+    fn entry(&self, project: &Pubkey) -> Pubkey {
+        pda(&[b"event_entry", self.event.as_ref(), project.as_ref()])
+    }
+
+    async fn register(&mut self, owner: &Keypair, project: Pubkey, track: u8) -> bool {
+        let ix = devcol_ix(
+            accounts::RegisterEventProject {
+                event: self.event,
+                entry: self.entry(&project),
+                project,
+                creator: owner.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::RegisterEventProject { track },
+        );
+        send(&mut self.ctx, &[ix], &[owner]).await.is_ok()
+    }
+
+    async fn score(&mut self, judge: usize, project: Pubkey, score: u8) -> bool {
+        let judge = self.judges[judge].insecure_clone();
+        let ix = devcol_ix(
+            accounts::ScoreEventProject {
+                event: self.event,
+                entry: self.entry(&project),
+                project,
+                judge: judge.pubkey(),
+            },
+            instruction::ScoreEventProject { score },
+        );
+        send(&mut self.ctx, &[ix], &[&judge]).await.is_ok()
+    }
+
+    fn rank_ix(&self, order: &[Pubkey]) -> Instruction {
+        let mut ix = devcol_ix(
+            accounts::ManageEvent { event: self.event, organizer: self.organizer.pubkey() },
+            instruction::RankEventProjects {},
+        );
+        ix.accounts.extend(order.iter().map(|p| AccountMeta::new(self.entry(p), false)));
+        ix
+    }
+
+    async fn claim(&mut self, project: Pubkey, creator: Pubkey) -> bool {
+        let ix = devcol_ix(
+            accounts::ClaimEventPrize { event: self.event, entry: self.entry(&project), project, creator },
+            instruction::ClaimEventPrize {},
+        );
+        send(&mut self.ctx, &[ix], &[]).await.is_ok()
+    }
+}
+
+#[tokio::test]
+async fn judged_rankings_pay_prizes_from_treasury() {
+    let mut h = Harness::start().await;
+    let mut teams = Vec::new();
+    for (name, track) in [("amm", 0), ("debugger", 1), ("lending", 0)] {
+        let owner = create_user(&mut h.ctx, name).await;
+        let project = create_project(&mut h.ctx, &owner, name, vec![role_slot(Role::Backend, 2)]).await;
+        assert!(h.register(&owner, project, track).await);
+        teams.push((owner, project));
+    }
+    let [amm, debugger, lending] = [teams[0].1, teams[1].1, teams[2].1];
+
+    // Judging opens when the event ends
+    assert!(!h.score(0, amm, 90).await);
+    advance_clock(&mut h.ctx, 2 * DAY).await;
+    for (project, scores) in [(amm, [90, 80]), (debugger, [70, 90]), (lending, [80, 60])] {
+        assert!(h.score(0, project, scores[0]).await);
+        assert!(h.score(1, project, scores[1]).await);
+    }
+    assert!(!h.score(0, amm, MAX_JUDGE_SCORE + 1).await);
+    let address = h.entry(&debugger);
+    let entry: EventEntry = fetch(&mut h.ctx, address).await.unwrap();
+    assert_eq!(entry.average_score(), 8_000);
+
+    // Rankings must follow average scores and cover every entry
+    let organizer = h.organizer.insecure_clone();
+    let ix = h.rank_ix(&[debugger, amm, lending]);
+    assert!(send(&mut h.ctx, &[ix], &[&organizer]).await.is_err());
+    let ix = h.rank_ix(&[amm, debugger]);
+    assert!(send(&mut h.ctx, &[ix], &[&organizer]).await.is_err());
+    let ix = h.rank_ix(&[amm, debugger, lending]);
+    send(&mut h.ctx, &[ix], &[&organizer]).await.unwrap();
+    let event: Event = fetch(&mut h.ctx, h.event).await.unwrap();
+    assert!(event.status == EventStatus::Ranked);
+    assert!(!h.score(0, amm, 10).await);
+
+    let (first_owner, second_owner) = (teams[0].0.pubkey(), teams[1].0.pubkey());
+    let before = lamports(&mut h.ctx, first_owner).await;
+    assert!(h.claim(amm, first_owner).await);
+    assert_eq!(lamports(&mut h.ctx, first_owner).await, before + FIRST);
+    // Paid once and only to the creator; entries outside the prize places settle for nothing
+    refresh_blockhash(&mut h.ctx).await;
+    assert!(!h.claim(amm, first_owner).await);
+    assert!(!h.claim(debugger, first_owner).await);
+    let (third_owner, before) = (teams[2].0.pubkey(), lamports(&mut h.ctx, teams[2].0.pubkey()).await);
+    assert!(h.claim(lending, third_owner).await);
+    assert_eq!(lamports(&mut h.ctx, third_owner).await, before);
+
+    let close = devcol_ix(
+        accounts::CloseEvent { event: h.event, organizer: organizer.pubkey() },
+        instruction::CloseEvent {},
+    );
+    assert!(send(&mut h.ctx, std::slice::from_ref(&close), &[&organizer]).await.is_err());
+    let before = lamports(&mut h.ctx, second_owner).await;
+    assert!(h.claim(debugger, second_owner).await);
+    assert_eq!(lamports(&mut h.ctx, second_owner).await, before + SECOND);
+    refresh_blockhash(&mut h.ctx).await;
+    send(&mut h.ctx, &[close], &[&organizer]).await.unwrap();
+    assert!(fetch::<Event>(&mut h.ctx, h.event).await.is_none());
+}
+
+#[tokio::test]
+async fn team_size_cap_follows_role_slots() {
+    let mut h = Harness::start().await;
+    let owner = create_user(&mut h.ctx, "owner").await;
+    let oversized = create_project(&mut h.ctx, &owner, "oversized", vec![role_slot(Role::Backend, 3)]).await;
+    assert!(!h.register(&owner, oversized, 0).await);
+    assert!(!h.register(&owner, oversized, 2).await);
+
+    let project = create_project(&mut h.ctx, &owner, "compact", vec![role_slot(Role::Backend, 1)]).await;
+    assert!(h.register(&owner, project, 1).await);
+    let state: Project = fetch(&mut h.ctx, project).await.unwrap();
+    assert_eq!(state.team_size_cap, 3);
+
+    // Entered projects can't add slots beyond the cap
+    let update = |needed: u8| {
+        devcol_ix(
+            accounts::UpdateProjectRoles { project, authority: owner.pubkey(), project_role: None, organization: None },
+            instruction::UpdateProjectRoles {
+                role_requirements: vec![role_slot(Role::Backend, 1), role_slot(Role::Frontend, needed)],
+            },
+        )
+    };
+    assert!(send(&mut h.ctx, &[update(2)], &[&owner]).await.is_err());
+    send(&mut h.ctx, &[update(1)], &[&owner]).await.unwrap();
+
+    // Registration closes when the event ends, and the cap lapses with it
+    advance_clock(&mut h.ctx, 2 * DAY).await;
+    let late = create_project(&mut h.ctx, &owner, "late", vec![]).await;
+    assert!(!h.register(&owner, late, 0).await);
+    send(&mut h.ctx, &[update(2)], &[&owner]).await.unwrap();
+}
+
+#[tokio::test]
+async fn event_names_must_fit_in_a_seed() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let organizer = funded_wallet(&mut ctx, 1_000_000_000).await;
+    let now = unix_timestamp(&mut ctx).await;
+    let create = |event: Pubkey, name: &str| {
+        devcol_ix(
+            accounts::CreateEvent {
+                event,
+                organizer: organizer.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateEvent {
+                name: name.to_string(),
+                judges: vec![Pubkey::new_unique()],
+                tracks: vec!["defi".to_string()],
+                start_time: now,
+                end_time: now + 2 * DAY,
+                max_team_size: 3,
+                prizes: vec![FIRST],
+            },
+        )
+    };
+
+    let long = "solana-summer-global-hackathon-26"; // 33 bytes
+    let result = send(&mut ctx, &[create(Pubkey::new_unique(), long)], &[&organizer]).await;
+    assert!(failed_with(result, ErrorCode::SeedNameTooLong));
+
+    let name = &long[..32];
+    let event = pda(&[b"event", organizer.pubkey().as_ref(), name.as_bytes()]);
+    send(&mut ctx, &[create(event, name)], &[&organizer]).await.unwrap();
+    assert_eq!(fetch::<Event>(&mut ctx, event).await.unwrap().name, name);
+}
@@ -0,0 +1,245 @@
+//! Quadratic-funding grant rounds: windows, eligibility, sybil weighting and chunked payouts
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{
+    accounts, instruction, ErrorCode, GrantContribution, GrantRound, GrantRoundStatus, Role,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+const POOL: u64 = 1_000_000_000;
+const DAY: i64 = 24 * 60 * 60;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    admin: Keypair,
+    operator: Keypair,
+    round: Pubkey,
+}
+
+impl Harness {
+    /// Round with a one day registration window followed by a week of contributions
+    async fn start() -> Self {
+        let admin = Keypair::new();
+        let mut ctx = program_test(&admin.pubkey()).start_with_context().await;
+        let operator = funded_wallet(&mut ctx, 2 * POOL).await;
+        let name = "season-1";
+        let round = pda(&[b"grant_round", operator.pubkey().as_ref(), name.as_bytes()]);
+        let now = unix_timestamp(&mut ctx).await;
+        let ix = devcol_ix(
+            accounts::CreateGrantRound {
+                round,
+                operator: operator.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateGrantRound {
+                name: name.to_string(),
+                matching_pool: POOL,
+                registration_start: now,
+                registration_end: now + DAY,
+                contribution_start: now + DAY,
+                contribution_end: now + 8 * DAY,
+            },
+        );
+        send(&mut ctx, &[ix], &[&operator]).await.unwrap();
+        Self { ctx, admin, operator, round }
+    }
+
+    /// Project registered for the round and marked eligible
+    async fn register(&mut self, owner: &Keypair, name: &str) -> Pubkey {
+        let project = create_project(&mut self.ctx, owner, name, vec![role_slot(Role::Backend, 1)]).await;
+        let ixs = [
+            devcol_ix(
+                accounts::RegisterGrantProject { round: self.round, project, creator: owner.pubkey() },
+                instruction::RegisterGrantProject {},
+            ),
+            devcol_ix(
+                accounts::SetGrantEligibility { round: self.round, operator: self.operator.pubkey() },
+                instruction::SetGrantEligibility { project, eligible: true },
+            ),
+        ];
+        let operator = self.operator.insecure_clone();
+        send(&mut self.ctx, &ixs, &[owner, &operator]).await.unwrap();
+        project
+    }
+
+    fn contribution(&self, project: &Pubkey, contributor: &Pubkey) -> Pubkey {
+        pda(&[b"grant_contribution", self.round.as_ref(), project.as_ref(), contributor.as_ref()])
+    }
+
+    fn contribute_ix(&self, project: Pubkey, contributor: Pubkey, amount: u64) -> Instruction {
+        devcol_ix(
+            accounts::ContributeToGrant {
+                round: self.round,
+                project,
+                contribution: self.contribution(&project, &contributor),
+                contributor_user: user_pda(&contributor),
+                contributor,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::ContributeToGrant { amount },
+        )
+    }
+
+    async fn contribute(&mut self, project: Pubkey, contributor: &Keypair, amount: u64) -> bool {
+        let ix = self.contribute_ix(project, contributor.pubkey(), amount);
+        send(&mut self.ctx, &[ix], &[contributor]).await.is_ok()
+    }
+
+    async fn round(&mut self) -> GrantRound {
+        fetch(&mut self.ctx, self.round).await.unwrap()
+    }
+}
+
+#[tokio::test]
+async fn verified_contributors_weigh_more_in_the_match() {
+    let mut h = Harness::start().await;
+    let owner_a = create_user(&mut h.ctx, "owner-a").await;
+    let owner_b = create_user(&mut h.ctx, "owner-b").await;
+    let project_a = h.register(&owner_a, "indexer").await;
+    let project_b = h.register(&owner_b, "explorer").await;
+
+    let alice = create_user(&mut h.ctx, "alice").await;
+    let verify = devcol_ix(
+        accounts::SetUserVerified { user: user_pda(&alice.pubkey()), config: config_pda(), admin: h.admin.pubkey() },
+        instruction::SetUserVerified { verified: true },
+    );
+    let admin = h.admin.insecure_clone();
+    send(&mut h.ctx, &[verify], &[&admin]).await.unwrap();
+
+    // Contributions only open after registration
+    assert!(!h.contribute(project_a, &alice, 400_000_000).await);
+    advance_clock(&mut h.ctx, DAY).await;
+    assert!(h.contribute(project_a, &alice, 400_000_000).await);
+
+    let mut crowd = Vec::new();
+    for name in ["bob", "carol", "dave"] {
+        let wallet = create_user(&mut h.ctx, name).await;
+        assert!(h.contribute(project_b, &wallet, 100_000_000).await);
+        crowd.push(wallet);
+    }
+    // A wallet without a profile can't take part
+    let anon = funded_wallet(&mut h.ctx, 200_000_000).await;
+    assert!(!h.contribute(project_b, &anon, 100_000_000).await);
+
+    // sqrt(4e8) at full weight vs three sqrt(1e8) terms at the unverified quarter weight
+    let address = h.contribution(&project_a, &alice.pubkey());
+    let record: GrantContribution = fetch(&mut h.ctx, address).await.unwrap();
+    assert_eq!(record.weighted_sqrt, 20_000);
+    let round = h.round().await;
+    assert_eq!(round.projects[1].sum_sqrt, 3 * 2_500);
+    assert_eq!(round.projects[1].contributors, 3);
+
+    // Finalization waits for the window to close
+    let address = h.round;
+    let finalize = || devcol_ix(accounts::FinalizeGrantRound { round: address }, instruction::FinalizeGrantRound {});
+    assert!(send(&mut h.ctx, &[finalize()], &[]).await.is_err());
+    advance_clock(&mut h.ctx, 7 * DAY).await;
+    assert!(!h.contribute(project_b, &crowd[0], 1).await);
+    send(&mut h.ctx, &[finalize()], &[]).await.unwrap();
+
+    let round = h.round().await;
+    assert!(round.status == GrantRoundStatus::Finalized);
+    let (score_a, score_b) = (20_000u128 * 20_000, 7_500u128 * 7_500);
+    let matched_a = (POOL as u128 * score_a / (score_a + score_b)) as u64;
+    let matched_b = (POOL as u128 * score_b / (score_a + score_b)) as u64;
+    assert_eq!(round.projects[0].matched, matched_a);
+    assert_eq!(round.projects[1].matched, matched_b);
+
+    // Paid out one project per transaction; the creator receives contributions plus match
+    let before = lamports(&mut h.ctx, owner_b.pubkey()).await;
+    let distribute = |project: Pubkey, recipient: Pubkey| {
+        let mut ix = devcol_ix(accounts::DistributeGrantRound { round: address }, instruction::DistributeGrantRound {});
+        ix.accounts.push(AccountMeta::new(project, false));
+        ix.accounts.push(AccountMeta::new(recipient, false));
+        ix
+    };
+    // Payout must go to the project's creator
+    assert!(send(&mut h.ctx, &[distribute(project_b, alice.pubkey())], &[]).await.is_err());
+    send(&mut h.ctx, &[distribute(project_b, owner_b.pubkey())], &[]).await.unwrap();
+    assert_eq!(lamports(&mut h.ctx, owner_b.pubkey()).await, before + 300_000_000 + matched_b);
+
+    let operator = h.operator.insecure_clone();
+    let close = || {
+        devcol_ix(
+            accounts::CloseGrantRound { round: address, operator: operator.pubkey() },
+            instruction::CloseGrantRound {},
+        )
+    };
+    assert!(send(&mut h.ctx, &[close()], &[&operator]).await.is_err());
+
+    let before = lamports(&mut h.ctx, owner_a.pubkey()).await;
+    send(&mut h.ctx, &[distribute(project_a, owner_a.pubkey())], &[]).await.unwrap();
+    assert_eq!(lamports(&mut h.ctx, owner_a.pubkey()).await, before + 400_000_000 + matched_a);
+
+    refresh_blockhash(&mut h.ctx).await;
+    send(&mut h.ctx, &[close()], &[&operator]).await.unwrap();
+    assert!(fetch::<GrantRound>(&mut h.ctx, h.round).await.is_none());
+}
+
+#[tokio::test]
+async fn only_eligible_projects_take_contributions() {
+    let mut h = Harness::start().await;
+    let owner = create_user(&mut h.ctx, "owner").await;
+    let project = create_project(&mut h.ctx, &owner, "pending", vec![role_slot(Role::Frontend, 1)]).await;
+    let register = devcol_ix(
+        accounts::RegisterGrantProject { round: h.round, project, creator: owner.pubkey() },
+        instruction::RegisterGrantProject {},
+    );
+    send(&mut h.ctx, &[register], &[&owner]).await.unwrap();
+
+    advance_clock(&mut h.ctx, DAY).await;
+    let backer = create_user(&mut h.ctx, "backer").await;
+    assert!(!h.contribute(project, &backer, 10_000_000).await);
+
+    // Eligibility is fixed once contributions open, and late registrations are refused
+    let operator = h.operator.insecure_clone();
+    let set_eligible = devcol_ix(
+        accounts::SetGrantEligibility { round: h.round, operator: operator.pubkey() },
+        instruction::SetGrantEligibility { project, eligible: true },
+    );
+    assert!(send(&mut h.ctx, &[set_eligible], &[&operator]).await.is_err());
+    let late = create_project(&mut h.ctx, &owner, "latecomer", vec![role_slot(Role::Frontend, 1)]).await;
+    let register = devcol_ix(
+        accounts::RegisterGrantProject { round: h.round, project: late, creator: owner.pubkey() },
+        instruction::RegisterGrantProject {},
+    );
+    assert!(send(&mut h.ctx, &[register], &[&owner]).await.is_err());
+    assert_eq!(h.round().await.projects.len(), 1);
+}
+
+#[tokio::test]
+async fn round_names_must_fit_in_a_seed() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let operator = funded_wallet(&mut ctx, 2 * POOL).await;
+    let now = unix_timestamp(&mut ctx).await;
+    let create = |round: Pubkey, name: &str| {
+        devcol_ix(
+            accounts::CreateGrantRound {
+                round,
+                operator: operator.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateGrantRound {
+                name: name.to_string(),
+                matching_pool: POOL,
+                registration_start: now,
+                registration_end: now + DAY,
+                contribution_start: now + DAY,
+                contribution_end: now + 8 * DAY,
+            },
+        )
+    };
+
+    let long = "public-goods-quadratic-round-2026"; // 33 bytes
+    let result = send(&mut ctx, &[create(Pubkey::new_unique(), long)], &[&operator]).await;
+    assert!(failed_with(result, ErrorCode::SeedNameTooLong));
+
+    let name = &long[..32];
+    let round = pda(&[b"grant_round", operator.pubkey().as_ref(), name.as_bytes()]);
+    send(&mut ctx, &[create(round, name)], &[&operator]).await.unwrap();
+    assert_eq!(fetch::<GrantRound>(&mut ctx, round).await.unwrap().name, name);
+}
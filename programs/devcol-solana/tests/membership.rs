@@ -0,0 +1,128 @@
+//! `assert_membership` as seen by a gating program
+//!
+//! The stand-in "gate" program only lets a wallet through if devcol-solana confirms it as a
+//! Backend member of the project, the way a grant distributor or token-gated chat would.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use common::*;
+use devcol_solana::{instruction, Membership, Role};
+use solana_program_test::{processor, ProgramTestContext};
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+/// Accounts: project, collab_request (or the devcol program id for none), member, devcol_program
+fn gate_entry(_program_id: &Pubkey, accounts: &[AccountInfo], _data: &[u8]) -> ProgramResult {
+    let [project, collab_request, member, devcol] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let collab_request = (collab_request.key != devcol.key).then(|| collab_request.clone());
+    let membership = devcol_solana::cpi::assert_membership(
+        CpiContext::new(
+            devcol.clone(),
+            devcol_solana::cpi::accounts::AssertMembership {
+                project: project.clone(),
+                collab_request,
+            },
+        ),
+        *member.key,
+        Some(Role::Backend),
+    )?
+    .get();
+
+    if membership.member != *member.key || membership.project != *project.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    msg!("Gate opened for {}", member.key);
+    Ok(())
+}
+
+struct Harness {
+    ctx: ProgramTestContext,
+    gate: Pubkey,
+    owner: Keypair,
+    project: Pubkey,
+}
+
+impl Harness {
+    async fn start() -> Self {
+        let gate = Pubkey::new_unique();
+        let mut pt = program_test(&Pubkey::new_unique());
+        pt.add_program("gate", gate, processor!(gate_entry));
+        let mut ctx = pt.start_with_context().await;
+
+        let owner = create_user(&mut ctx, "owner").await;
+        let project = create_project(
+            &mut ctx,
+            &owner,
+            "indexer",
+            vec![role_slot(Role::Backend, 2), role_slot(Role::Designer, 1)],
+        )
+        .await;
+        Self { ctx, gate, owner, project }
+    }
+
+    async fn member(&mut self, username: &str, role: Role, accept: bool) -> (Keypair, Pubkey) {
+        let wallet = create_user(&mut self.ctx, username).await;
+        let owner = self.owner.pubkey();
+        let request = send_request(&mut self.ctx, &wallet, self.project, &owner, Some(role)).await;
+        if accept {
+            accept_request(&mut self.ctx, &self.owner, self.project, request).await;
+        }
+        (wallet, request)
+    }
+
+    async fn open_gate(&mut self, member: Pubkey, request: Option<Pubkey>) -> bool {
+        let ix = Instruction::new_with_bytes(
+            self.gate,
+            &[],
+            vec![
+                AccountMeta::new_readonly(self.project, false),
+                AccountMeta::new_readonly(request.unwrap_or(devcol_solana::ID), false),
+                AccountMeta::new_readonly(member, false),
+                AccountMeta::new_readonly(devcol_solana::ID, false),
+            ],
+        );
+        send(&mut self.ctx, &[ix], &[]).await.is_ok()
+    }
+}
+
+#[tokio::test]
+async fn accepted_member_passes_gate() {
+    let mut h = Harness::start().await;
+    let (wallet, request) = h.member("alice", Role::Backend, true).await;
+    assert!(h.open_gate(wallet.pubkey(), Some(request)).await);
+}
+
+#[tokio::test]
+async fn pending_or_wrong_role_is_rejected() {
+    let mut h = Harness::start().await;
+    let (pending, pending_request) = h.member("bob", Role::Backend, false).await;
+    assert!(!h.open_gate(pending.pubkey(), Some(pending_request)).await);
+
+    let (designer, designer_request) = h.member("carol", Role::Designer, true).await;
+    assert!(!h.open_gate(designer.pubkey(), Some(designer_request)).await);
+}
+
+#[tokio::test]
+async fn request_of_another_wallet_is_rejected() {
+    let mut h = Harness::start().await;
+    let (_, request) = h.member("alice", Role::Backend, true).await;
+    let impostor = Pubkey::new_unique();
+    assert!(!h.open_gate(impostor, Some(request)).await);
+}
+
+#[tokio::test]
+async fn owner_membership_is_returned_via_return_data() {
+    let mut h = Harness::start().await;
+    let ix = devcol_ix(
+        devcol_solana::accounts::AssertMembership { project: h.project, collab_request: None },
+        instruction::AssertMembership { member: h.owner.pubkey(), role: None },
+    );
+    let membership: Membership = simulate_return(&mut h.ctx, ix).await;
+    assert!(membership.is_owner);
+    assert_eq!(membership.member, h.owner.pubkey());
+    assert_eq!(membership.project, h.project);
+    assert!(membership.role.is_none());
+}
@@ -0,0 +1,52 @@
+//! Accounts written under an older, smaller layout are grown in place by their owner
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, Project};
+use solana_sdk::{account::Account as SolanaAccount, signer::Signer as _};
+
+/// Bytes of `Project` fields appended after the original layout
+const PROJECT_FIELDS_SINCE_V1: usize = 189;
+
+#[tokio::test]
+async fn old_project_layout_is_grown_and_reseeded() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let mallory = create_user(&mut ctx, "mallory").await;
+    let project = create_project(&mut ctx, &owner, "vault", vec![]).await;
+
+    // Rewrite the project as the original layout would have stored it
+    let mut state: Project = fetch(&mut ctx, project).await.unwrap();
+    state.seed_creator = Pubkey::default();
+    let old_size = 8 + Project::INIT_SPACE - PROJECT_FIELDS_SINCE_V1;
+    let mut data = Vec::new();
+    state.try_serialize(&mut data).unwrap();
+    data.resize(old_size, 0);
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let old_account = SolanaAccount {
+        lamports: rent.minimum_balance(old_size),
+        data,
+        owner: devcol_solana::ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    ctx.set_account(&project, &old_account.into());
+
+    let migrate = |signer: Pubkey| {
+        devcol_ix(
+            accounts::MigrateProject { project, signer, system_program: anchor_lang::system_program::ID },
+            instruction::MigrateProjectAccount {},
+        )
+    };
+    assert!(send(&mut ctx, &[migrate(mallory.pubkey())], &[&mallory]).await.is_err());
+    send(&mut ctx, &[migrate(owner.pubkey())], &[&owner]).await.unwrap();
+
+    let account = ctx.banks_client.get_account(project).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + Project::INIT_SPACE);
+    assert_eq!(account.lamports, rent.minimum_balance(8 + Project::INIT_SPACE));
+    let state: Project = fetch(&mut ctx, project).await.unwrap();
+    assert_eq!(state.seed_creator, owner.pubkey());
+    assert_eq!(state.name, "vault");
+}
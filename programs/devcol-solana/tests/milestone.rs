@@ -0,0 +1,162 @@
+//! Milestones: member assignees, completion percentage and the suggested project status
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, Milestone, MilestoneStatus, Project, ProjectStatus, Role};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+const WEEK: i64 = 7 * 24 * 60 * 60;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    owner: Keypair,
+    member: Keypair,
+    project: Pubkey,
+    request: Pubkey,
+}
+
+impl Harness {
+    /// Project with one accepted member
+    async fn start() -> Self {
+        let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+        let owner = create_user(&mut ctx, "owner").await;
+        let project = create_project(&mut ctx, &owner, "roadmap", vec![role_slot(Role::Backend, 2)]).await;
+        let member = create_user(&mut ctx, "member").await;
+        let request = send_request(&mut ctx, &member, project, &owner.pubkey(), Some(Role::Backend)).await;
+        accept_request(&mut ctx, &owner, project, request).await;
+        Self { ctx, owner, member, project, request }
+    }
+
+    fn milestone(&self, index: u32) -> Pubkey {
+        pda(&[b"milestone", self.project.as_ref(), &index.to_le_bytes()])
+    }
+
+    async fn create(&mut self, authority: &Keypair, assignees: Vec<Pubkey>, requests: &[Pubkey]) -> bool {
+        let project: Project = fetch(&mut self.ctx, self.project).await.unwrap();
+        let due_date = unix_timestamp(&mut self.ctx).await + WEEK;
+        let mut ix = devcol_ix(
+            accounts::CreateMilestone {
+                milestone: self.milestone(project.milestone_count),
+                project: self.project,
+                authority: authority.pubkey(),
+                project_role: None,
+                organization: None,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateMilestone {
+                title: format!("Milestone {}", project.milestone_count),
+                spec_ipfs_hash: "QmSpec".to_string(),
+                due_date,
+                assignees,
+            },
+        );
+        ix.accounts.extend(requests.iter().map(|r| AccountMeta::new_readonly(*r, false)));
+        send(&mut self.ctx, &[ix], &[authority]).await.is_ok()
+    }
+
+    fn manage_ix(&self, index: u32, data: impl anchor_lang::InstructionData) -> Instruction {
+        devcol_ix(
+            accounts::ManageMilestone {
+                milestone: self.milestone(index),
+                project: self.project,
+                authority: self.owner.pubkey(),
+                project_role: None,
+                organization: None,
+            },
+            data,
+        )
+    }
+
+    async fn complete(&mut self, index: u32) -> bool {
+        let ix = self.manage_ix(index, instruction::CompleteMilestone {});
+        let owner = self.owner.insecure_clone();
+        send(&mut self.ctx, &[ix], &[&owner]).await.is_ok()
+    }
+
+    async fn progress(&mut self) -> (u8, ProjectStatus) {
+        let project: Project = fetch(&mut self.ctx, self.project).await.unwrap();
+        (project.completion_pct, project.project_status)
+    }
+}
+
+#[tokio::test]
+async fn completion_drives_suggested_status() {
+    let mut h = Harness::start().await;
+    let owner = h.owner.insecure_clone();
+    let follow = devcol_ix(
+        accounts::UpdateProject {
+            project: h.project,
+            authority: owner.pubkey(),
+            project_role: None,
+            organization: None,
+            authority_user: None,
+            rate_state: None,
+            program_creator: None,
+            config: config_pda(),
+        },
+        instruction::SetStatusFromMilestones { enabled: true },
+    );
+    send(&mut h.ctx, &[follow], &[&owner]).await.unwrap();
+    // Nothing to suggest without milestones
+    assert!(h.progress().await == (0, ProjectStatus::InProgress));
+
+    let (member, request) = (h.member.pubkey(), h.request);
+    assert!(h.create(&owner, vec![member], &[request]).await);
+    assert!(h.create(&owner, vec![owner.pubkey(), member], &[request]).await);
+    assert!(h.create(&owner, vec![], &[]).await);
+    assert!(h.create(&owner, vec![], &[]).await);
+    assert!(h.progress().await == (0, ProjectStatus::JustStarted));
+
+    assert!(h.complete(0).await);
+    assert!(h.progress().await == (25, ProjectStatus::InProgress));
+    assert!(h.complete(1).await);
+    assert!(h.complete(2).await);
+    assert!(h.progress().await == (75, ProjectStatus::NearlyComplete));
+    assert!(h.complete(3).await);
+    assert!(h.progress().await == (100, ProjectStatus::Completed));
+    refresh_blockhash(&mut h.ctx).await;
+    assert!(!h.complete(3).await);
+
+    let reopen = h.manage_ix(3, instruction::ReopenMilestone {});
+    send(&mut h.ctx, &[reopen], &[&owner]).await.unwrap();
+    assert!(h.progress().await == (75, ProjectStatus::NearlyComplete));
+    let address = h.milestone(3);
+    let milestone: Milestone = fetch(&mut h.ctx, address).await.unwrap();
+    assert!(milestone.status == MilestoneStatus::Open);
+    assert_eq!(milestone.completed_at, 0);
+}
+
+#[tokio::test]
+async fn assignees_must_be_members() {
+    let mut h = Harness::start().await;
+    let owner = h.owner.insecure_clone();
+    let (member, request) = (h.member.pubkey(), h.request);
+
+    // Pending applicants, missing proofs, duplicates and non-managers are refused
+    let applicant = create_user(&mut h.ctx, "applicant").await;
+    let pending = send_request(&mut h.ctx, &applicant, h.project, &owner.pubkey(), Some(Role::Backend)).await;
+    assert!(!h.create(&owner, vec![applicant.pubkey()], &[pending]).await);
+    assert!(!h.create(&owner, vec![member], &[]).await);
+    assert!(!h.create(&owner, vec![member, member], &[request, request]).await);
+    let member_kp = h.member.insecure_clone();
+    assert!(!h.create(&member_kp, vec![], &[]).await);
+
+    assert!(h.create(&owner, vec![], &[]).await);
+    let mut update = h.manage_ix(
+        0,
+        instruction::UpdateMilestone { title: None, spec_ipfs_hash: None, due_date: None, assignees: Some(vec![member]) },
+    );
+    update.accounts.push(AccountMeta::new_readonly(request, false));
+    send(&mut h.ctx, &[update.clone()], &[&owner]).await.unwrap();
+    let address = h.milestone(0);
+    let milestone: Milestone = fetch(&mut h.ctx, address).await.unwrap();
+    assert_eq!(milestone.assignees, vec![member]);
+
+    // Completed milestones are frozen until reopened
+    assert!(h.complete(0).await);
+    refresh_blockhash(&mut h.ctx).await;
+    assert!(send(&mut h.ctx, &[update], &[&owner]).await.is_err());
+}
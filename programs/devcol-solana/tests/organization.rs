@@ -0,0 +1,130 @@
+//! Projects created under an organization are indexed and must leave it before deletion
+//! or transfer
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{
+    accounts, instruction, CollaborationLevel, CreateProjectArgs, ErrorCode, OrgProject, Organization,
+    Project, ProjectStatus,
+};
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+fn delete_ix(project: Pubkey, creator: &Keypair) -> Instruction {
+    devcol_ix(
+        accounts::DeleteProject {
+            project,
+            creator: creator.pubkey(),
+            creator_user: user_pda(&creator.pubkey()),
+            program_creator: pda(&[b"program_creator", creator.pubkey().as_ref()]),
+        },
+        instruction::DeleteProject {},
+    )
+}
+
+#[tokio::test]
+async fn org_projects_are_indexed_and_unlisted_before_delete() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let admin = create_user(&mut ctx, "admin").await;
+    let outsider = create_user(&mut ctx, "outsider").await;
+
+    let organization = pda(&[b"organization", b"guild"]);
+    let create_org = devcol_ix(
+        accounts::CreateOrganization {
+            organization,
+            user: user_pda(&admin.pubkey()),
+            creator: admin.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::CreateOrganization { name: "guild".to_string(), logo_ipfs_hash: String::new() },
+    );
+    send(&mut ctx, &[create_org], &[&admin]).await.unwrap();
+
+    let org_project = pda(&[b"org_project", organization.as_ref(), &0u32.to_le_bytes()]);
+    let create = |creator: &Keypair| {
+        devcol_ix(
+            accounts::CreateOrganizationProject {
+                project: project_pda(&creator.pubkey(), "forge"),
+                org_project,
+                organization,
+                user: user_pda(&creator.pubkey()),
+                rate_state: rate_state_pda(&creator.pubkey()),
+                config: config_pda(),
+                creator: creator.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateOrganizationProject {
+                args: CreateProjectArgs {
+                    name: "forge".to_string(),
+                    description: String::new(),
+                    github_link: String::new(),
+                    logo_ipfs_hash: String::new(),
+                    tech_stack: vec![],
+                    contribution_needs: vec![],
+                    collab_intent: String::new(),
+                    collaboration_level: CollaborationLevel::AllLevels,
+                    project_status: ProjectStatus::InProgress,
+                    required_roles: vec![],
+                },
+            },
+        )
+    };
+    assert!(send(&mut ctx, &[create(&outsider)], &[&outsider]).await.is_err());
+    send(&mut ctx, &[create(&admin)], &[&admin]).await.unwrap();
+
+    let project = project_pda(&admin.pubkey(), "forge");
+    assert_eq!(fetch::<Project>(&mut ctx, project).await.unwrap().organization, organization);
+    let entry = fetch::<OrgProject>(&mut ctx, org_project).await.unwrap();
+    assert_eq!(entry.project, project);
+    assert_eq!(fetch::<Organization>(&mut ctx, organization).await.unwrap().project_count, 1);
+
+    assert!(send(&mut ctx, &[delete_ix(project, &admin)], &[&admin]).await.is_err());
+
+    // Nor can it be handed to someone outside the organization
+    let propose = devcol_ix(
+        accounts::ProposeTransfer { project, creator: admin.pubkey(), new_owner_user: user_pda(&outsider.pubkey()) },
+        instruction::ProposeTransfer {},
+    );
+    assert!(send(&mut ctx, &[propose], &[&admin]).await.is_err());
+
+    let remove = devcol_ix(
+        accounts::RemoveProjectFromOrganization { org_project, organization, project, admin: admin.pubkey() },
+        instruction::RemoveProjectFromOrganization {},
+    );
+    send(&mut ctx, &[remove], &[&admin]).await.unwrap();
+    assert!(fetch::<OrgProject>(&mut ctx, org_project).await.is_none());
+    let org = fetch::<Organization>(&mut ctx, organization).await.unwrap();
+    assert_eq!((org.project_count, org.project_index), (0, 1));
+
+    refresh_blockhash(&mut ctx).await;
+    send(&mut ctx, &[delete_ix(project, &admin)], &[&admin]).await.unwrap();
+    assert!(fetch::<Project>(&mut ctx, project).await.is_none());
+}
+
+#[tokio::test]
+async fn org_names_must_fit_in_a_seed() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let admin = create_user(&mut ctx, "admin").await;
+    let create = |organization: Pubkey, name: &str| {
+        devcol_ix(
+            accounts::CreateOrganization {
+                organization,
+                user: user_pda(&admin.pubkey()),
+                creator: admin.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateOrganization { name: name.to_string(), logo_ipfs_hash: String::new() },
+        )
+    };
+
+    // No address can be derived from a longer name, so it is refused up front
+    let long = "decentralized-collaboration-guild"; // 33 bytes
+    let result = send(&mut ctx, &[create(Pubkey::new_unique(), long)], &[&admin]).await;
+    assert!(failed_with(result, ErrorCode::SeedNameTooLong));
+
+    let name = &long[..32];
+    let organization = pda(&[b"organization", name.as_bytes()]);
+    send(&mut ctx, &[create(organization, name)], &[&admin]).await.unwrap();
+    assert_eq!(fetch::<Organization>(&mut ctx, organization).await.unwrap().name, name);
+}
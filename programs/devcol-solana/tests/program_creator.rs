@@ -0,0 +1,275 @@
+//! Program-owned project creators acting through CPI
+//!
+//! A stand-in "DAO" program owns a vault PDA and drives devcol-solana with
+//! `invoke_signed`, the way a multisig or DAO treasury would.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use common::*;
+use devcol_solana::{
+    CollaborationAcceptance, CollaborationLevel, CreateProjectArgs, ProgramCreator, Project,
+    ProjectStatus, User,
+};
+use solana_program_test::{processor, ProgramTestContext};
+use solana_sdk::{instruction::Instruction, signer::Signer as _};
+
+const VAULT_SEED: &[u8] = b"vault";
+
+const REGISTER: u8 = 0;
+const CREATE_PROJECT: u8 = 1;
+const CLOSE_PROJECT: u8 = 2;
+const PROPOSE_TRANSFER: u8 = 3;
+
+/// Caller program: forwards each action to devcol-solana, signing as its vault
+///
+/// Accounts: vault, program_creator, project, config, payer, system_program, devcol_program,
+/// then any the action needs (the new owner's User PDA for a transfer)
+fn dao_entry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let [vault, program_creator, project, config, payer, system_program, devcol, extra @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let (_, bump) = Pubkey::find_program_address(&[VAULT_SEED], program_id);
+    let signer: &[&[&[u8]]] = &[&[VAULT_SEED, &[bump]]];
+
+    let result = match data[0] {
+        REGISTER => devcol_solana::cpi::register_program_creator(
+            CpiContext::new_with_signer(
+                devcol.clone(),
+                devcol_solana::cpi::accounts::RegisterProgramCreator {
+                    program_creator: program_creator.clone(),
+                    authority: vault.clone(),
+                    payer: payer.clone(),
+                    system_program: system_program.clone(),
+                },
+                signer,
+            ),
+            "Treasury DAO".to_string(),
+        ),
+        CREATE_PROJECT => devcol_solana::cpi::create_program_project(
+            CpiContext::new_with_signer(
+                devcol.clone(),
+                devcol_solana::cpi::accounts::CreateProgramProject {
+                    project: project.clone(),
+                    program_creator: program_creator.clone(),
+                    config: config.clone(),
+                    creator: vault.clone(),
+                    payer: payer.clone(),
+                    system_program: system_program.clone(),
+                },
+                signer,
+            ),
+            project_args("dao-tooling"),
+        ),
+        CLOSE_PROJECT => devcol_solana::cpi::close_project(CpiContext::new_with_signer(
+            devcol.clone(),
+            devcol_solana::cpi::accounts::UpdateProject {
+                project: project.clone(),
+                authority: vault.clone(),
+                project_role: None,
+                organization: None,
+                authority_user: None,
+                rate_state: None,
+                program_creator: Some(program_creator.clone()),
+                config: config.clone(),
+            },
+            signer,
+        )),
+        PROPOSE_TRANSFER => devcol_solana::cpi::propose_transfer(CpiContext::new_with_signer(
+            devcol.clone(),
+            devcol_solana::cpi::accounts::ProposeTransfer {
+                project: project.clone(),
+                creator: vault.clone(),
+                new_owner_user: extra.first().ok_or(ProgramError::NotEnoughAccountKeys)?.clone(),
+            },
+            signer,
+        )),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+    result.map_err(Into::into)
+}
+
+fn project_args(name: &str) -> CreateProjectArgs {
+    CreateProjectArgs {
+        name: name.to_string(),
+        description: "Tooling maintained by the DAO treasury".to_string(),
+        github_link: "https://github.com/example/dao-tooling".to_string(),
+        logo_ipfs_hash: String::new(),
+        tech_stack: vec!["rust".to_string()],
+        contribution_needs: vec![],
+        collab_intent: String::new(),
+        collaboration_level: CollaborationLevel::Intermediate,
+        project_status: ProjectStatus::JustStarted,
+        required_roles: vec![],
+    }
+}
+
+struct Harness {
+    ctx: ProgramTestContext,
+    dao: Pubkey,
+    vault: Pubkey,
+    program_creator: Pubkey,
+    project: Pubkey,
+    config: Pubkey,
+}
+
+impl Harness {
+    async fn start() -> Self {
+        let dao = Pubkey::new_unique();
+        let mut pt = program_test(&Pubkey::new_unique());
+        pt.add_program("dao", dao, processor!(dao_entry));
+        let ctx = pt.start_with_context().await;
+
+        let (vault, _) = Pubkey::find_program_address(&[VAULT_SEED], &dao);
+        let (program_creator, _) = Pubkey::find_program_address(
+            &[b"program_creator", vault.as_ref()],
+            &devcol_solana::ID,
+        );
+        let (project, _) = Pubkey::find_program_address(
+            &[b"project", vault.as_ref(), b"dao-tooling"],
+            &devcol_solana::ID,
+        );
+        let (config, _) = Pubkey::find_program_address(&[b"config"], &devcol_solana::ID);
+        Self { ctx, dao, vault, program_creator, project, config }
+    }
+
+    async fn call_dao(&mut self, action: u8) -> std::result::Result<(), solana_program_test::BanksClientError> {
+        self.call_dao_with(action, vec![]).await
+    }
+
+    async fn call_dao_with(
+        &mut self,
+        action: u8,
+        extra: Vec<AccountMeta>,
+    ) -> std::result::Result<(), solana_program_test::BanksClientError> {
+        let mut metas = vec![
+            AccountMeta::new_readonly(self.vault, false),
+            AccountMeta::new(self.program_creator, false),
+            AccountMeta::new(self.project, false),
+            AccountMeta::new_readonly(self.config, false),
+            AccountMeta::new(self.ctx.payer.pubkey(), true),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+            AccountMeta::new_readonly(devcol_solana::ID, false),
+        ];
+        metas.extend(extra);
+        let ix = Instruction::new_with_bytes(self.dao, &[action], metas);
+        send(&mut self.ctx, &[ix], &[]).await
+    }
+}
+
+#[tokio::test]
+async fn vault_registers_and_creates_project_via_cpi() {
+    let mut h = Harness::start().await;
+
+    h.call_dao(REGISTER).await.unwrap();
+    let creator: ProgramCreator = fetch(&mut h.ctx, h.program_creator).await.unwrap();
+    assert_eq!(creator.authority, h.vault);
+    assert_eq!(creator.display_name, "Treasury DAO");
+
+    h.call_dao(CREATE_PROJECT).await.unwrap();
+    let project: Project = fetch(&mut h.ctx, h.project).await.unwrap();
+    assert_eq!(project.creator, h.vault);
+    assert_eq!(project.seed_creator, h.vault);
+    assert_eq!(project.name, "dao-tooling");
+    assert!(project.accepting_collaborations == CollaborationAcceptance::Open);
+
+    let creator: ProgramCreator = fetch(&mut h.ctx, h.program_creator).await.unwrap();
+    assert_eq!(creator.projects_count, 1);
+}
+
+#[tokio::test]
+async fn vault_administers_project_via_cpi() {
+    let mut h = Harness::start().await;
+    h.call_dao(REGISTER).await.unwrap();
+    h.call_dao(CREATE_PROJECT).await.unwrap();
+
+    h.call_dao(CLOSE_PROJECT).await.unwrap();
+    let project: Project = fetch(&mut h.ctx, h.project).await.unwrap();
+    assert!(project.accepting_collaborations == CollaborationAcceptance::Closed);
+}
+
+#[tokio::test]
+async fn create_requires_registered_program_creator() {
+    let mut h = Harness::start().await;
+    assert!(h.call_dao(CREATE_PROJECT).await.is_err());
+}
+
+#[tokio::test]
+async fn wallet_cannot_act_as_vault() {
+    let mut h = Harness::start().await;
+    h.call_dao(REGISTER).await.unwrap();
+    h.call_dao(CREATE_PROJECT).await.unwrap();
+
+    // A direct call naming the vault as authority has no way to produce its signature
+    let accounts = devcol_solana::accounts::UpdateProject {
+        project: h.project,
+        authority: h.vault,
+        project_role: None,
+        organization: None,
+        authority_user: None,
+        rate_state: None,
+        program_creator: Some(h.program_creator),
+        config: h.config,
+    };
+    let mut metas = accounts.to_account_metas(None);
+    for meta in metas.iter_mut() {
+        meta.is_signer = false;
+    }
+    let ix = Instruction {
+        program_id: devcol_solana::ID,
+        accounts: metas,
+        data: devcol_solana::instruction::CloseProject {}.data(),
+    };
+    assert!(send(&mut h.ctx, &[ix], &[]).await.is_err());
+}
+
+#[tokio::test]
+async fn wallet_cannot_register_as_program_creator() {
+    let mut h = Harness::start().await;
+    let wallet = funded_wallet(&mut h.ctx, 1_000_000_000).await;
+
+    let ix = devcol_ix(
+        devcol_solana::accounts::RegisterProgramCreator {
+            program_creator: pda(&[b"program_creator", wallet.pubkey().as_ref()]),
+            authority: wallet.pubkey(),
+            payer: wallet.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        devcol_solana::instruction::RegisterProgramCreator {
+            display_name: "Not a DAO".to_string(),
+        },
+    );
+    assert!(send(&mut h.ctx, &[ix], &[&wallet]).await.is_err());
+}
+
+#[tokio::test]
+async fn transfer_releases_the_program_creator_count() {
+    let mut h = Harness::start().await;
+    h.call_dao(REGISTER).await.unwrap();
+    h.call_dao(CREATE_PROJECT).await.unwrap();
+    let heir = create_user(&mut h.ctx, "heir").await;
+
+    let heir_user = user_pda(&heir.pubkey());
+    h.call_dao_with(PROPOSE_TRANSFER, vec![AccountMeta::new_readonly(heir_user, false)]).await.unwrap();
+    let accept = devcol_ix(
+        devcol_solana::accounts::AcceptTransfer {
+            project: h.project,
+            new_owner: heir.pubkey(),
+            new_owner_user: heir_user,
+            previous_owner_user: user_pda(&h.vault),
+            previous_program_creator: h.program_creator,
+        },
+        devcol_solana::instruction::AcceptTransfer {},
+    );
+    send(&mut h.ctx, &[accept], &[&heir]).await.unwrap();
+
+    let creator: ProgramCreator = fetch(&mut h.ctx, h.program_creator).await.unwrap();
+    assert_eq!(creator.projects_count, 0);
+    assert_eq!(fetch::<User>(&mut h.ctx, heir_user).await.unwrap().projects_count, 1);
+}
@@ -0,0 +1,85 @@
+//! Delegations lapse when the project changes hands until the new owner renews them
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, ProjectRole, Role};
+use solana_sdk::{instruction::Instruction, signer::Signer as _};
+
+#[tokio::test]
+async fn transfer_suspends_delegations_until_renewed() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let heir = create_user(&mut ctx, "heir").await;
+    let delegate = create_user(&mut ctx, "delegate").await;
+    let alice = create_user(&mut ctx, "alice").await;
+    let project = create_project(&mut ctx, &owner, "vault", vec![role_slot(Role::Backend, 1)]).await;
+    let delegate_role = pda(&[b"project_role", project.as_ref(), delegate.pubkey().as_ref()]);
+    let grant = devcol_ix(
+        accounts::GrantProjectRole {
+            project_role: delegate_role,
+            project,
+            authority: owner.pubkey(),
+            authority_role: None,
+            organization: None,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::GrantProjectRole {
+            delegate: delegate.pubkey(),
+            permissions: ProjectRole::REVIEW_REQUESTS,
+        },
+    );
+    send(&mut ctx, &[grant], &[&owner]).await.unwrap();
+    let request = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Backend)).await;
+
+    let propose = devcol_ix(
+        accounts::ProposeTransfer { project, creator: owner.pubkey(), new_owner_user: user_pda(&heir.pubkey()) },
+        instruction::ProposeTransfer {},
+    );
+    send(&mut ctx, &[propose], &[&owner]).await.unwrap();
+    let accept = devcol_ix(
+        accounts::AcceptTransfer {
+            project,
+            new_owner: heir.pubkey(),
+            new_owner_user: user_pda(&heir.pubkey()),
+            previous_owner_user: user_pda(&owner.pubkey()),
+            previous_program_creator: pda(&[b"program_creator", owner.pubkey().as_ref()]),
+        },
+        instruction::AcceptTransfer {},
+    );
+    send(&mut ctx, &[accept], &[&heir]).await.unwrap();
+
+    let review = || -> Instruction {
+        devcol_ix(
+            accounts::UpdateCollabRequest {
+                collab_request: request,
+                reviewer: delegate.pubkey(),
+                project_role: Some(delegate_role),
+                organization: None,
+                project,
+                block: pda(&[b"block", delegate.pubkey().as_ref(), alice.pubkey().as_ref()]),
+                owner_block: pda(&[b"block", heir.pubkey().as_ref(), alice.pubkey().as_ref()]),
+                sender_user: user_pda(&alice.pubkey()),
+                member_stats: None,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::MarkUnderReview {},
+        )
+    };
+    assert!(send(&mut ctx, &[review()], &[&delegate]).await.is_err());
+
+    let renew = devcol_ix(
+        accounts::UpdateProjectRole {
+            project_role: delegate_role,
+            project,
+            authority: heir.pubkey(),
+            authority_role: None,
+            organization: None,
+        },
+        instruction::UpdateProjectRole { permissions: ProjectRole::REVIEW_REQUESTS },
+    );
+    send(&mut ctx, &[renew], &[&heir]).await.unwrap();
+    refresh_blockhash(&mut ctx).await;
+    send(&mut ctx, &[review()], &[&delegate]).await.unwrap();
+}
@@ -0,0 +1,383 @@
+//! Approver sets: proposals for each gated action, their approvals, expiry and cancellation
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{
+    accounts, instruction, ApproverSet, CollaborationRequest, Project, Proposal, ProposalAction,
+    RequestStatus, Role, User,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+const DAY: i64 = 24 * 60 * 60;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    owner: Keypair,
+    alice: Keypair,
+    bob: Keypair,
+    project: Pubkey,
+    approver_set: Pubkey,
+}
+
+impl Harness {
+    /// Project requiring 2 of {owner, alice, bob}
+    async fn start() -> Self {
+        let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+        let owner = create_user(&mut ctx, "owner").await;
+        let alice = funded_wallet(&mut ctx, 100_000_000).await;
+        let bob = funded_wallet(&mut ctx, 100_000_000).await;
+        let project = create_project(&mut ctx, &owner, "treasury", vec![role_slot(Role::Backend, 2)]).await;
+        let approver_set = pda(&[b"approver_set", project.as_ref()]);
+        let configure = devcol_ix(
+            accounts::ConfigureApprovers {
+                approver_set,
+                project,
+                creator: owner.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::ConfigureApprovers {
+                approvers: vec![owner.pubkey(), alice.pubkey(), bob.pubkey()],
+                threshold: 2,
+            },
+        );
+        send(&mut ctx, &[configure], &[&owner]).await.unwrap();
+        Self { ctx, owner, alice, bob, project, approver_set }
+    }
+
+    fn proposal(&self, index: u64) -> Pubkey {
+        pda(&[b"proposal", self.project.as_ref(), &index.to_le_bytes()])
+    }
+
+    /// Create a proposal and return its address
+    async fn propose(&mut self, proposer: &Keypair, action: ProposalAction, ttl: i64) -> Pubkey {
+        let index = fetch::<Project>(&mut self.ctx, self.project).await.unwrap().proposal_count;
+        let proposal = self.proposal(index);
+        let ix = devcol_ix(
+            accounts::CreateProposal {
+                proposal,
+                project: self.project,
+                approver_set: self.approver_set,
+                proposer: proposer.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateProposal { action, ttl },
+        );
+        send(&mut self.ctx, &[ix], &[proposer]).await.unwrap();
+        proposal
+    }
+
+    async fn approve(&mut self, proposal: Pubkey, approver: &Keypair) -> bool {
+        let ix = devcol_ix(
+            accounts::ApproveProposal { proposal, approver_set: self.approver_set, approver: approver.pubkey() },
+            instruction::ApproveProposal {},
+        );
+        send(&mut self.ctx, &[ix], &[approver]).await.is_ok()
+    }
+
+    fn update_roles_ix(&self, proposal: Pubkey, proposer: Pubkey) -> Instruction {
+        devcol_ix(
+            accounts::ExecuteProjectProposal {
+                proposal,
+                project: self.project,
+                approver_set: self.approver_set,
+                proposer,
+                executor: self.ctx.payer.pubkey(),
+            },
+            instruction::ExecuteUpdateRoles {},
+        )
+    }
+
+    fn update_approvers_ix(&self, proposal: Pubkey, proposer: Pubkey) -> Instruction {
+        devcol_ix(
+            accounts::ExecuteUpdateApprovers {
+                proposal,
+                project: self.project,
+                approver_set: self.approver_set,
+                creator: self.owner.pubkey(),
+                proposer,
+                executor: self.ctx.payer.pubkey(),
+            },
+            instruction::ExecuteUpdateApprovers {},
+        )
+    }
+
+    async fn project(&mut self) -> Project {
+        fetch(&mut self.ctx, self.project).await.unwrap()
+    }
+}
+
+fn roles_action(needed: u8) -> ProposalAction {
+    ProposalAction::UpdateProjectRoles { role_requirements: vec![role_slot(Role::Backend, needed)] }
+}
+
+#[tokio::test]
+async fn accept_request_waits_for_the_threshold() {
+    let mut h = Harness::start().await;
+    let member = create_user(&mut h.ctx, "member").await;
+    let owner = h.owner.insecure_clone();
+    let request = send_request(&mut h.ctx, &member, h.project, &owner.pubkey(), Some(Role::Backend)).await;
+
+    let action = ProposalAction::AcceptRequest { request, owner_message: "Welcome".to_string() };
+    let proposal = h.propose(&owner, action, DAY).await;
+    let execute = devcol_ix(
+        accounts::ExecuteAcceptRequest {
+            proposal,
+            project: h.project,
+            approver_set: h.approver_set,
+            collab_request: request,
+            sender_user: user_pda(&member.pubkey()),
+            member_stats: member_stats_pda(&h.project, &member.pubkey()),
+            proposer: owner.pubkey(),
+            executor: h.ctx.payer.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::ExecuteAcceptRequest {},
+    );
+    // The proposer's own approval is one of two
+    assert!(send(&mut h.ctx, std::slice::from_ref(&execute), &[]).await.is_err());
+
+    // Only listed approvers count, each once
+    let outsider = funded_wallet(&mut h.ctx, 10_000_000).await;
+    assert!(!h.approve(proposal, &outsider).await);
+    assert!(!h.approve(proposal, &owner).await);
+    let alice = h.alice.insecure_clone();
+    assert!(h.approve(proposal, &alice).await);
+
+    refresh_blockhash(&mut h.ctx).await;
+    send(&mut h.ctx, &[execute], &[]).await.unwrap();
+    let state: CollaborationRequest = fetch(&mut h.ctx, request).await.unwrap();
+    assert!(state.status == RequestStatus::Accepted);
+    assert_eq!(state.owner_message, "Welcome");
+    let profile: User = fetch(&mut h.ctx, user_pda(&member.pubkey())).await.unwrap();
+    assert_eq!(profile.collabs_count, 1);
+    assert!(fetch::<Proposal>(&mut h.ctx, proposal).await.is_none());
+}
+
+#[tokio::test]
+async fn update_roles_and_transfer_run_through_proposals() {
+    let mut h = Harness::start().await;
+    let owner = h.owner.insecure_clone();
+    let bob = h.bob.insecure_clone();
+    let heir = create_user(&mut h.ctx, "heir").await;
+
+    // Direct transfers are refused once approvals are required
+    let propose = devcol_ix(
+        accounts::ProposeTransfer { project: h.project, creator: owner.pubkey(), new_owner_user: user_pda(&heir.pubkey()) },
+        instruction::ProposeTransfer {},
+    );
+    assert!(send(&mut h.ctx, &[propose], &[&owner]).await.is_err());
+
+    let roles = h.propose(&bob, roles_action(4), DAY).await;
+    assert!(h.approve(roles, &owner).await);
+    let transfer = h.propose(&bob, ProposalAction::TransferOwnership { new_owner: heir.pubkey() }, DAY).await;
+    assert!(h.approve(transfer, &owner).await);
+
+    // Each execute instruction only runs its own action
+    let (project, approver_set, executor) = (h.project, h.approver_set, h.ctx.payer.pubkey());
+    let execute_transfer = |proposal: Pubkey| {
+        devcol_ix(
+            accounts::ExecuteTransfer {
+                proposal,
+                project,
+                approver_set,
+                new_owner_user: user_pda(&heir.pubkey()),
+                proposer: bob.pubkey(),
+                executor,
+            },
+            instruction::ExecuteTransfer {},
+        )
+    };
+    assert!(send(&mut h.ctx, &[execute_transfer(roles)], &[]).await.is_err());
+
+    let ix = h.update_roles_ix(roles, bob.pubkey());
+    send(&mut h.ctx, &[ix], &[]).await.unwrap();
+    assert_eq!(h.project().await.required_roles[0].needed, 4);
+
+    send(&mut h.ctx, &[execute_transfer(transfer)], &[]).await.unwrap();
+    assert_eq!(h.project().await.pending_owner, heir.pubkey());
+    let accept = devcol_ix(
+        accounts::AcceptTransfer {
+            project: h.project,
+            new_owner: heir.pubkey(),
+            new_owner_user: user_pda(&heir.pubkey()),
+            previous_owner_user: user_pda(&owner.pubkey()),
+            previous_program_creator: pda(&[b"program_creator", owner.pubkey().as_ref()]),
+        },
+        instruction::AcceptTransfer {},
+    );
+    send(&mut h.ctx, &[accept], &[&heir]).await.unwrap();
+    assert_eq!(h.project().await.creator, heir.pubkey());
+}
+
+#[tokio::test]
+async fn delete_proposal_closes_project_and_approver_set() {
+    let mut h = Harness::start().await;
+    let owner = h.owner.insecure_clone();
+    let alice = h.alice.insecure_clone();
+    let proposal = h.propose(&alice, ProposalAction::DeleteProject, DAY).await;
+    assert!(h.approve(proposal, &owner).await);
+
+    let execute = devcol_ix(
+        accounts::ExecuteDeleteProject {
+            proposal,
+            project: h.project,
+            approver_set: h.approver_set,
+            creator: owner.pubkey(),
+            creator_user: user_pda(&owner.pubkey()),
+            program_creator: pda(&[b"program_creator", owner.pubkey().as_ref()]),
+            proposer: alice.pubkey(),
+            executor: h.ctx.payer.pubkey(),
+        },
+        instruction::ExecuteDeleteProject {},
+    );
+    send(&mut h.ctx, &[execute], &[]).await.unwrap();
+    assert!(fetch::<Project>(&mut h.ctx, h.project).await.is_none());
+    assert!(fetch::<ApproverSet>(&mut h.ctx, h.approver_set).await.is_none());
+    let profile: User = fetch(&mut h.ctx, user_pda(&owner.pubkey())).await.unwrap();
+    assert_eq!(profile.projects_count, 0);
+}
+
+#[tokio::test]
+async fn withdraw_proposal_pays_only_the_named_recipient() {
+    let mut h = Harness::start().await;
+    let owner = h.owner.insecure_clone();
+    let vault = pda(&[b"donation_vault", h.project.as_ref()]);
+    let donor = funded_wallet(&mut h.ctx, 500_000_000).await;
+    let ixs = [
+        devcol_ix(
+            accounts::OpenDonationVault {
+                vault,
+                project: h.project,
+                creator: owner.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::OpenDonationVault {},
+        ),
+        devcol_ix(
+            accounts::Donate {
+                vault,
+                project: h.project,
+                donor_record: pda(&[b"donor", h.project.as_ref(), donor.pubkey().as_ref()]),
+                receipt: None,
+                donor: donor.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::Donate { amount: 100_000_000, memo: String::new() },
+        ),
+    ];
+    send(&mut h.ctx, &ixs, &[&owner, &donor]).await.unwrap();
+
+    let treasury = Pubkey::new_unique();
+    let action = ProposalAction::WithdrawDonations { mint: Pubkey::default(), amount: 60_000_000, recipient: treasury };
+    let proposal = h.propose(&owner, action, DAY).await;
+    let bob = h.bob.insecure_clone();
+    assert!(h.approve(proposal, &bob).await);
+
+    let (project, approver_set, executor) = (h.project, h.approver_set, h.ctx.payer.pubkey());
+    let execute = |recipient: Pubkey| {
+        devcol_ix(
+            accounts::ExecuteWithdrawDonations {
+                proposal,
+                project,
+                approver_set,
+                proposer: owner.pubkey(),
+                executor,
+                vault,
+                recipient,
+            },
+            instruction::ExecuteWithdrawDonations {},
+        )
+    };
+    assert!(send(&mut h.ctx, &[execute(owner.pubkey())], &[]).await.is_err());
+    send(&mut h.ctx, &[execute(treasury)], &[]).await.unwrap();
+    assert_eq!(lamports(&mut h.ctx, treasury).await, 60_000_000);
+}
+
+#[tokio::test]
+async fn expired_proposals_lapse_and_anyone_can_clear_them() {
+    let mut h = Harness::start().await;
+    let alice = h.alice.insecure_clone();
+    let bob = h.bob.insecure_clone();
+    let outsider = funded_wallet(&mut h.ctx, 10_000_000).await;
+    let project = h.project;
+    let cancel = |proposal: Pubkey, authority: Pubkey, proposer: Pubkey| {
+        devcol_ix(
+            accounts::CancelProposal { proposal, project, authority, proposer },
+            instruction::CancelProposal {},
+        )
+    };
+
+    // Before expiry only the proposer or the creator may cancel
+    let withdrawn = h.propose(&alice, roles_action(3), DAY).await;
+    let ix = cancel(withdrawn, outsider.pubkey(), alice.pubkey());
+    assert!(send(&mut h.ctx, &[ix], &[&outsider]).await.is_err());
+    let ix = cancel(withdrawn, alice.pubkey(), alice.pubkey());
+    send(&mut h.ctx, &[ix], &[&alice]).await.unwrap();
+    assert!(fetch::<Proposal>(&mut h.ctx, withdrawn).await.is_none());
+
+    let stale = h.propose(&alice, roles_action(3), 60).await;
+    advance_clock(&mut h.ctx, 61).await;
+    assert!(!h.approve(stale, &bob).await);
+    let ix = h.update_roles_ix(stale, alice.pubkey());
+    assert!(send(&mut h.ctx, &[ix], &[]).await.is_err());
+
+    // Expired proposals can be swept by anyone, refunding the proposer
+    let before = lamports(&mut h.ctx, alice.pubkey()).await;
+    let ix = cancel(stale, outsider.pubkey(), alice.pubkey());
+    send(&mut h.ctx, &[ix], &[&outsider]).await.unwrap();
+    assert!(lamports(&mut h.ctx, alice.pubkey()).await > before);
+    assert_eq!(h.project().await.required_roles[0].needed, 2);
+}
+
+#[tokio::test]
+async fn removed_approvers_no_longer_count() {
+    let mut h = Harness::start().await;
+    let owner = h.owner.insecure_clone();
+    let alice = h.alice.insecure_clone();
+    let bob = h.bob.insecure_clone();
+
+    // Approved by alice and bob, but not executed yet
+    let pending = h.propose(&alice, roles_action(5), DAY).await;
+    assert!(h.approve(pending, &bob).await);
+
+    // Bob is dropped from the set
+    let action = ProposalAction::UpdateApprovers { approvers: vec![owner.pubkey(), alice.pubkey()], threshold: 2 };
+    let update = h.propose(&owner, action, DAY).await;
+    assert!(h.approve(update, &alice).await);
+    let ix = h.update_approvers_ix(update, owner.pubkey());
+    send(&mut h.ctx, &[ix], &[]).await.unwrap();
+    let set: ApproverSet = fetch(&mut h.ctx, h.approver_set).await.unwrap();
+    assert_eq!(set.approvers, vec![owner.pubkey(), alice.pubkey()]);
+
+    // Bob's earlier approval is stale, so the pending proposal is one short again
+    let ix = h.update_roles_ix(pending, alice.pubkey());
+    assert!(send(&mut h.ctx, &[ix], &[]).await.is_err());
+    assert!(!h.approve(pending, &bob).await);
+    assert!(h.approve(pending, &owner).await);
+    let ix = h.update_roles_ix(pending, alice.pubkey());
+    send(&mut h.ctx, &[ix], &[]).await.unwrap();
+    assert_eq!(h.project().await.required_roles[0].needed, 5);
+}
+
+#[tokio::test]
+async fn dissolving_the_set_restores_direct_control() {
+    let mut h = Harness::start().await;
+    let owner = h.owner.insecure_clone();
+    let bob = h.bob.insecure_clone();
+    let action = ProposalAction::UpdateApprovers { approvers: vec![], threshold: 0 };
+    let update = h.propose(&bob, action, DAY).await;
+    assert!(h.approve(update, &owner).await);
+    let ix = h.update_approvers_ix(update, bob.pubkey());
+    send(&mut h.ctx, &[ix], &[]).await.unwrap();
+    assert!(fetch::<ApproverSet>(&mut h.ctx, h.approver_set).await.is_none());
+    assert_eq!(h.project().await.approval_threshold, 0);
+
+    let member = create_user(&mut h.ctx, "member").await;
+    let request = send_request(&mut h.ctx, &member, h.project, &owner.pubkey(), Some(Role::Backend)).await;
+    accept_request(&mut h.ctx, &owner, h.project, request).await;
+    let state: CollaborationRequest = fetch(&mut h.ctx, request).await.unwrap();
+    assert!(state.status == RequestStatus::Accepted);
+}
@@ -0,0 +1,71 @@
+//! Rate limits: rolling windows, and counters that outlive the profile
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, RateWindow, DAY};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signature::Keypair, signer::Signer as _};
+
+#[test]
+fn window_rolls_instead_of_resetting() {
+    let start = 1_000 * DAY;
+    let mut window = RateWindow::default();
+    // A full allowance spent at the very end of one period...
+    for _ in 0..10 {
+        assert!(window.try_hit(start + DAY - 1, DAY, 10));
+    }
+    assert!(!window.try_hit(start + DAY - 1, DAY, 10));
+    // ...is still counted right after the boundary
+    assert!(!window.try_hit(start + DAY + 1, DAY, 10));
+    // and frees up gradually as it ages out
+    let mut allowed = 0;
+    while window.try_hit(start + DAY + DAY / 2, DAY, 10) {
+        allowed += 1;
+    }
+    assert_eq!(allowed, 5);
+    assert!(window.try_hit(start + 3 * DAY, DAY, 10));
+}
+
+async fn rename(ctx: &mut ProgramTestContext, wallet: &Keypair, name: &str) -> bool {
+    let ix = devcol_ix(
+        accounts::UpdateUser {
+            user: user_pda(&wallet.pubkey()),
+            signer: wallet.pubkey(),
+            wallet: wallet.pubkey(),
+            rate_state: rate_state_pda(&wallet.pubkey()),
+            config: config_pda(),
+        },
+        instruction::UpdateUser {
+            display_name: Some(name.to_string()),
+            role: None,
+            location: None,
+            bio: None,
+            github_link: None,
+            ipfs_metadata_hash: None,
+            contact_info: None,
+            open_to_collab: None,
+            profile_visibility: None,
+        },
+    );
+    send(ctx, &[ix], &[wallet]).await.is_ok()
+}
+
+#[tokio::test]
+async fn recreating_a_profile_keeps_its_limits() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let alice = create_user(&mut ctx, "alice").await;
+    for i in 0..TEST_LIMITS.updates_per_hour {
+        assert!(rename(&mut ctx, &alice, &format!("alice {i}")).await);
+    }
+    assert!(!rename(&mut ctx, &alice, "one too many").await);
+
+    let delete = devcol_ix(
+        accounts::DeleteUser { user: user_pda(&alice.pubkey()), signer: alice.pubkey(), wallet: alice.pubkey() },
+        instruction::DeleteUser {},
+    );
+    send(&mut ctx, &[delete], &[&alice]).await.unwrap();
+    create_profile(&mut ctx, &alice, "alice").await;
+    assert!(!rename(&mut ctx, &alice, "fresh start").await);
+}
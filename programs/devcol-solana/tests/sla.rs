@@ -0,0 +1,122 @@
+//! Response SLA: breach claims, retirement and the sender's cleanup of expired requests
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, CollaborationRequest, RequestStatus, Role, SLA_CLAIM_PERIOD};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+const WINDOW: i64 = 24 * 60 * 60;
+const PENALTY: u64 = 10_000_000;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    owner: Keypair,
+    alice: Keypair,
+    project: Pubkey,
+    sla_bond: Pubkey,
+}
+
+impl Harness {
+    /// Project under a one-day SLA with room for a few breaches
+    async fn start() -> Self {
+        let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+        let owner = create_user(&mut ctx, "owner").await;
+        let alice = create_user(&mut ctx, "alice").await;
+        let project = create_project(&mut ctx, &owner, "vault", vec![role_slot(Role::Backend, 1)]).await;
+        let sla_bond = pda(&[b"sla_bond", project.as_ref()]);
+        let commit = devcol_ix(
+            accounts::CommitResponseSla {
+                sla_bond,
+                project,
+                creator: owner.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CommitResponseSla { response_window: WINDOW, penalty: PENALTY, stake: 3 * PENALTY },
+        );
+        send(&mut ctx, &[commit], &[&owner]).await.unwrap();
+        Self { ctx, owner, alice, project, sla_bond }
+    }
+
+    fn claim_ix(&self, request: Pubkey) -> Instruction {
+        devcol_ix(
+            accounts::ClaimSlaBreach {
+                collab_request: request,
+                project: self.project,
+                sla_bond: self.sla_bond,
+                application_bond: pda(&[b"application_bond", self.alice.pubkey().as_ref(), self.project.as_ref()]),
+                applicant: self.alice.pubkey(),
+            },
+            instruction::ClaimSlaBreach {},
+        )
+    }
+
+    fn withdraw_ix(&self) -> Instruction {
+        devcol_ix(
+            accounts::WithdrawSlaBond { sla_bond: self.sla_bond, project: self.project, owner: self.owner.pubkey() },
+            instruction::WithdrawSlaBond {},
+        )
+    }
+}
+
+#[tokio::test]
+async fn expired_request_can_be_deleted_and_resent() {
+    let mut h = Harness::start().await;
+    let alice = h.alice.insecure_clone();
+    let owner = h.owner.pubkey();
+    let request = send_request(&mut h.ctx, &alice, h.project, &owner, Some(Role::Backend)).await;
+    advance_clock(&mut h.ctx, WINDOW + 1).await;
+    let before = lamports(&mut h.ctx, alice.pubkey()).await;
+    let claim = h.claim_ix(request);
+    send(&mut h.ctx, &[claim], &[&alice]).await.unwrap();
+    assert!(lamports(&mut h.ctx, alice.pubkey()).await > before);
+    let state: CollaborationRequest = fetch(&mut h.ctx, request).await.unwrap();
+    assert!(state.status == RequestStatus::Expired);
+
+    let delete = devcol_ix(
+        accounts::DeleteSenderRejectedRequest { collab_request: request, sender: alice.pubkey(), project: h.project },
+        instruction::DeleteSenderRejectedRequest {},
+    );
+    send(&mut h.ctx, &[delete], &[&alice]).await.unwrap();
+    send_request(&mut h.ctx, &alice, h.project, &owner, Some(Role::Backend)).await;
+}
+
+#[tokio::test]
+async fn retired_bond_stays_until_claims_lapse() {
+    let mut h = Harness::start().await;
+    let (alice, owner) = (h.alice.insecure_clone(), h.owner.insecure_clone());
+    let request = send_request(&mut h.ctx, &alice, h.project, &owner.pubkey(), Some(Role::Backend)).await;
+    let retire = devcol_ix(
+        accounts::ManageSlaBond {
+            sla_bond: h.sla_bond,
+            project: h.project,
+            creator: owner.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::RetireResponseSla {},
+    );
+    send(&mut h.ctx, &[retire], &[&owner]).await.unwrap();
+
+    // Well past the deadline the bond is still held for the applicant's claim
+    advance_clock(&mut h.ctx, 3 * WINDOW).await;
+    let withdraw = h.withdraw_ix();
+    assert!(send(&mut h.ctx, std::slice::from_ref(&withdraw), &[&owner]).await.is_err());
+    let claim = h.claim_ix(request);
+    send(&mut h.ctx, &[claim], &[&alice]).await.unwrap();
+
+    advance_clock(&mut h.ctx, SLA_CLAIM_PERIOD).await;
+    send(&mut h.ctx, &[withdraw], &[&owner]).await.unwrap();
+}
+
+#[tokio::test]
+async fn breach_cannot_be_claimed_after_claim_period() {
+    let mut h = Harness::start().await;
+    let alice = h.alice.insecure_clone();
+    let owner = h.owner.pubkey();
+    let request = send_request(&mut h.ctx, &alice, h.project, &owner, Some(Role::Backend)).await;
+    advance_clock(&mut h.ctx, WINDOW + SLA_CLAIM_PERIOD + 1).await;
+    let claim = h.claim_ix(request);
+    assert!(send(&mut h.ctx, &[claim], &[&alice]).await.is_err());
+}
@@ -0,0 +1,113 @@
+//! Follows and stars belong to one incarnation of a profile or project
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, Project, User};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signature::Keypair, signer::Signer as _};
+
+async fn member_since(ctx: &mut ProgramTestContext, wallet: &Pubkey) -> i64 {
+    fetch::<User>(ctx, user_pda(wallet)).await.unwrap().member_since
+}
+
+async fn follow(ctx: &mut ProgramTestContext, follower: &Keypair, followed: &Pubkey) -> Pubkey {
+    let since = member_since(ctx, followed).await;
+    let follow = pda(&[b"follow", follower.pubkey().as_ref(), followed.as_ref(), &since.to_le_bytes()]);
+    let ix = devcol_ix(
+        accounts::FollowUser {
+            follow,
+            follower_user: user_pda(&follower.pubkey()),
+            followed_user: user_pda(followed),
+            follower: follower.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::FollowUser {},
+    );
+    send(ctx, &[ix], &[follower]).await.unwrap();
+    follow
+}
+
+async fn star(ctx: &mut ProgramTestContext, user: &Keypair, project: Pubkey) -> Pubkey {
+    let since = fetch::<Project>(ctx, project).await.unwrap().timestamp;
+    let star = pda(&[b"star", user.pubkey().as_ref(), project.as_ref(), &since.to_le_bytes()]);
+    let ix = devcol_ix(
+        accounts::StarProject {
+            star,
+            user_profile: user_pda(&user.pubkey()),
+            project,
+            user: user.pubkey(),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::StarProject {},
+    );
+    send(ctx, &[ix], &[user]).await.unwrap();
+    star
+}
+
+#[tokio::test]
+async fn recreated_profile_starts_with_fresh_follows() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let alice = create_user(&mut ctx, "alice").await;
+    let bob = create_user(&mut ctx, "bob").await;
+    let stale = follow(&mut ctx, &alice, &bob.pubkey()).await;
+
+    let delete = devcol_ix(
+        accounts::DeleteUser { user: user_pda(&bob.pubkey()), signer: bob.pubkey(), wallet: bob.pubkey() },
+        instruction::DeleteUser {},
+    );
+    send(&mut ctx, &[delete], &[&bob]).await.unwrap();
+    advance_clock(&mut ctx, 60).await;
+    create_profile(&mut ctx, &bob, "bob").await;
+
+    // The old follow no longer blocks a new one, and unfollowing it leaves the new profile's count alone
+    follow(&mut ctx, &alice, &bob.pubkey()).await;
+    let unfollow = devcol_ix(
+        accounts::UnfollowUser {
+            follow: stale,
+            follower_user: user_pda(&alice.pubkey()),
+            followed_user: user_pda(&bob.pubkey()),
+            follower: alice.pubkey(),
+        },
+        instruction::UnfollowUser {},
+    );
+    send(&mut ctx, &[unfollow], &[&alice]).await.unwrap();
+
+    let bob_user: User = fetch(&mut ctx, user_pda(&bob.pubkey())).await.unwrap();
+    assert_eq!(bob_user.followers_count, 1);
+    let alice_user: User = fetch(&mut ctx, user_pda(&alice.pubkey())).await.unwrap();
+    assert_eq!(alice_user.following_count, 1);
+}
+
+#[tokio::test]
+async fn recreated_project_starts_with_fresh_stars() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let alice = create_user(&mut ctx, "alice").await;
+    let project = create_project(&mut ctx, &owner, "vault", vec![]).await;
+    let stale = star(&mut ctx, &alice, project).await;
+
+    let delete = devcol_ix(
+        accounts::DeleteProject {
+            project,
+            creator: owner.pubkey(),
+            creator_user: user_pda(&owner.pubkey()),
+            program_creator: pda(&[b"program_creator", owner.pubkey().as_ref()]),
+        },
+        instruction::DeleteProject {},
+    );
+    send(&mut ctx, &[delete], &[&owner]).await.unwrap();
+    advance_clock(&mut ctx, 60).await;
+    create_project(&mut ctx, &owner, "vault", vec![]).await;
+
+    star(&mut ctx, &alice, project).await;
+    let unstar = devcol_ix(
+        accounts::UnstarProject { star: stale, project, user: alice.pubkey() },
+        instruction::UnstarProject {},
+    );
+    send(&mut ctx, &[unstar], &[&alice]).await.unwrap();
+
+    let state: Project = fetch(&mut ctx, project).await.unwrap();
+    assert_eq!(state.stars_count, 1);
+}
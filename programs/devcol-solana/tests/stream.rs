@@ -0,0 +1,178 @@
+//! Contributor streams: cliff, withdrawals, pausing on leave and cancellation
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, CollaborationRequest, Role, Stream};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signature::Keypair, signer::Signer as _};
+
+const RATE: u64 = 100;
+const DAY: i64 = 24 * 60 * 60;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    owner: Keypair,
+    member: Keypair,
+    project: Pubkey,
+    request: Pubkey,
+    stream: Pubkey,
+    start: i64,
+}
+
+impl Harness {
+    /// Member streamed RATE lamports/s over 30 days with a 10 day cliff
+    async fn start() -> Self {
+        let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+        let owner = create_user(&mut ctx, "owner").await;
+        let project = create_project(&mut ctx, &owner, "runtime", vec![role_slot(Role::DevOps, 1)]).await;
+        let member = create_user(&mut ctx, "member").await;
+        let request = send_request(&mut ctx, &member, project, &owner.pubkey(), Some(Role::DevOps)).await;
+        accept_request(&mut ctx, &owner, project, request).await;
+
+        let stream = pda(&[b"stream", request.as_ref()]);
+        let start = unix_timestamp(&mut ctx).await;
+        let ix = devcol_ix(
+            accounts::CreateStream {
+                stream,
+                collab_request: request,
+                project,
+                funder: owner.pubkey(),
+                project_role: None,
+                organization: None,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateStream { start, cliff: start + 10 * DAY, end: start + 30 * DAY, rate: RATE },
+        );
+        send(&mut ctx, &[ix], &[&owner]).await.unwrap();
+        Self { ctx, owner, member, project, request, stream, start }
+    }
+
+    async fn withdraw(&mut self) -> bool {
+        let ix = devcol_ix(
+            accounts::WithdrawStream {
+                stream: self.stream,
+                collab_request: self.request,
+                recipient: self.member.pubkey(),
+            },
+            instruction::WithdrawStream {},
+        );
+        let member = self.member.insecure_clone();
+        send(&mut self.ctx, &[ix], &[&member]).await.is_ok()
+    }
+
+    async fn leave(&mut self, stream: Pubkey) -> bool {
+        let ix = devcol_ix(
+            accounts::LeaveProject {
+                collab_request: self.request,
+                project: self.project,
+                member: self.member.pubkey(),
+                stream,
+            },
+            instruction::LeaveProject {},
+        );
+        let member = self.member.insecure_clone();
+        send(&mut self.ctx, &[ix], &[&member]).await.is_ok()
+    }
+
+    async fn withdrawn(&mut self) -> u64 {
+        let stream: Stream = fetch(&mut self.ctx, self.stream).await.unwrap();
+        stream.withdrawn
+    }
+
+    async fn warp_to(&mut self, offset: i64) {
+        let now = unix_timestamp(&mut self.ctx).await;
+        advance_clock(&mut self.ctx, self.start + offset - now).await;
+    }
+}
+
+#[tokio::test]
+async fn vests_linearly_after_cliff() {
+    let mut h = Harness::start().await;
+
+    h.warp_to(5 * DAY).await;
+    assert!(!h.withdraw().await);
+
+    h.warp_to(12 * DAY).await;
+    assert!(h.withdraw().await);
+    assert_eq!(h.withdrawn().await, RATE * (12 * DAY) as u64);
+
+    h.warp_to(40 * DAY).await;
+    assert!(h.withdraw().await);
+    assert_eq!(h.withdrawn().await, RATE * (30 * DAY) as u64);
+}
+
+#[tokio::test]
+async fn leaving_pauses_and_cancel_returns_unvested() {
+    let mut h = Harness::start().await;
+
+    h.warp_to(15 * DAY).await;
+    assert!(h.leave(h.stream).await);
+
+    // Vesting stopped when the member left
+    h.warp_to(25 * DAY).await;
+    assert!(h.withdraw().await);
+    let vested = RATE * (15 * DAY) as u64;
+    assert_eq!(h.withdrawn().await, vested);
+
+    let funder_before = lamports(&mut h.ctx, h.owner.pubkey()).await;
+    let escrow = lamports(&mut h.ctx, h.stream).await;
+    let cancel = devcol_ix(
+        accounts::CancelStream {
+            stream: h.stream,
+            collab_request: h.request,
+            funder: h.owner.pubkey(),
+            recipient: h.member.pubkey(),
+        },
+        instruction::CancelStream {},
+    );
+    let owner = h.owner.insecure_clone();
+    send(&mut h.ctx, &[cancel], &[&owner]).await.unwrap();
+
+    assert_eq!(escrow, RATE * (30 * DAY) as u64 - vested + rent_of_stream(&mut h.ctx).await);
+    assert_eq!(lamports(&mut h.ctx, h.owner.pubkey()).await, funder_before + escrow);
+    assert!(fetch::<Stream>(&mut h.ctx, h.stream).await.is_none());
+}
+
+#[tokio::test]
+async fn vesting_stops_at_leaving_whatever_happens_after() {
+    let mut h = Harness::start().await;
+
+    // The stream can't be left out when leaving
+    h.warp_to(15 * DAY).await;
+    assert!(!h.leave(Pubkey::new_unique()).await);
+    assert!(h.leave(h.stream).await);
+    let left_at = unix_timestamp(&mut h.ctx).await;
+
+    // Later thread activity doesn't move the pause point
+    h.warp_to(20 * DAY).await;
+    let request: CollaborationRequest = fetch(&mut h.ctx, h.request).await.unwrap();
+    assert_eq!(request.left_at, left_at);
+    let post = devcol_ix(
+        accounts::PostRequestMessage {
+            collab_request: h.request,
+            request_message: pda(&[
+                b"request_message",
+                h.request.as_ref(),
+                &request.timestamp.to_le_bytes(),
+                &request.message_count.to_le_bytes(),
+            ]),
+            author: h.member.pubkey(),
+            block: pda(&[b"block", h.owner.pubkey().as_ref(), h.member.pubkey().as_ref()]),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::PostRequestMessage { text: "Still here".to_string(), ipfs_hash: String::new() },
+    );
+    let member = h.member.insecure_clone();
+    assert!(send(&mut h.ctx, &[post], &[&member]).await.is_err());
+
+    h.warp_to(25 * DAY).await;
+    assert!(h.withdraw().await);
+    assert_eq!(h.withdrawn().await, RATE * (left_at - h.start) as u64);
+}
+
+async fn rent_of_stream(ctx: &mut ProgramTestContext) -> u64 {
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    rent.minimum_balance(8 + Stream::INIT_SPACE)
+}
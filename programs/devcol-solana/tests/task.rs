@@ -0,0 +1,213 @@
+//! Task board: member-only claims, submissions, approval and contribution credit
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{
+    accounts, instruction, CollaborationLevel, MemberStats, ProjectStatus, Role, Task, TaskStatus,
+    User, MAX_PROJECT_REPUTATION,
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+struct Harness {
+    ctx: ProgramTestContext,
+    owner: Keypair,
+    project: Pubkey,
+}
+
+impl Harness {
+    /// Project tagged "rust" and "wasm" with two Backend slots
+    async fn start() -> Self {
+        let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+        let owner = create_user(&mut ctx, "owner").await;
+        let project = project_pda(&owner.pubkey(), "runtime");
+        let ix = devcol_ix(
+            accounts::CreateProject {
+                project,
+                user: user_pda(&owner.pubkey()),
+                rate_state: rate_state_pda(&owner.pubkey()),
+                config: config_pda(),
+                creator: owner.pubkey(),
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateProject {
+                name: "runtime".to_string(),
+                description: String::new(),
+                github_link: String::new(),
+                logo_ipfs_hash: String::new(),
+                tech_stack: vec!["rust".to_string(), "wasm".to_string()],
+                contribution_needs: vec![],
+                collab_intent: String::new(),
+                collaboration_level: CollaborationLevel::AllLevels,
+                project_status: ProjectStatus::InProgress,
+                required_roles: vec![role_slot(Role::Backend, 2)],
+            },
+        );
+        send(&mut ctx, &[ix], &[&owner]).await.unwrap();
+        Self { ctx, owner, project }
+    }
+
+    async fn member(&mut self, username: &str) -> Keypair {
+        let wallet = create_user(&mut self.ctx, username).await;
+        let owner = self.owner.pubkey();
+        let request = send_request(&mut self.ctx, &wallet, self.project, &owner, Some(Role::Backend)).await;
+        accept_request(&mut self.ctx, &self.owner, self.project, request).await;
+        wallet
+    }
+
+    fn task(&self, index: u32) -> Pubkey {
+        pda(&[b"task", self.project.as_ref(), &index.to_le_bytes()])
+    }
+
+    async fn create(&mut self, index: u32, labels: &[&str], estimate: u16) -> bool {
+        let ix = devcol_ix(
+            accounts::CreateTask {
+                task: self.task(index),
+                project: self.project,
+                authority: self.owner.pubkey(),
+                project_role: None,
+                organization: None,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateTask {
+                title: format!("Task {index}"),
+                labels: labels.iter().map(|l| l.to_string()).collect(),
+                estimate,
+            },
+        );
+        let owner = self.owner.insecure_clone();
+        send(&mut self.ctx, &[ix], &[&owner]).await.is_ok()
+    }
+
+    async fn claim(&mut self, index: u32, member: &Keypair) -> bool {
+        let ix = devcol_ix(
+            accounts::ClaimTask {
+                task: self.task(index),
+                project: self.project,
+                member_request: request_pda(&member.pubkey(), &self.project),
+                member: member.pubkey(),
+            },
+            instruction::ClaimTask {},
+        );
+        send(&mut self.ctx, &[ix], &[member]).await.is_ok()
+    }
+
+    async fn work(&mut self, index: u32, member: &Keypair, data: impl anchor_lang::InstructionData) -> bool {
+        let ix = devcol_ix(accounts::WorkOnTask { task: self.task(index), assignee: member.pubkey() }, data);
+        send(&mut self.ctx, &[ix], &[member]).await.is_ok()
+    }
+
+    fn approve_ix(&self, index: u32, assignee: &Pubkey) -> Instruction {
+        devcol_ix(
+            accounts::ApproveTask {
+                task: self.task(index),
+                project: self.project,
+                member_stats: self.stats(assignee),
+                assignee_user: user_pda(assignee),
+                authority: self.owner.pubkey(),
+                project_role: None,
+                organization: None,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::ApproveTask {},
+        )
+    }
+
+    fn stats(&self, member: &Pubkey) -> Pubkey {
+        pda(&[b"member_stats", self.project.as_ref(), member.as_ref()])
+    }
+}
+
+fn submit() -> instruction::SubmitTask {
+    instruction::SubmitTask { submission_ipfs_hash: "QmPatch".to_string() }
+}
+
+#[tokio::test]
+async fn approved_tasks_credit_the_member() {
+    let mut h = Harness::start().await;
+    let alice = h.member("alice").await;
+    assert!(h.create(0, &["rust"], 3).await);
+    assert!(h.create(1, &["rust", "wasm"], 5).await);
+
+    // Claims are exclusive, and can be handed back
+    let bob = h.member("bob").await;
+    assert!(h.claim(0, &bob).await);
+    assert!(!h.claim(0, &alice).await);
+    assert!(!h.work(0, &alice, instruction::UnclaimTask {}).await);
+    assert!(h.work(0, &bob, instruction::UnclaimTask {}).await);
+    assert!(h.claim(0, &alice).await);
+
+    // Only submitted work can be approved
+    let owner = h.owner.insecure_clone();
+    let ix = h.approve_ix(0, &alice.pubkey());
+    assert!(send(&mut h.ctx, &[ix], &[&owner]).await.is_err());
+    assert!(h.work(0, &alice, submit()).await);
+    assert!(!h.work(0, &alice, instruction::UnclaimTask {}).await);
+    let ix = h.approve_ix(0, &alice.pubkey());
+    send(&mut h.ctx, &[ix], &[&owner]).await.unwrap();
+
+    assert!(h.claim(1, &alice).await);
+    assert!(h.work(1, &alice, submit()).await);
+    let ix = h.approve_ix(1, &alice.pubkey());
+    send(&mut h.ctx, &[ix], &[&owner]).await.unwrap();
+
+    let address = h.task(1);
+    let task: Task = fetch(&mut h.ctx, address).await.unwrap();
+    assert!(task.status == TaskStatus::Done);
+    assert_eq!(task.submission_ipfs_hash, "QmPatch");
+    let address = h.stats(&alice.pubkey());
+    let stats: MemberStats = fetch(&mut h.ctx, address).await.unwrap();
+    assert_eq!(stats.tasks_completed, 2);
+    assert_eq!(stats.task_points, 8);
+    // Reputation is capped per project, whatever the estimates say
+    assert_eq!(stats.reputation_awarded, MAX_PROJECT_REPUTATION);
+    let profile: User = fetch(&mut h.ctx, user_pda(&alice.pubkey())).await.unwrap();
+    assert_eq!(profile.reputation, MAX_PROJECT_REPUTATION);
+    assert_eq!(profile.collabs_count, 1);
+    let address = h.stats(&bob.pubkey());
+    let stats: MemberStats = fetch(&mut h.ctx, address).await.unwrap();
+    assert_eq!(stats.tasks_completed, 0);
+    assert!(stats.collab_counted);
+}
+
+#[tokio::test]
+async fn board_is_limited_to_members_and_project_tags() {
+    let mut h = Harness::start().await;
+    assert!(!h.create(0, &["python"], 1).await);
+    assert!(!h.create(0, &["rust", "rust"], 1).await);
+    assert!(h.create(0, &["wasm"], 1).await);
+
+    let outsider = create_user(&mut h.ctx, "outsider").await;
+    assert!(!h.claim(0, &outsider).await);
+    let owner = h.owner.pubkey();
+    let pending = create_user(&mut h.ctx, "pending").await;
+    send_request(&mut h.ctx, &pending, h.project, &owner, Some(Role::Backend)).await;
+    assert!(!h.claim(0, &pending).await);
+
+    let address = h.task(0);
+    let task: Task = fetch(&mut h.ctx, address).await.unwrap();
+    assert!(task.status == TaskStatus::Open);
+    assert_eq!(task.labels[0].value, "wasm");
+}
+
+#[tokio::test]
+async fn re_accepting_a_member_counts_one_collab() {
+    let mut h = Harness::start().await;
+    let alice = h.member("alice").await;
+
+    // The owner drops the accepted request and takes alice back on a fresh one
+    let owner = h.owner.insecure_clone();
+    let request = request_pda(&alice.pubkey(), &h.project);
+    let delete = devcol_ix(
+        accounts::DeleteCollabRequest { collab_request: request, project_owner: owner.pubkey(), to: owner.pubkey() },
+        instruction::DeleteCollabRequest {},
+    );
+    send(&mut h.ctx, &[delete], &[&owner]).await.unwrap();
+    let request = send_request(&mut h.ctx, &alice, h.project, &owner.pubkey(), Some(Role::Backend)).await;
+    accept_request(&mut h.ctx, &owner, h.project, request).await;
+
+    let profile: User = fetch(&mut h.ctx, user_pda(&alice.pubkey())).await.unwrap();
+    assert_eq!(profile.collabs_count, 1);
+}
@@ -0,0 +1,108 @@
+//! Request threads: append-only messages and a fresh thread when a request is re-sent
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, CollaborationRequest, RequestMessage, Role};
+use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer as _};
+
+fn message_pda(request: &Pubkey, thread: i64, seq: u32) -> Pubkey {
+    pda(&[b"request_message", request.as_ref(), &thread.to_le_bytes(), &seq.to_le_bytes()])
+}
+
+fn post_ix(request: &CollaborationRequest, address: Pubkey, author: &Keypair, counterpart: &Pubkey) -> Instruction {
+    devcol_ix(
+        accounts::PostRequestMessage {
+            collab_request: address,
+            request_message: message_pda(&address, request.timestamp, request.message_count),
+            author: author.pubkey(),
+            block: pda(&[b"block", counterpart.as_ref(), author.pubkey().as_ref()]),
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::PostRequestMessage { text: "Hello".to_string(), ipfs_hash: String::new() },
+    )
+}
+
+fn close_ix(request: Pubkey, message: Pubkey, author: &Keypair) -> Instruction {
+    devcol_ix(
+        accounts::CloseRequestMessage { request_message: message, author: author.pubkey(), collab_request: request },
+        instruction::CloseRequestMessage {},
+    )
+}
+
+#[tokio::test]
+async fn resent_request_starts_a_new_thread() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let project = create_project(&mut ctx, &owner, "forum", vec![role_slot(Role::Backend, 1)]).await;
+    let alice = create_user(&mut ctx, "alice").await;
+
+    let address = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Backend)).await;
+    let first: CollaborationRequest = fetch(&mut ctx, address).await.unwrap();
+    send(&mut ctx, &[post_ix(&first, address, &alice, &owner.pubkey())], &[&alice]).await.unwrap();
+    let old_message = message_pda(&address, first.timestamp, 0);
+
+    // Messages can't be closed while their request is alive
+    assert!(send(&mut ctx, &[close_ix(address, old_message, &alice)], &[&alice]).await.is_err());
+
+    let withdraw = devcol_ix(
+        accounts::WithdrawCollabRequest { collab_request: address, sender: alice.pubkey(), project },
+        instruction::WithdrawCollabRequest {},
+    );
+    send(&mut ctx, &[withdraw], &[&alice]).await.unwrap();
+    advance_clock(&mut ctx, 60).await;
+    send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Backend)).await;
+
+    // The re-sent request posts from seq 0 again without colliding with the old thread
+    let second: CollaborationRequest = fetch(&mut ctx, address).await.unwrap();
+    assert_eq!(second.message_count, 0);
+    send(&mut ctx, &[post_ix(&second, address, &owner, &alice.pubkey())], &[&owner]).await.unwrap();
+    let message: RequestMessage = fetch(&mut ctx, message_pda(&address, second.timestamp, 0)).await.unwrap();
+    assert_eq!(message.author, owner.pubkey());
+
+    // The old thread is closable; the live one is not
+    send(&mut ctx, &[close_ix(address, old_message, &alice)], &[&alice]).await.unwrap();
+    assert!(fetch::<RequestMessage>(&mut ctx, old_message).await.is_none());
+    let live = message_pda(&address, second.timestamp, 0);
+    assert!(send(&mut ctx, &[close_ix(address, live, &owner)], &[&owner]).await.is_err());
+}
+
+#[tokio::test]
+async fn threads_close_with_the_request_and_leave_its_timestamps_alone() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let project = create_project(&mut ctx, &owner, "forum", vec![role_slot(Role::Backend, 1)]).await;
+    let alice = create_user(&mut ctx, "alice").await;
+    let address = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Backend)).await;
+
+    // Posting marks thread activity but not a status change
+    advance_clock(&mut ctx, 60).await;
+    let before: CollaborationRequest = fetch(&mut ctx, address).await.unwrap();
+    send(&mut ctx, &[post_ix(&before, address, &alice, &owner.pubkey())], &[&alice]).await.unwrap();
+    let after: CollaborationRequest = fetch(&mut ctx, address).await.unwrap();
+    assert_eq!(after.last_activity, before.last_activity);
+    assert_eq!(after.thread_activity, unix_timestamp(&mut ctx).await);
+
+    let reject = devcol_ix(
+        accounts::UpdateCollabRequest {
+            collab_request: address,
+            reviewer: owner.pubkey(),
+            project_role: None,
+            organization: None,
+            project,
+            block: pda(&[b"block", owner.pubkey().as_ref(), alice.pubkey().as_ref()]),
+            owner_block: pda(&[b"block", owner.pubkey().as_ref(), alice.pubkey().as_ref()]),
+            sender_user: user_pda(&alice.pubkey()),
+            member_stats: None,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::RejectCollabRequest { owner_message: "Not now".to_string() },
+    );
+    send(&mut ctx, &[reject], &[&owner]).await.unwrap();
+
+    // Neither party can post once the request is rejected
+    let rejected: CollaborationRequest = fetch(&mut ctx, address).await.unwrap();
+    assert!(send(&mut ctx, &[post_ix(&rejected, address, &alice, &owner.pubkey())], &[&alice]).await.is_err());
+    assert!(send(&mut ctx, &[post_ix(&rejected, address, &owner, &alice.pubkey())], &[&owner]).await.is_err());
+}
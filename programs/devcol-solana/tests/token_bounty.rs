@@ -0,0 +1,261 @@
+//! SPL Token and Token-2022 bounties against the token programs bundled with program-test
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{
+    get_associated_token_address_with_program_id as ata,
+    spl_associated_token_account::instruction::create_associated_token_account,
+};
+use anchor_spl::token::spl_token;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::instruction::initialize_transfer_fee_config, ExtensionType},
+};
+use common::*;
+use devcol_solana::{accounts, instruction, Bounty, BountyStatus, Role};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{program_pack::Pack, signature::Keypair, signer::Signer as _};
+use solana_system_interface::instruction as system_instruction;
+
+const MINTED: u64 = 10_000_000;
+const AMOUNT: u64 = 1_000_000;
+const FEE_BPS: u16 = 100;
+
+struct Harness {
+    ctx: ProgramTestContext,
+    admin: Keypair,
+    owner: Keypair,
+    project: Pubkey,
+}
+
+impl Harness {
+    async fn start() -> Self {
+        let admin = Keypair::new();
+        let mut ctx = program_test(&admin.pubkey()).start_with_context().await;
+        let owner = create_user(&mut ctx, "owner").await;
+        let project = create_project(&mut ctx, &owner, "payments", vec![role_slot(Role::Backend, 2)]).await;
+        Self { ctx, admin, owner, project }
+    }
+
+    /// New mint under `token_program` (with a 1% transfer fee on Token-2022), `MINTED` to the owner
+    async fn mint(&mut self, token_program: Pubkey) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.ctx.payer.pubkey();
+        let with_fee = token_program == spl_token_2022::ID;
+        let len = if with_fee {
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+                ExtensionType::TransferFeeConfig,
+            ])
+            .unwrap()
+        } else {
+            spl_token::state::Mint::LEN
+        };
+        let rent = self.ctx.banks_client.get_rent().await.unwrap().minimum_balance(len);
+
+        let mut ixs = vec![system_instruction::create_account(
+            &payer,
+            &mint.pubkey(),
+            rent,
+            len as u64,
+            &token_program,
+        )];
+        if with_fee {
+            ixs.push(
+                initialize_transfer_fee_config(&token_program, &mint.pubkey(), None, None, FEE_BPS, u64::MAX)
+                    .unwrap(),
+            );
+        }
+        ixs.push(
+            spl_token_2022::instruction::initialize_mint2(&token_program, &mint.pubkey(), &payer, None, 6)
+                .unwrap(),
+        );
+        let owner = self.owner.pubkey();
+        ixs.push(create_associated_token_account(&payer, &owner, &mint.pubkey(), &token_program));
+        ixs.push(
+            spl_token_2022::instruction::mint_to(
+                &token_program,
+                &mint.pubkey(),
+                &ata(&owner, &mint.pubkey(), &token_program),
+                &payer,
+                &[],
+                MINTED,
+            )
+            .unwrap(),
+        );
+        send(&mut self.ctx, &ixs, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    async fn allow(&mut self, mint: Pubkey) {
+        let ix = devcol_ix(
+            accounts::UpdateConfig { config: config_pda(), admin: self.admin.pubkey() },
+            instruction::SetMintAllowed { mint, allowed: true },
+        );
+        let admin = self.admin.insecure_clone();
+        send(&mut self.ctx, &[ix], &[&admin]).await.unwrap();
+    }
+
+    fn vault(&self, mint: Pubkey, token_program: Pubkey) -> Pubkey {
+        let authority = pda(&[b"vault_authority", self.project.as_ref()]);
+        ata(&authority, &mint, &token_program)
+    }
+
+    async fn create_bounty(&mut self, mint: Pubkey, token_program: Pubkey) -> std::result::Result<Pubkey, ()> {
+        let project: devcol_solana::Project = fetch(&mut self.ctx, self.project).await.unwrap();
+        let bounty = pda(&[b"bounty", self.project.as_ref(), &project.bounty_count.to_le_bytes()]);
+        let deadline = unix_timestamp(&mut self.ctx).await + 7 * 24 * 60 * 60;
+        let ix = devcol_ix(
+            accounts::CreateTokenBounty {
+                bounty,
+                project: self.project,
+                manager: self.owner.pubkey(),
+                project_role: None,
+                organization: None,
+                config: config_pda(),
+                mint,
+                funder_token: ata(&self.owner.pubkey(), &mint, &token_program),
+                vault_authority: pda(&[b"vault_authority", self.project.as_ref()]),
+                vault: self.vault(mint, token_program),
+                token_program,
+                associated_token_program: anchor_spl::associated_token::ID,
+                system_program: anchor_lang::system_program::ID,
+            },
+            instruction::CreateTokenBounty {
+                title: "Stablecoin payouts".to_string(),
+                spec_ipfs_hash: "QmSpec".to_string(),
+                amount: AMOUNT,
+                deadline,
+            },
+        );
+        let owner = self.owner.insecure_clone();
+        send(&mut self.ctx, &[ix], &[&owner]).await.map(|_| bounty).map_err(|_| ())
+    }
+
+    async fn token_balance(&mut self, account: Pubkey) -> u64 {
+        let account = self.ctx.banks_client.get_account(account).await.unwrap().unwrap();
+        // Base account layout is shared by both token programs: mint, owner, amount
+        u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+    }
+}
+
+#[tokio::test]
+async fn token_2022_bounty_escrows_net_of_transfer_fee() {
+    let mut h = Harness::start().await;
+    let token_program = spl_token_2022::ID;
+    let mint = h.mint(token_program).await;
+    h.allow(mint).await;
+
+    let bounty = h.create_bounty(mint, token_program).await.unwrap();
+    let fee = AMOUNT * FEE_BPS as u64 / 10_000;
+    let escrowed = AMOUNT - fee;
+    let vault = h.vault(mint, token_program);
+    assert_eq!(h.token_balance(vault).await, escrowed);
+    let state: Bounty = fetch(&mut h.ctx, bounty).await.unwrap();
+    assert_eq!(state.amount, escrowed);
+    assert_eq!(state.mint, mint);
+
+    // Member with their own token account completes the bounty
+    let alice = create_user(&mut h.ctx, "alice").await;
+    let owner = h.owner.pubkey();
+    let request = send_request(&mut h.ctx, &alice, h.project, &owner, Some(Role::Backend)).await;
+    accept_request(&mut h.ctx, &h.owner, h.project, request).await;
+    let alice_token = ata(&alice.pubkey(), &mint, &token_program);
+    let payer = h.ctx.payer.pubkey();
+    let create_ata = create_associated_token_account(&payer, &alice.pubkey(), &mint, &token_program);
+    let assign = devcol_ix(
+        accounts::AssignBounty {
+            bounty,
+            project: h.project,
+            member_request: request,
+            manager: owner,
+            project_role: None,
+            organization: None,
+        },
+        instruction::AssignBounty { assignee: alice.pubkey() },
+    );
+    let submit = devcol_ix(
+        accounts::SubmitBounty { bounty, assignee: alice.pubkey() },
+        instruction::SubmitBounty { submission_ipfs_hash: "QmWork".to_string() },
+    );
+    let owner_kp = h.owner.insecure_clone();
+    send(&mut h.ctx, &[create_ata, assign, submit], &[&owner_kp, &alice]).await.unwrap();
+
+    let approve = devcol_ix(
+        accounts::ApproveTokenBounty {
+            bounty,
+            project: h.project,
+            manager: owner,
+            project_role: None,
+            organization: None,
+            mint,
+            assignee_token: alice_token,
+            vault_authority: pda(&[b"vault_authority", h.project.as_ref()]),
+            vault,
+            token_program,
+        },
+        instruction::ApproveTokenBounty {},
+    );
+    send(&mut h.ctx, &[approve], &[&owner_kp]).await.unwrap();
+
+    assert_eq!(h.token_balance(vault).await, 0);
+    let payout_fee = escrowed * FEE_BPS as u64 / 10_000;
+    assert_eq!(h.token_balance(alice_token).await, escrowed - payout_fee);
+    let state: Bounty = fetch(&mut h.ctx, bounty).await.unwrap();
+    assert!(state.status == BountyStatus::Paid);
+}
+
+#[tokio::test]
+async fn spl_token_bounty_cancel_refunds_funder() {
+    let mut h = Harness::start().await;
+    let token_program = spl_token::ID;
+    let mint = h.mint(token_program).await;
+    h.allow(mint).await;
+
+    let bounty = h.create_bounty(mint, token_program).await.unwrap();
+    let owner_token = ata(&h.owner.pubkey(), &mint, &token_program);
+    assert_eq!(h.token_balance(owner_token).await, MINTED - AMOUNT);
+
+    // The SOL path refuses token bounties
+    let sol_cancel = devcol_ix(
+        accounts::CancelBounty {
+            bounty,
+            project: h.project,
+            funder: h.owner.pubkey(),
+            manager: h.owner.pubkey(),
+            project_role: None,
+            organization: None,
+        },
+        instruction::CancelBounty {},
+    );
+    let owner = h.owner.insecure_clone();
+    assert!(send(&mut h.ctx, &[sol_cancel], &[&owner]).await.is_err());
+
+    let cancel = devcol_ix(
+        accounts::CancelTokenBounty {
+            bounty,
+            project: h.project,
+            manager: h.owner.pubkey(),
+            project_role: None,
+            organization: None,
+            mint,
+            funder_token: owner_token,
+            vault_authority: pda(&[b"vault_authority", h.project.as_ref()]),
+            vault: h.vault(mint, token_program),
+            token_program,
+        },
+        instruction::CancelTokenBounty {},
+    );
+    send(&mut h.ctx, &[cancel], &[&owner]).await.unwrap();
+    assert_eq!(h.token_balance(owner_token).await, MINTED);
+}
+
+#[tokio::test]
+async fn mint_must_be_allowlisted() {
+    let mut h = Harness::start().await;
+    let mint = h.mint(spl_token::ID).await;
+    assert!(h.create_bounty(mint, spl_token::ID).await.is_err());
+
+    h.allow(mint).await;
+    assert!(h.create_bounty(mint, spl_token::ID).await.is_ok());
+}
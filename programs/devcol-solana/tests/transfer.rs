@@ -0,0 +1,79 @@
+//! Ownership transfer hands every request on the project to the new owner
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, CollaborationRequest, Role};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::Keypair,
+    signer::Signer as _,
+};
+
+fn delete_ix(request: Pubkey, owner: &Keypair) -> Instruction {
+    devcol_ix(
+        accounts::DeleteCollabRequest { collab_request: request, project_owner: owner.pubkey(), to: owner.pubkey() },
+        instruction::DeleteCollabRequest {},
+    )
+}
+
+#[tokio::test]
+async fn resolved_requests_follow_the_project() {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let heir = create_user(&mut ctx, "heir").await;
+    let alice = create_user(&mut ctx, "alice").await;
+    let bob = create_user(&mut ctx, "bob").await;
+    let project = create_project(&mut ctx, &owner, "vault", vec![role_slot(Role::Backend, 2)]).await;
+    let accepted = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Backend)).await;
+    accept_request(&mut ctx, &owner, project, accepted).await;
+    let rejected = send_request(&mut ctx, &bob, project, &owner.pubkey(), Some(Role::Backend)).await;
+    let reject = devcol_ix(
+        accounts::UpdateCollabRequest {
+            collab_request: rejected,
+            reviewer: owner.pubkey(),
+            project_role: None,
+            organization: None,
+            project,
+            block: pda(&[b"block", owner.pubkey().as_ref(), bob.pubkey().as_ref()]),
+            owner_block: pda(&[b"block", owner.pubkey().as_ref(), bob.pubkey().as_ref()]),
+            sender_user: user_pda(&bob.pubkey()),
+            member_stats: None,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::RejectCollabRequest { owner_message: "Not now".to_string() },
+    );
+    send(&mut ctx, &[reject], &[&owner]).await.unwrap();
+
+    let propose = devcol_ix(
+        accounts::ProposeTransfer { project, creator: owner.pubkey(), new_owner_user: user_pda(&heir.pubkey()) },
+        instruction::ProposeTransfer {},
+    );
+    send(&mut ctx, &[propose], &[&owner]).await.unwrap();
+    let mut accept = devcol_ix(
+        accounts::AcceptTransfer {
+            project,
+            new_owner: heir.pubkey(),
+            new_owner_user: user_pda(&heir.pubkey()),
+            previous_owner_user: user_pda(&owner.pubkey()),
+            previous_program_creator: pda(&[b"program_creator", owner.pubkey().as_ref()]),
+        },
+        instruction::AcceptTransfer {},
+    );
+    accept.accounts.push(AccountMeta::new(rejected, false));
+    send(&mut ctx, &[accept], &[&heir]).await.unwrap();
+
+    // The rejected request moved with the project; the previous owner can no longer clean it up
+    assert!(send(&mut ctx, &[delete_ix(rejected, &owner)], &[&owner]).await.is_err());
+    send(&mut ctx, &[delete_ix(rejected, &heir)], &[&heir]).await.unwrap();
+
+    // A resolved request left out of the transfer can be synced afterwards
+    let sync = devcol_ix(
+        accounts::SyncRequestOwner { collab_request: accepted, project },
+        instruction::SyncRequestOwner {},
+    );
+    send(&mut ctx, &[sync], &[]).await.unwrap();
+    let state: CollaborationRequest = fetch(&mut ctx, accepted).await.unwrap();
+    assert_eq!(state.to, heir.pubkey());
+}
@@ -0,0 +1,109 @@
+//! View instructions read through simulateTransaction
+
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use devcol_solana::{accounts, instruction, Eligibility, ErrorCode, OpenSlots, ProfileSummary, Role};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{signature::Keypair, signer::Signer as _};
+
+async fn setup() -> (ProgramTestContext, Keypair, Pubkey) {
+    let mut ctx = program_test(&Pubkey::new_unique()).start_with_context().await;
+    let owner = create_user(&mut ctx, "owner").await;
+    let project = create_project(&mut ctx, &owner, "explorer", vec![role_slot(Role::Frontend, 1)]).await;
+    (ctx, owner, project)
+}
+
+async fn can_apply(
+    ctx: &mut ProgramTestContext,
+    owner: &Pubkey,
+    project: Pubkey,
+    applicant: Pubkey,
+    desired_role: Option<Role>,
+) -> Eligibility {
+    let ix = devcol_ix(
+        accounts::CanApply {
+            project,
+            applicant_user: user_pda(&applicant),
+            rate_state: rate_state_pda(&applicant),
+            config: config_pda(),
+            collab_request: request_pda(&applicant, &project),
+            block: pda(&[b"block", owner.as_ref(), applicant.as_ref()]),
+            application_bond: pda(&[b"application_bond", applicant.as_ref(), project.as_ref()]),
+        },
+        instruction::CanApply { applicant, desired_role },
+    );
+    simulate_return(ctx, ix).await
+}
+
+fn code(error: ErrorCode) -> u64 {
+    (anchor_lang::error::ERROR_CODE_OFFSET + error as u32).into()
+}
+
+#[tokio::test]
+async fn can_apply_mirrors_send_collab_request() {
+    let (mut ctx, owner, project) = setup().await;
+    let alice = create_user(&mut ctx, "alice").await;
+    let bob = create_user(&mut ctx, "bob").await;
+
+    let eligibility = can_apply(&mut ctx, &owner.pubkey(), project, alice.pubkey(), Some(Role::Frontend)).await;
+    assert!(eligibility.eligible);
+    assert_eq!(eligibility.error_code, 0);
+
+    let eligibility = can_apply(&mut ctx, &owner.pubkey(), project, alice.pubkey(), Some(Role::Designer)).await;
+    assert!(!eligibility.eligible);
+    assert_eq!(eligibility.error_code, code(ErrorCode::RoleNotFound));
+
+    let request = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Frontend)).await;
+    let eligibility = can_apply(&mut ctx, &owner.pubkey(), project, alice.pubkey(), Some(Role::Frontend)).await;
+    assert_eq!(eligibility.error_code, code(ErrorCode::RequestAlreadySent));
+
+    accept_request(&mut ctx, &owner, project, request).await;
+    let eligibility = can_apply(&mut ctx, &owner.pubkey(), project, bob.pubkey(), Some(Role::Frontend)).await;
+    assert_eq!(eligibility.error_code, code(ErrorCode::RoleSlotFull));
+}
+
+#[test]
+fn builtin_program_errors_keep_a_nonzero_code() {
+    let eligibility = Eligibility::from_check(Err(ProgramError::InsufficientFunds.into()));
+    assert!(!eligibility.eligible);
+    assert_eq!(eligibility.error_code, u64::from(ProgramError::InsufficientFunds));
+    // Truncated to 32 bits this would have read as eligible
+    assert_eq!(eligibility.error_code as u32, 0);
+
+    let eligibility = Eligibility::from_check(Err(ProgramError::Custom(7).into()));
+    assert_eq!(eligibility.error_code, 7);
+    assert!(Eligibility::from_check(Ok(())).eligible);
+}
+
+#[tokio::test]
+async fn open_slots_track_acceptances() {
+    let (mut ctx, owner, project) = setup().await;
+    let slots_ix = || devcol_ix(accounts::ProjectView { project }, instruction::GetOpenSlots {});
+
+    let slots: OpenSlots = simulate_return(&mut ctx, slots_ix()).await;
+    assert!(slots.accepting);
+    assert_eq!(slots.slots.len(), 1);
+    assert_eq!(slots.slots[0].open, 1);
+
+    let alice = create_user(&mut ctx, "alice").await;
+    let request = send_request(&mut ctx, &alice, project, &owner.pubkey(), Some(Role::Frontend)).await;
+    accept_request(&mut ctx, &owner, project, request).await;
+
+    let slots: OpenSlots = simulate_return(&mut ctx, slots_ix()).await;
+    assert_eq!(slots.slots[0].open, 0);
+}
+
+#[tokio::test]
+async fn profile_summary_reports_counters() {
+    let (mut ctx, owner, _) = setup().await;
+    let ix = devcol_ix(
+        accounts::ProfileView { user: user_pda(&owner.pubkey()) },
+        instruction::GetProfileSummary {},
+    );
+    let summary: ProfileSummary = simulate_return(&mut ctx, ix).await;
+    assert_eq!(summary.wallet, owner.pubkey());
+    assert_eq!(summary.username, "owner");
+    assert_eq!(summary.projects_count, 1);
+}